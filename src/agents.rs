@@ -0,0 +1,405 @@
+use std::f64::consts::PI;
+
+use crate::{
+    distribution,
+    world::{
+        DistributionComponent, HouseholdAgent, HouseholdAgentsComponent, HouseholdComponent,
+        IncomeGroup, TaxBracket,
+    },
+};
+
+const EPS: f64 = 1e-9;
+
+pub struct IncomeOutcome {
+    pub disposable: f64,
+    pub income_tax: f64,
+    pub wage_bill: f64,
+    pub transfers: f64,
+}
+
+pub fn dispersion_factors(count: usize, sigma: f64) -> Vec<f64> {
+    if count == 0 {
+        return Vec::new();
+    }
+    let factors: Vec<f64> = (0..count)
+        .map(|i| {
+            let p = (i as f64 + 0.5) / count as f64;
+            let z = (p / (1.0 - p)).ln() * 3.0_f64.sqrt() / PI;
+            (sigma.max(0.0) * z).exp()
+        })
+        .collect();
+    let mean = factors.iter().sum::<f64>() / count as f64;
+    factors.into_iter().map(|f| f / mean).collect()
+}
+
+pub fn persons(component: &HouseholdAgentsComponent) -> f64 {
+    component.agents.iter().map(|a| a.persons()).sum()
+}
+
+pub fn employed_workers(component: &HouseholdAgentsComponent) -> f64 {
+    component
+        .agents
+        .iter()
+        .filter(|a| a.employed)
+        .map(|a| a.workers())
+        .sum()
+}
+
+pub fn labour_force(component: &HouseholdAgentsComponent) -> f64 {
+    component.agents.iter().map(|a| a.workers()).sum()
+}
+
+pub fn set_participation(component: &mut HouseholdAgentsComponent, participation: f64) {
+    let participation = participation.clamp(0.0, 1.0);
+    for agent in component.agents.iter_mut() {
+        agent.earners = agent.size * participation;
+    }
+}
+
+pub fn add_births(component: &mut HouseholdAgentsComponent, births: f64) {
+    if labour_force(component) > EPS {
+        grow(component, births, |a| a.workers());
+    } else {
+        grow(component, births, |a| a.persons());
+    }
+}
+
+pub fn add_persons(component: &mut HouseholdAgentsComponent, people: f64) {
+    grow(component, people, |a| a.persons());
+}
+
+pub fn remove_persons(component: &mut HouseholdAgentsComponent, people: f64) -> f64 {
+    shrink(component, people, |a| a.persons())
+}
+
+pub fn remove_starving(component: &mut HouseholdAgentsComponent, people: f64) -> f64 {
+    let total = persons(component);
+    let income: f64 = component.agents.iter().map(|a| a.weight * a.income).sum();
+    if total <= EPS || income <= EPS {
+        return remove_persons(component, people);
+    }
+    let mean = income / total;
+    shrink(component, people, |a| {
+        let per_person = if a.size > EPS { a.income / a.size } else { 0.0 };
+        a.persons() / (per_person.max(0.0) + mean)
+    })
+}
+
+fn shares(component: &HouseholdAgentsComponent, key: impl Fn(&HouseholdAgent) -> f64) -> Vec<f64> {
+    let keys: Vec<f64> = component.agents.iter().map(|a| key(a).max(0.0)).collect();
+    let total: f64 = keys.iter().sum();
+    if total <= EPS {
+        return vec![0.0; keys.len()];
+    }
+    keys.into_iter().map(|k| k / total).collect()
+}
+
+fn grow(
+    component: &mut HouseholdAgentsComponent,
+    people: f64,
+    key: impl Fn(&HouseholdAgent) -> f64,
+) {
+    if people <= EPS {
+        return;
+    }
+    let shares = shares(component, key);
+    for (agent, share) in component.agents.iter_mut().zip(shares) {
+        if agent.weight > EPS {
+            agent.size += people * share / agent.weight;
+        }
+    }
+}
+
+fn shrink(
+    component: &mut HouseholdAgentsComponent,
+    people: f64,
+    key: impl Fn(&HouseholdAgent) -> f64,
+) -> f64 {
+    if people <= EPS {
+        return 0.0;
+    }
+    let shares = shares(component, key);
+    let mut removed = 0.0;
+    for (agent, share) in component.agents.iter_mut().zip(shares) {
+        let lost = (people * share).min(agent.persons());
+        if lost <= EPS || agent.size <= EPS {
+            continue;
+        }
+        let remaining = (agent.size - lost / agent.weight).max(0.0);
+        agent.earners *= remaining / agent.size;
+        agent.size = remaining;
+        removed += lost;
+    }
+    removed
+}
+
+pub fn assign_employment(component: &mut HouseholdAgentsComponent, target: f64) -> f64 {
+    let mut current = employed_workers(component);
+    let mut order: Vec<usize> = (0..component.agents.len()).collect();
+    if current < target {
+        order.retain(|&i| !component.agents[i].employed);
+        order.sort_by(|&a, &b| {
+            component.agents[b]
+                .productivity
+                .total_cmp(&component.agents[a].productivity)
+        });
+        for i in order {
+            let workers = component.agents[i].workers();
+            if current + workers * 0.5 <= target {
+                component.agents[i].employed = true;
+                current += workers;
+            }
+        }
+    } else {
+        order.retain(|&i| component.agents[i].employed);
+        order.sort_by(|&a, &b| {
+            component.agents[a]
+                .productivity
+                .total_cmp(&component.agents[b].productivity)
+        });
+        for i in order {
+            let workers = component.agents[i].workers();
+            if current - workers * 0.5 >= target {
+                component.agents[i].employed = false;
+                current -= workers;
+            }
+        }
+    }
+    current.max(0.0)
+}
+
+pub fn distribute_income(
+    component: &mut HouseholdAgentsComponent,
+    brackets: &[TaxBracket],
+    wage: f64,
+    transfer_pool: f64,
+    dt: f64,
+) -> IncomeOutcome {
+    let (employed, productive, unemployed) =
+        component
+            .agents
+            .iter()
+            .fold((0.0, 0.0, 0.0), |(e, p, u), a| match a.employed {
+                true => (
+                    e + a.workers(),
+                    p + a.workers() * a.productivity.max(0.0),
+                    u,
+                ),
+                false => (e, p, u + a.workers()),
+            });
+    let wage_scale = if productive > EPS {
+        employed / productive
+    } else {
+        1.0
+    };
+    let transfer_per_worker = if unemployed > EPS {
+        transfer_pool.max(0.0) / unemployed
+    } else {
+        0.0
+    };
+
+    let mut outcome = IncomeOutcome {
+        disposable: 0.0,
+        income_tax: 0.0,
+        wage_bill: 0.0,
+        transfers: 0.0,
+    };
+    for agent in component.agents.iter_mut() {
+        let (wages, transfers) = if agent.employed {
+            let pay = wage * agent.productivity.max(0.0) * wage_scale * agent.earners * dt;
+            (pay, 0.0)
+        } else {
+            (0.0, transfer_per_worker * agent.earners)
+        };
+        agent.gross_income = (wages + transfers).max(0.0);
+        agent.income_tax = if agent.size > EPS && dt > EPS {
+            let per_capita_daily = agent.gross_income / (agent.size * dt);
+            distribution::bracket_tax(brackets, per_capita_daily) * agent.size * dt
+        } else {
+            0.0
+        };
+        agent.income = agent.gross_income - agent.income_tax;
+        outcome.wage_bill += agent.weight * wages;
+        outcome.transfers += agent.weight * transfers;
+        outcome.income_tax += agent.weight * agent.income_tax;
+        outcome.disposable += agent.weight * agent.income;
+    }
+    outcome
+}
+
+pub fn plan_consumption(
+    component: &mut HouseholdAgentsComponent,
+    params: Option<&HouseholdComponent>,
+    propensity_to_consume: f64,
+    dt: f64,
+) -> f64 {
+    let Some(params) = params else {
+        for agent in component.agents.iter_mut() {
+            agent.planned_consumption = agent.income.max(0.0) * propensity_to_consume;
+        }
+        return component
+            .agents
+            .iter()
+            .map(|a| a.weight * a.planned_consumption)
+            .sum();
+    };
+    let smoothing = (params.income_smoothing * dt).clamp(0.0, 1.0);
+    let drawdown = (params.wealth_drawdown_rate * dt).clamp(0.0, 1.0);
+    let mut budget = 0.0;
+    for agent in component.agents.iter_mut() {
+        let daily_income = if dt > EPS { agent.income / dt } else { 0.0 };
+        agent.permanent_income += smoothing * (daily_income - agent.permanent_income);
+        agent.permanent_income = agent.permanent_income.max(0.0);
+        let target_wealth = params.target_buffer_days * agent.permanent_income;
+        let planned = propensity_to_consume * agent.permanent_income * dt
+            + drawdown * (agent.net_wealth() - target_wealth);
+        let headroom = (params.borrowing_limit_days * agent.permanent_income - agent.debt).max(0.0);
+        let liquidity = agent.income + agent.deposits.max(0.0) + headroom;
+        agent.planned_consumption = planned.clamp(0.0, liquidity.max(0.0));
+        budget += agent.weight * agent.planned_consumption;
+    }
+    budget
+}
+
+pub fn settle(component: &mut HouseholdAgentsComponent, fulfilment: f64) {
+    let fulfilment = fulfilment.clamp(0.0, 1.0);
+    for agent in component.agents.iter_mut() {
+        let spending = agent.planned_consumption * fulfilment;
+        let saving = agent.income - spending;
+        if saving >= 0.0 {
+            let repayment = saving.min(agent.debt);
+            agent.debt -= repayment;
+            agent.deposits += saving - repayment;
+        } else {
+            let drawn = (-saving).min(agent.deposits.max(0.0));
+            agent.deposits -= drawn;
+            agent.debt += -saving - drawn;
+        }
+        agent.consumption = spending;
+    }
+}
+
+pub fn accrue_interest(
+    component: &mut HouseholdAgentsComponent,
+    deposit_growth: f64,
+    debt_growth: f64,
+) {
+    for agent in component.agents.iter_mut() {
+        if agent.deposits > 0.0 {
+            agent.deposits *= deposit_growth;
+        }
+        if agent.debt > 0.0 {
+            agent.debt *= debt_growth;
+        }
+    }
+}
+
+pub fn summarise_households(
+    component: &HouseholdAgentsComponent,
+    household: &mut HouseholdComponent,
+) {
+    let sum = |f: fn(&HouseholdAgent) -> f64| -> f64 {
+        component.agents.iter().map(|a| a.weight * f(a)).sum()
+    };
+    household.deposits = sum(|a| a.deposits);
+    household.debt = sum(|a| a.debt);
+    household.permanent_income = sum(|a| a.permanent_income);
+    household.disposable_income = sum(|a| a.income);
+    household.consumption = sum(|a| a.consumption);
+    household.saving = household.disposable_income - household.consumption;
+}
+
+pub fn summarise_distribution(
+    component: &HouseholdAgentsComponent,
+    distribution: &mut DistributionComponent,
+) {
+    let total = persons(component);
+    let groups: Vec<IncomeGroup> = component
+        .agents
+        .iter()
+        .map(|a| IncomeGroup {
+            name: String::new(),
+            population_share: if total > EPS {
+                a.persons() / total
+            } else {
+                0.0
+            },
+            wage_multiplier: a.productivity,
+            employment_weight: 1.0,
+            propensity_multiplier: 1.0,
+            transfer_weight: 1.0,
+            members: a.persons(),
+            employed: if a.employed { a.workers() } else { 0.0 },
+            gross_income: a.weight * a.gross_income,
+            income_tax: a.weight * a.income_tax,
+            transfers: if a.employed {
+                0.0
+            } else {
+                a.weight * a.gross_income
+            },
+            disposable_income: a.weight * a.income,
+        })
+        .collect();
+    distribution.income_tax = groups.iter().map(|g| g.income_tax).sum();
+    distribution.gini = distribution::gini(&groups);
+    distribution.palma = distribution::palma(&groups);
+    distribution.poverty_rate =
+        distribution::poverty_rate(&groups, distribution.poverty_line_ratio);
+    regroup(&mut distribution.groups, groups, total);
+}
+
+fn regroup(groups: &mut [IncomeGroup], mut agents: Vec<IncomeGroup>, total: f64) {
+    let shares: f64 = groups.iter().map(|g| g.population_share.max(0.0)).sum();
+    for group in groups.iter_mut() {
+        group.members = 0.0;
+        group.employed = 0.0;
+        group.gross_income = 0.0;
+        group.income_tax = 0.0;
+        group.transfers = 0.0;
+        group.disposable_income = 0.0;
+    }
+    if shares <= EPS || total <= EPS {
+        return;
+    }
+    let per_capita = |g: &IncomeGroup| {
+        if g.members > EPS {
+            g.disposable_income / g.members
+        } else {
+            0.0
+        }
+    };
+    agents.sort_by(|a, b| per_capita(a).total_cmp(&per_capita(b)));
+    let mut order: Vec<usize> = (0..groups.len()).collect();
+    order.sort_by(|a, b| {
+        groups[*a]
+            .wage_multiplier
+            .total_cmp(&groups[*b].wage_multiplier)
+    });
+    let mut agents = agents.into_iter().map(|a| (a.members, a)).peekable();
+    for idx in order {
+        let group = &mut groups[idx];
+        let mut room = group.population_share.max(0.0) / shares * total;
+        while room > EPS {
+            let Some((left, agent)) = agents.peek_mut() else {
+                break;
+            };
+            let taken = left.min(room);
+            let part = if agent.members > EPS {
+                taken / agent.members
+            } else {
+                0.0
+            };
+            group.members += taken;
+            group.employed += part * agent.employed;
+            group.gross_income += part * agent.gross_income;
+            group.income_tax += part * agent.income_tax;
+            group.transfers += part * agent.transfers;
+            group.disposable_income += part * agent.disposable_income;
+            *left -= taken;
+            room -= taken;
+            if *left <= EPS {
+                agents.next();
+            }
+        }
+    }
+}
@@ -0,0 +1,148 @@
+use rand::Rng;
+
+use crate::{
+    rng,
+    world::{AgeCohort, DemographicEvents, DemographyComponent},
+};
+
+const EPS: f64 = 1e-9;
+
+pub fn total(demography: &DemographyComponent) -> f64 {
+    demography
+        .cohorts
+        .iter()
+        .map(|c| c.population.max(0.0))
+        .sum()
+}
+
+fn overlaps_working_age(demography: &DemographyComponent, cohort: &AgeCohort) -> bool {
+    let max_age = cohort.max_age.unwrap_or(u32::MAX);
+    cohort.min_age <= demography.working_age_max && max_age >= demography.working_age_min
+}
+
+pub fn refresh_indicators(demography: &mut DemographyComponent) {
+    let mut working_age = 0.0;
+    let mut dependants = 0.0;
+    let mut labour_force = 0.0;
+    for cohort in &demography.cohorts {
+        if overlaps_working_age(demography, cohort) {
+            working_age += cohort.population;
+            labour_force += cohort.population * cohort.participation_rate.clamp(0.0, 1.0);
+        } else {
+            dependants += cohort.population;
+        }
+    }
+    demography.labour_force = labour_force;
+    demography.dependency_ratio = if working_age > EPS {
+        dependants / working_age
+    } else {
+        0.0
+    };
+}
+
+pub fn advance<R: Rng + ?Sized>(
+    demography: &mut DemographyComponent,
+    dt_years: f64,
+    events: DemographicEvents,
+    rng: &mut R,
+) {
+    let expected_births: f64 = demography
+        .cohorts
+        .iter()
+        .map(|c| c.population * c.fertility_rate.max(0.0) * dt_years)
+        .sum();
+    let births = match events {
+        DemographicEvents::Deterministic => expected_births,
+        DemographicEvents::Stochastic => rng::poisson(rng, expected_births) as f64,
+    };
+    let mut deaths = 0.0;
+    for cohort in demography.cohorts.iter_mut() {
+        let hazard = (cohort.mortality_rate.max(0.0) * dt_years).min(1.0);
+        let died = match events {
+            DemographicEvents::Deterministic => cohort.population * hazard,
+            DemographicEvents::Stochastic => {
+                rng::binomial(rng, cohort.population.round().max(0.0) as u64, hazard) as f64
+            }
+        }
+        .min(cohort.population.max(0.0));
+        cohort.population -= died;
+        deaths += died;
+    }
+    let outflows: Vec<f64> = demography
+        .cohorts
+        .iter()
+        .map(|c| match c.width_years() {
+            Some(width) => c.population * (dt_years / width).min(1.0),
+            None => 0.0,
+        })
+        .collect();
+    let last = demography.cohorts.len().saturating_sub(1);
+    for (idx, outflow) in outflows.into_iter().enumerate() {
+        if idx == last && demography.cohorts[idx].max_age.is_some() {
+            continue;
+        }
+        demography.cohorts[idx].population -= outflow;
+        if let Some(next) = demography.cohorts.get_mut(idx + 1) {
+            next.population += outflow;
+        }
+    }
+    if let Some(first) = demography.cohorts.first_mut() {
+        first.population += births;
+    }
+    demography.births = births;
+    demography.deaths = deaths;
+}
+
+pub fn remove_proportionally(demography: &mut DemographyComponent, people: f64) -> f64 {
+    let population = total(demography);
+    if population <= EPS || people <= 0.0 {
+        return 0.0;
+    }
+    let share = (people / population).min(1.0);
+    for cohort in demography.cohorts.iter_mut() {
+        cohort.population -= cohort.population * share;
+    }
+    population * share
+}
+
+fn cohort_index(demography: &DemographyComponent, age: u32) -> Option<usize> {
+    demography
+        .cohorts
+        .iter()
+        .position(|c| c.min_age <= age && c.max_age.is_none_or(|max| age <= max))
+        .or_else(|| demography.cohorts.len().checked_sub(1))
+}
+
+pub fn take_migrants(demography: &mut DemographyComponent, people: f64) -> Vec<(u32, f64)> {
+    let pool: f64 = demography
+        .cohorts
+        .iter()
+        .filter(|c| overlaps_working_age(demography, c))
+        .map(|c| c.population.max(0.0))
+        .sum();
+    if pool <= EPS || people <= 0.0 {
+        return Vec::new();
+    }
+    let share = (people / pool).min(1.0);
+    let (min_age, max_age) = (demography.working_age_min, demography.working_age_max);
+    let mut migrants = Vec::new();
+    for cohort in demography.cohorts.iter_mut() {
+        let in_working_age =
+            cohort.min_age <= max_age && cohort.max_age.unwrap_or(u32::MAX) >= min_age;
+        if !in_working_age {
+            continue;
+        }
+        let moved = cohort.population.max(0.0) * share;
+        cohort.population -= moved;
+        migrants.push((cohort.min_age, moved));
+    }
+    migrants
+}
+
+pub fn add_migrants(demography: &mut DemographyComponent, migrants: &[(u32, f64)]) {
+    for &(age, people) in migrants {
+        if let Some(idx) = cohort_index(demography, age) {
+            demography.cohorts[idx].population += people;
+        }
+    }
+}
@@ -0,0 +1,90 @@
+use crate::world::{Good, ResourceDeposit};
+
+const EPS: f64 = 1e-9;
+
+pub fn quality(deposit: &ResourceDeposit) -> f64 {
+    if deposit.carrying_capacity <= EPS {
+        return 0.0;
+    }
+    (deposit.remaining / deposit.carrying_capacity)
+        .clamp(0.0, 1.0)
+        .powf(deposit.quality_elasticity.max(0.0))
+}
+
+pub fn serves(deposits: &[ResourceDeposit], good: Good) -> bool {
+    deposits.iter().any(|d| d.kind.good() == good)
+}
+
+pub fn regenerate(deposits: &mut [ResourceDeposit], dt: f64) {
+    for deposit in deposits.iter_mut() {
+        let capacity = deposit.carrying_capacity.max(0.0);
+        if capacity > EPS {
+            let growth = deposit.regen_rate.max(0.0) / 365.0
+                * deposit.remaining
+                * (1.0 - deposit.remaining / capacity)
+                * dt;
+            deposit.remaining = (deposit.remaining + growth.max(0.0)).min(capacity);
+        }
+        deposit.quality = quality(deposit);
+    }
+}
+
+pub fn yield_factor(deposits: &[ResourceDeposit], good: Good) -> Option<f64> {
+    let (weighted, capacity) = deposits.iter().filter(|d| d.kind.good() == good).fold(
+        (0.0, 0.0),
+        |(weighted, capacity), d| {
+            (
+                weighted + d.quality * d.carrying_capacity,
+                capacity + d.carrying_capacity,
+            )
+        },
+    );
+    if !serves(deposits, good) {
+        None
+    } else if capacity > EPS {
+        Some(weighted / capacity)
+    } else {
+        Some(0.0)
+    }
+}
+
+pub fn available(deposits: &[ResourceDeposit], good: Good) -> Option<f64> {
+    if !serves(deposits, good) {
+        return None;
+    }
+    Some(
+        deposits
+            .iter()
+            .filter(|d| d.kind.good() == good)
+            .map(|d| d.remaining.max(0.0))
+            .sum(),
+    )
+}
+
+pub fn extract(deposits: &mut [ResourceDeposit], good: Good, amount: f64) -> f64 {
+    let Some(available) = available(deposits, good) else {
+        return amount;
+    };
+    let taken = amount.max(0.0).min(available);
+    for deposit in deposits.iter_mut().filter(|d| d.kind.good() == good) {
+        let share = if available > EPS {
+            taken * deposit.remaining.max(0.0) / available
+        } else {
+            0.0
+        };
+        deposit.remaining = (deposit.remaining - share).max(0.0);
+        deposit.extracted += share;
+        deposit.last_extraction = share;
+        deposit.quality = quality(deposit);
+    }
+    taken
+}
+
+pub fn extracted_share(deposits: &[ResourceDeposit]) -> f64 {
+    let initial: f64 = deposits.iter().map(|d| d.initial).sum();
+    if initial > EPS {
+        deposits.iter().map(|d| d.extracted).sum::<f64>() / initial
+    } else {
+        0.0
+    }
+}
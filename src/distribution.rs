@@ -0,0 +1,225 @@
+use crate::world::{DistributionComponent, IncomeGroup, TaxBracket};
+
+const EPS: f64 = 1e-9;
+
+pub fn bracket_tax(brackets: &[TaxBracket], income: f64) -> f64 {
+    if income <= 0.0 {
+        return 0.0;
+    }
+    let mut tax = 0.0;
+    for (idx, bracket) in brackets.iter().enumerate() {
+        if income <= bracket.threshold {
+            break;
+        }
+        let upper = brackets
+            .get(idx + 1)
+            .map(|next| next.threshold)
+            .unwrap_or(f64::INFINITY);
+        let taxable = income.min(upper) - bracket.threshold;
+        tax += taxable.max(0.0) * bracket.rate.clamp(0.0, 1.0);
+    }
+    tax.min(income)
+}
+
+pub fn allocate_employment(groups: &mut [IncomeGroup], employed: f64) {
+    for group in groups.iter_mut() {
+        group.employed = 0.0;
+    }
+    let mut remaining = employed.max(0.0);
+    for _ in 0..groups.len() {
+        if remaining <= EPS {
+            break;
+        }
+        let weight: f64 = groups
+            .iter()
+            .filter(|g| g.members - g.employed > EPS)
+            .map(|g| g.members * g.employment_weight.max(0.0))
+            .sum();
+        if weight <= EPS {
+            break;
+        }
+        let mut placed = 0.0;
+        for group in groups.iter_mut() {
+            let spare = group.members - group.employed;
+            if spare <= EPS {
+                continue;
+            }
+            let share = remaining * group.members * group.employment_weight.max(0.0) / weight;
+            let hired = share.min(spare);
+            group.employed += hired;
+            placed += hired;
+        }
+        remaining -= placed;
+    }
+}
+
+fn sorted_by_income(groups: &[IncomeGroup]) -> Vec<(f64, f64)> {
+    let mut points: Vec<(f64, f64)> = groups
+        .iter()
+        .filter(|g| g.members > EPS)
+        .map(|g| (g.members, g.disposable_income.max(0.0)))
+        .collect();
+    points.sort_by(|a, b| {
+        (a.1 / a.0)
+            .partial_cmp(&(b.1 / b.0))
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    points
+}
+
+fn lorenz_curve(groups: &[IncomeGroup]) -> Vec<(f64, f64)> {
+    let points = sorted_by_income(groups);
+    let population: f64 = points.iter().map(|p| p.0).sum();
+    let income: f64 = points.iter().map(|p| p.1).sum();
+    let mut curve = vec![(0.0, 0.0)];
+    if population <= EPS || income <= EPS {
+        return curve;
+    }
+    let (mut cum_pop, mut cum_income) = (0.0, 0.0);
+    for (members, earned) in points {
+        cum_pop += members / population;
+        cum_income += earned / income;
+        curve.push((cum_pop, cum_income));
+    }
+    curve
+}
+
+fn lorenz_at(curve: &[(f64, f64)], population_share: f64) -> f64 {
+    for pair in curve.windows(2) {
+        let (p0, l0) = pair[0];
+        let (p1, l1) = pair[1];
+        if population_share <= p1 + EPS {
+            if p1 - p0 <= EPS {
+                return l1;
+            }
+            return l0 + (l1 - l0) * (population_share - p0) / (p1 - p0);
+        }
+    }
+    curve.last().map(|p| p.1).unwrap_or(0.0)
+}
+
+pub fn gini(groups: &[IncomeGroup]) -> f64 {
+    let curve = lorenz_curve(groups);
+    if curve.len() < 2 {
+        return 0.0;
+    }
+    let area: f64 = curve
+        .windows(2)
+        .map(|pair| (pair[1].0 - pair[0].0) * (pair[1].1 + pair[0].1))
+        .sum();
+    (1.0 - area).clamp(0.0, 1.0)
+}
+
+pub fn palma(groups: &[IncomeGroup]) -> f64 {
+    let curve = lorenz_curve(groups);
+    if curve.len() < 2 {
+        return 0.0;
+    }
+    let bottom_40 = lorenz_at(&curve, 0.4);
+    let top_10 = 1.0 - lorenz_at(&curve, 0.9);
+    if bottom_40 > EPS {
+        top_10 / bottom_40
+    } else {
+        0.0
+    }
+}
+
+pub fn poverty_rate(groups: &[IncomeGroup], poverty_line_ratio: f64) -> f64 {
+    let points = sorted_by_income(groups);
+    let population: f64 = points.iter().map(|p| p.0).sum();
+    if population <= EPS {
+        return 0.0;
+    }
+    let mut cumulative = 0.0;
+    let mut median = 0.0;
+    for (members, earned) in &points {
+        cumulative += members;
+        if cumulative >= population * 0.5 {
+            median = earned / members;
+            break;
+        }
+    }
+    let line = median * poverty_line_ratio.max(0.0);
+    let poor: f64 = points
+        .iter()
+        .filter(|(members, earned)| earned / members < line)
+        .map(|(members, _)| members)
+        .sum();
+    poor / population
+}
+
+pub fn distribute_income(
+    distribution: &mut DistributionComponent,
+    brackets: &[TaxBracket],
+    citizens: f64,
+    employed: f64,
+    wage: f64,
+    transfer_pool: f64,
+    dt: f64,
+) -> f64 {
+    for group in distribution.groups.iter_mut() {
+        group.members = citizens * group.population_share;
+    }
+    allocate_employment(&mut distribution.groups, employed);
+    let weighted_multiplier: f64 = distribution
+        .groups
+        .iter()
+        .map(|g| g.employed * g.wage_multiplier.max(0.0))
+        .sum();
+    let wage_scale = if weighted_multiplier > EPS {
+        employed / weighted_multiplier
+    } else {
+        1.0
+    };
+    let transfer_weight: f64 = distribution
+        .groups
+        .iter()
+        .map(|g| (g.members - g.employed).max(0.0) * g.transfer_weight.max(0.0))
+        .sum();
+
+    let mut income_tax = 0.0;
+    let mut disposable = 0.0;
+    for group in distribution.groups.iter_mut() {
+        let wages = wage * group.wage_multiplier.max(0.0) * wage_scale * group.employed * dt;
+        group.transfers = if transfer_weight > EPS {
+            transfer_pool.max(0.0)
+                * (group.members - group.employed).max(0.0)
+                * group.transfer_weight.max(0.0)
+                / transfer_weight
+        } else {
+            0.0
+        };
+        group.gross_income = (wages + group.transfers).max(0.0);
+        group.income_tax = if group.members > EPS && dt > EPS {
+            let per_capita_daily = group.gross_income / (group.members * dt);
+            bracket_tax(brackets, per_capita_daily) * group.members * dt
+        } else {
+            0.0
+        };
+        group.disposable_income = group.gross_income - group.income_tax;
+        income_tax += group.income_tax;
+        disposable += group.disposable_income;
+    }
+    distribution.income_tax = income_tax;
+    distribution.gini = gini(&distribution.groups);
+    distribution.palma = palma(&distribution.groups);
+    distribution.poverty_rate = poverty_rate(&distribution.groups, distribution.poverty_line_ratio);
+    disposable
+}
+
+pub fn propensity_multiplier(distribution: &DistributionComponent) -> f64 {
+    let income: f64 = distribution
+        .groups
+        .iter()
+        .map(|g| g.disposable_income.max(0.0))
+        .sum();
+    if income <= EPS {
+        return 1.0;
+    }
+    distribution
+        .groups
+        .iter()
+        .map(|g| g.disposable_income.max(0.0) * g.propensity_multiplier.max(0.0))
+        .sum::<f64>()
+        / income
+}
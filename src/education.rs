@@ -0,0 +1,53 @@
+use crate::world::EducationComponent;
+
+const EPS: f64 = 1e-9;
+const DAYS_PER_YEAR: f64 = 365.0;
+
+pub fn target_attainment(education: &EducationComponent) -> f64 {
+    let ratio = if education.reference_spending_per_capita > EPS {
+        (education.spending_per_capita / education.reference_spending_per_capita).max(0.0)
+    } else {
+        1.0
+    };
+    let funded = ratio.powf(education.funding_elasticity.max(0.0));
+    (education.min_attainment
+        + (education.reference_attainment - education.min_attainment) * funded)
+        .clamp(education.min_attainment, education.max_attainment)
+}
+
+pub fn advance(education: &mut EducationComponent, citizens: f64, dt_days: f64) {
+    education.spending_per_capita = if citizens > EPS && dt_days > EPS {
+        education.pending_funding.max(0.0) / (citizens * dt_days)
+    } else {
+        0.0
+    };
+    education.pending_funding = 0.0;
+
+    let target = target_attainment(education);
+    let schooling = (dt_days / (education.schooling_years.max(EPS) * DAYS_PER_YEAR)).min(1.0);
+    education.graduate_attainment += (target - education.graduate_attainment) * schooling;
+    let turnover =
+        (dt_days / (education.workforce_turnover_years.max(EPS) * DAYS_PER_YEAR)).min(1.0);
+    education.attainment += (education.graduate_attainment - education.attainment) * turnover;
+    education.required_skill *= (education.skill_demand_growth * dt_days / DAYS_PER_YEAR).exp();
+    refresh(education);
+}
+
+pub fn refresh(education: &mut EducationComponent) {
+    education.skill_index = if education.reference_attainment > EPS {
+        (education.attainment / education.reference_attainment).max(0.0)
+    } else {
+        1.0
+    };
+    education.skill_mismatch = if education.required_skill > EPS {
+        ((education.required_skill - education.skill_index).abs() / education.required_skill)
+            .clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    education.productivity_multiplier = education
+        .skill_index
+        .powf(education.productivity_elasticity.max(0.0));
+    education.matching_multiplier =
+        (1.0 - education.mismatch_sensitivity.max(0.0) * education.skill_mismatch).clamp(0.0, 1.0);
+}
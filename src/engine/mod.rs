@@ -3,6 +3,7 @@ use std::path::PathBuf;
 use anyhow::Result;
 
 use crate::{
+    ledger,
     rng::{RngManager, SystemRng},
     snapshot::SnapshotWriter,
     world::{World, WorldSnapshot},
@@ -68,6 +69,7 @@ impl Engine {
     {
         for _ in 0..ticks {
             let current_tick = world.tick();
+            ledger::open(world, current_tick);
             for system in &mut self.systems {
                 let mut rng_stream = self.rng.stream(system.name());
                 let ctx = SystemContext {
@@ -78,9 +80,10 @@ impl Engine {
                 system.run(&ctx, world, &mut rng_stream)?;
             }
             world.advance_time();
-            self.snapshot_writer
-                .maybe_write(world, &self.settings.scenario_name)?;
             let snapshot = world.snapshot(&self.settings.scenario_name);
+            if self.snapshot_writer.maybe_write(&snapshot)? {
+                world.mark_snapshot_written();
+            }
             hook(snapshot);
         }
         Ok(())
@@ -0,0 +1,129 @@
+use crate::world::{Bond, PolicyComponent};
+
+const EPS: f64 = 1e-9;
+const DAYS_PER_YEAR: f64 = 365.0;
+const AUCTION_WINDOW_DAYS: f64 = 30.0;
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Holders {
+    pub bank: f64,
+    pub households: f64,
+}
+
+impl Holders {
+    pub fn total(&self) -> f64 {
+        self.bank + self.households
+    }
+}
+
+pub fn refresh(policy: &mut PolicyComponent) {
+    policy.bonds.retain(|b| b.outstanding() > EPS);
+    policy.public_debt = policy.bonds.iter().map(|b| b.outstanding()).sum();
+}
+
+pub fn debt_to_gdp(policy: &PolicyComponent, revenue: f64, dt_days: f64) -> f64 {
+    let annual_gdp = if dt_days > EPS {
+        revenue.max(0.0) * DAYS_PER_YEAR / dt_days
+    } else {
+        0.0
+    };
+    if annual_gdp > EPS {
+        policy.public_debt / annual_gdp
+    } else {
+        0.0
+    }
+}
+
+pub fn sovereign_yield(policy: &PolicyComponent, base_rate: f64) -> f64 {
+    (base_rate
+        + policy.term_premium
+        + policy.debt_sensitivity.max(0.0) * (policy.debt_to_gdp - policy.debt_threshold).max(0.0)
+        + policy.approval_sensitivity.max(0.0) * (1.0 - policy.approval_rating).clamp(0.0, 1.0))
+    .max(0.0)
+}
+
+pub fn coupons(policy: &PolicyComponent, dt_days: f64) -> Holders {
+    let dt_years = dt_days / DAYS_PER_YEAR;
+    policy
+        .bonds
+        .iter()
+        .fold(Holders::default(), |acc, bond| Holders {
+            bank: acc.bank + bond.bank_holding * bond.coupon_rate * dt_years,
+            households: acc.households + bond.household_holding * bond.coupon_rate * dt_years,
+        })
+}
+
+pub fn mature(policy: &mut PolicyComponent, dt_days: f64) -> Holders {
+    let mut redeemed = Holders::default();
+    for bond in policy.bonds.iter_mut() {
+        bond.age_days += dt_days;
+        if bond.age_days + EPS >= bond.maturity_days {
+            redeemed.bank += bond.bank_holding;
+            redeemed.households += bond.household_holding;
+            bond.bank_holding = 0.0;
+            bond.household_holding = 0.0;
+        }
+    }
+    refresh(policy);
+    redeemed
+}
+
+pub fn issue(policy: &mut PolicyComponent, amount: f64, household_capacity: f64) -> Holders {
+    if amount <= EPS {
+        return Holders::default();
+    }
+    let households =
+        (amount * policy.household_bond_share.clamp(0.0, 1.0)).min(household_capacity.max(0.0));
+    let sold = Holders {
+        bank: amount - households,
+        households,
+    };
+    if let Some(bond) = policy
+        .bonds
+        .last_mut()
+        .filter(|b| b.age_days < AUCTION_WINDOW_DAYS)
+    {
+        let total = bond.outstanding() + amount;
+        bond.coupon_rate =
+            (bond.coupon_rate * bond.outstanding() + policy.bond_yield * amount) / total;
+        bond.bank_holding += sold.bank;
+        bond.household_holding += sold.households;
+        refresh(policy);
+        return sold;
+    }
+    policy.next_bond_id += 1;
+    policy.bonds.push(Bond {
+        id: policy.next_bond_id,
+        coupon_rate: policy.bond_yield,
+        maturity_days: policy.bond_maturity_days.max(1.0),
+        age_days: 0.0,
+        bank_holding: sold.bank,
+        household_holding: sold.households,
+    });
+    refresh(policy);
+    sold
+}
+
+pub fn buy_back(policy: &mut PolicyComponent, amount: f64) -> Holders {
+    let mut remaining = amount.max(0.0);
+    let mut bought = Holders::default();
+    for bond in policy.bonds.iter_mut() {
+        if remaining <= EPS {
+            break;
+        }
+        let outstanding = bond.outstanding();
+        let retired = remaining.min(outstanding);
+        let share = retired / outstanding;
+        bought.bank += bond.bank_holding * share;
+        bought.households += bond.household_holding * share;
+        bond.bank_holding *= 1.0 - share;
+        bond.household_holding *= 1.0 - share;
+        remaining -= retired;
+    }
+    refresh(policy);
+    bought
+}
+
+pub fn budget_identity_residual(policy: &PolicyComponent) -> f64 {
+    policy.budget_balance - (policy.last_cash_change - policy.last_debt_change)
+}
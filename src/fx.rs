@@ -0,0 +1,52 @@
+use crate::world::{Currency, EntityId, ExchangeRegime, FxMarket, FxObservation};
+
+const EPS: f64 = 1e-9;
+const DAYS_PER_YEAR: f64 = 365.0;
+
+pub fn currency(fx: &FxMarket, id: EntityId) -> Option<&Currency> {
+    fx.currencies.iter().find(|c| c.members.contains(&id))
+}
+
+pub fn rate(fx: &FxMarket, id: EntityId) -> f64 {
+    currency(fx, id).map(|c| c.rate).unwrap_or(1.0)
+}
+
+pub fn convert(fx: &FxMarket, from: EntityId, to: EntityId, amount: f64) -> f64 {
+    let target = rate(fx, to);
+    if target > EPS {
+        amount * rate(fx, from) / target
+    } else {
+        amount
+    }
+}
+
+pub fn drift(fx: &FxMarket, currency: &Currency, base_policy_rate: f64, dt_days: f64) -> f64 {
+    let trade_share = if currency.output > EPS {
+        currency.trade_balance / currency.output
+    } else {
+        0.0
+    };
+    let annual = fx.trade_sensitivity * trade_share
+        + fx.interest_sensitivity * (currency.policy_rate - base_policy_rate);
+    let bound = fx.max_daily_change.max(0.0) * dt_days;
+    (annual * dt_days / DAYS_PER_YEAR).clamp(-bound, bound)
+}
+
+pub fn anchor<'a>(fx: &'a FxMarket, currency: &Currency) -> Option<&'a Currency> {
+    if currency.regime != ExchangeRegime::Pegged {
+        return None;
+    }
+    match &currency.anchor {
+        Some(code) => fx.currencies.iter().find(|c| &c.code == code),
+        None => fx.currencies.first(),
+    }
+}
+
+pub fn record(currency: &mut Currency, tick: u64, history_ticks: usize) {
+    currency.history.push(FxObservation {
+        tick,
+        rate: currency.rate,
+    });
+    let excess = currency.history.len().saturating_sub(history_ticks.max(1));
+    currency.history.drain(..excess);
+}
@@ -0,0 +1,70 @@
+use crate::world::{Disease, Epidemic, HealthComponent};
+
+const EPS: f64 = 1e-9;
+
+pub struct Exposure {
+    pub population: f64,
+    pub imported_infectious: f64,
+    pub contact_reduction: f64,
+    pub vaccination_rate: f64,
+    pub fatality_multiplier: f64,
+}
+
+pub fn rescale(epidemic: &mut Epidemic, population: f64) {
+    let tracked =
+        epidemic.susceptible + epidemic.exposed + epidemic.infectious + epidemic.recovered;
+    if tracked <= EPS {
+        epidemic.susceptible = population.max(0.0);
+        return;
+    }
+    let factor = population.max(0.0) / tracked;
+    epidemic.susceptible *= factor;
+    epidemic.exposed *= factor;
+    epidemic.infectious *= factor;
+    epidemic.recovered *= factor;
+}
+
+pub fn step(epidemic: &mut Epidemic, disease: &Disease, exposure: &Exposure, dt_days: f64) -> f64 {
+    let population = exposure.population;
+    if population <= EPS {
+        epidemic.new_infections = 0.0;
+        epidemic.deaths = 0.0;
+        return 0.0;
+    }
+    let contact = (1.0 - exposure.contact_reduction).clamp(0.0, 1.0);
+    let pressure = (epidemic.infectious + exposure.imported_infectious.max(0.0)) / population;
+    let force = disease.transmission_rate * contact * pressure;
+    let infected = epidemic.susceptible * (1.0 - (-force * dt_days).exp());
+    let onset = epidemic.exposed * (1.0 - (-dt_days / disease.incubation_days).exp());
+    let resolved = epidemic.infectious * (1.0 - (-dt_days / disease.infectious_days).exp());
+    let fatality = (disease.fatality_rate * exposure.fatality_multiplier).clamp(0.0, 1.0);
+    let deaths = resolved * fatality;
+    let susceptible = epidemic.susceptible - infected;
+    let vaccinated = susceptible * (exposure.vaccination_rate.max(0.0) * dt_days).min(1.0);
+
+    epidemic.susceptible = susceptible - vaccinated;
+    epidemic.exposed += infected - onset;
+    epidemic.infectious += onset - resolved;
+    epidemic.recovered += resolved - deaths + vaccinated;
+    epidemic.vaccinated += vaccinated;
+    epidemic.new_infections = infected;
+    epidemic.deaths = deaths;
+    epidemic.cumulative_deaths += deaths;
+    epidemic.peak_infectious = epidemic.peak_infectious.max(epidemic.infectious);
+    deaths
+}
+
+pub fn infectious_share(health: &HealthComponent, population: f64) -> f64 {
+    if population <= EPS {
+        return 0.0;
+    }
+    (health.infectious() / population).clamp(0.0, 1.0)
+}
+
+pub fn fatality_multiplier(patients: f64, beds: f64, overload_multiplier: f64) -> f64 {
+    if patients <= beds || patients <= EPS {
+        return 1.0;
+    }
+    let untreated = 1.0 - beds.max(0.0) / patients;
+    1.0 + (overload_multiplier - 1.0).max(0.0) * untreated
+}
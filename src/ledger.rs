@@ -0,0 +1,151 @@
+use anyhow::{ensure, Result};
+
+use crate::{
+    fx,
+    world::{EntityId, FlowKind, Ledger, LedgerEntry, Sector, World},
+};
+
+const TOLERANCE: f64 = 1e-9;
+
+pub fn post(
+    ledger: &mut Ledger,
+    region: Option<EntityId>,
+    kind: FlowKind,
+    from: Sector,
+    to: Sector,
+    amount: f64,
+) {
+    if !amount.is_finite() || amount.abs() <= f64::EPSILON {
+        return;
+    }
+    let (from, to, amount) = if amount < 0.0 {
+        (to, from, -amount)
+    } else {
+        (from, to, amount)
+    };
+    let entry = LedgerEntry {
+        region: region.map(|id| id.raw()),
+        kind,
+        from,
+        to,
+        amount,
+    };
+    let base = entry.amount * rate(ledger, entry.region);
+    let (from, to) = (entry.from.index(), entry.to.index());
+    ledger.balances[from] -= base;
+    ledger.balances[to] += base;
+    ledger.matrix[from][to] += base;
+    match ledger.entries.iter_mut().find(|e| {
+        e.region == entry.region && e.kind == entry.kind && e.from == entry.from && e.to == entry.to
+    }) {
+        Some(existing) => existing.amount += entry.amount,
+        None => ledger.entries.push(entry),
+    }
+}
+
+fn rate(ledger: &Ledger, region: Option<u64>) -> f64 {
+    region
+        .and_then(|id| ledger.rates.get(&id))
+        .copied()
+        .unwrap_or(1.0)
+}
+
+pub fn open(world: &mut World, tick: u64) {
+    world.ledger.rates = world
+        .regions
+        .keys()
+        .map(|id| (id.raw(), fx::rate(&world.fx, *id)))
+        .collect();
+    let opening = positions(world);
+    let ledger = &mut world.ledger;
+    ledger.tick = tick;
+    ledger.opening = opening;
+    ledger.entries.clear();
+    ledger.matrix = [[0.0; 5]; 5];
+}
+
+pub fn positions(world: &World) -> [f64; 5] {
+    let mut positions = [0.0; 5];
+    let base = |id: &EntityId| {
+        world
+            .ledger
+            .rates
+            .get(&id.raw())
+            .copied()
+            .unwrap_or_else(|| fx::rate(&world.fx, *id))
+    };
+    for (id, household) in &world.households {
+        let net = match world.household_agents.get(id) {
+            Some(households) => households
+                .agents
+                .iter()
+                .map(|a| a.weight * (a.deposits - a.debt))
+                .sum(),
+            None => household.deposits - household.debt,
+        };
+        positions[Sector::Households.index()] += net * base(id);
+    }
+    for (id, finance) in &world.finances {
+        let loans: f64 = finance.loans.iter().map(|l| l.outstanding).sum();
+        positions[Sector::Firms.index()] += (finance.bank_deposits - loans) * base(id);
+        positions[Sector::Banks.index()] += finance.capital * base(id);
+    }
+    for (id, policy) in &world.policies {
+        let held: f64 = policy.bonds.iter().map(|b| b.household_holding).sum();
+        positions[Sector::Households.index()] += held * base(id);
+        positions[Sector::Government.index()] +=
+            (policy.treasury_cash - policy.public_debt) * base(id);
+    }
+    positions
+}
+
+pub fn balance(ledger: &Ledger, sector: Sector) -> f64 {
+    ledger.balances[sector.index()]
+}
+
+pub fn net_flows(ledger: &Ledger) -> [f64; 5] {
+    let mut net = [0.0; 5];
+    for (from, row) in ledger.matrix.iter().enumerate() {
+        for (to, amount) in row.iter().enumerate() {
+            net[from] -= amount;
+            net[to] += amount;
+        }
+    }
+    net
+}
+
+pub fn verify(world: &World) -> Result<()> {
+    let closing = positions(world);
+    let ledger = &world.ledger;
+    let mut matrix = [[0.0; 5]; 5];
+    for entry in &ledger.entries {
+        matrix[entry.from.index()][entry.to.index()] += entry.amount * rate(ledger, entry.region);
+    }
+    for from in Sector::ALL {
+        for to in Sector::ALL {
+            let posted = matrix[from.index()][to.index()];
+            let recorded = ledger.matrix[from.index()][to.index()];
+            ensure!(
+                (posted - recorded).abs() <= TOLERANCE * (posted.abs() + 1.0),
+                "flow of funds from {from:?} to {to:?} does not match its entries at tick {}",
+                ledger.tick
+            );
+        }
+    }
+    let net = net_flows(ledger);
+    for sector in Sector::ALL {
+        if sector == Sector::RestOfWorld {
+            continue;
+        }
+        let i = sector.index();
+        let change = closing[i] - ledger.opening[i];
+        let scale = closing[i].abs() + ledger.opening[i].abs() + net[i].abs() + 1.0;
+        ensure!(
+            (change - net[i]).abs() <= TOLERANCE * scale,
+            "{sector:?} balances moved by {change:.6} but the ledger posted {:.6} at tick {}",
+            net[i],
+            ledger.tick
+        );
+    }
+    Ok(())
+}
@@ -0,0 +1,279 @@
+use crate::world::{Amortisation, BorrowerType, FinanceComponent, Loan, LoanAgeBucket};
+
+const EPS: f64 = 1e-9;
+const DAYS_PER_YEAR: f64 = 365.0;
+const AGE_BUCKETS: [(u32, Option<u32>); 5] = [
+    (0, Some(1)),
+    (1, Some(3)),
+    (3, Some(5)),
+    (5, Some(10)),
+    (10, None),
+];
+
+pub struct Servicing {
+    pub due: f64,
+    pub paid: f64,
+    pub capitalised: f64,
+}
+
+pub fn origination_rate(finance: &FinanceComponent) -> f64 {
+    (finance.policy_rate + finance.loan_rate_spread).max(0.0)
+}
+
+pub fn reset_flows(finance: &mut FinanceComponent, tick: u64) {
+    if finance.flows_tick == Some(tick) {
+        return;
+    }
+    finance.flows_tick = Some(tick);
+    finance.new_lending = 0.0;
+    finance.repayments = 0.0;
+    finance.interest_received = 0.0;
+    finance.defaults = 0.0;
+}
+
+pub fn originate(finance: &mut FinanceComponent, borrower: BorrowerType, amount: f64, day: f64) {
+    if !amount.is_finite() || amount <= EPS {
+        return;
+    }
+    let rate = origination_rate(finance);
+    let terms = match borrower {
+        BorrowerType::Firm => finance.firm_loan_terms,
+        BorrowerType::Infrastructure => finance.infrastructure_loan_terms,
+    };
+    let cohort_days = finance.cohort_days.max(EPS);
+    let cohort = (day.max(0.0) / cohort_days).floor() as u64;
+    finance.new_lending += amount;
+    finance.loan_balance += amount;
+
+    if let Some(loan) = finance.loans.iter_mut().find(|l| {
+        l.borrower == borrower
+            && l.cohort == cohort
+            && l.amortisation == terms.amortisation
+            && l.age_days < cohort_days
+    }) {
+        let total = loan.outstanding + amount;
+        loan.rate = (loan.rate * loan.outstanding + rate * amount) / total;
+        loan.principal += amount;
+        loan.outstanding = total;
+        return;
+    }
+    finance.next_loan_id += 1;
+    finance.loans.push(Loan {
+        id: finance.next_loan_id,
+        borrower,
+        amortisation: terms.amortisation,
+        principal: amount,
+        outstanding: amount,
+        rate,
+        maturity_days: terms.maturity_days.max(1.0),
+        age_days: 0.0,
+        cohort,
+        arrears: 0.0,
+    });
+}
+
+pub fn seed(finance: &mut FinanceComponent, amount: f64, cohorts: usize) {
+    if amount <= EPS || cohorts == 0 {
+        return;
+    }
+    let terms = finance.firm_loan_terms;
+    let rate = origination_rate(finance);
+    let share = amount / cohorts as f64;
+    for i in 0..cohorts {
+        let age_days = terms.maturity_days * i as f64 / cohorts as f64;
+        let remaining = 1.0 - age_days / terms.maturity_days.max(EPS);
+        finance.next_loan_id += 1;
+        finance.loans.push(Loan {
+            id: finance.next_loan_id,
+            borrower: BorrowerType::Firm,
+            amortisation: terms.amortisation,
+            principal: share / remaining.max(EPS),
+            outstanding: share,
+            rate,
+            maturity_days: terms.maturity_days.max(1.0),
+            age_days,
+            cohort: 0,
+            arrears: 0.0,
+        });
+    }
+    refresh(finance);
+}
+
+pub fn scheduled(loan: &Loan, dt_days: f64) -> (f64, f64) {
+    let period_rate = loan.rate.max(0.0) * dt_days / DAYS_PER_YEAR;
+    let interest = loan.outstanding * period_rate;
+    let remaining = loan.remaining_days();
+    if remaining <= dt_days + EPS {
+        return (interest, loan.outstanding);
+    }
+    let principal = match loan.amortisation {
+        Amortisation::Bullet => 0.0,
+        Amortisation::Linear => loan.outstanding * dt_days / remaining,
+        Amortisation::Annuity => {
+            let periods = remaining / dt_days.max(EPS);
+            let payment = if period_rate > EPS {
+                loan.outstanding * period_rate / (1.0 - (1.0 + period_rate).powf(-periods))
+            } else {
+                loan.outstanding / periods
+            };
+            (payment - interest).max(0.0)
+        }
+    };
+    (interest, principal.min(loan.outstanding))
+}
+
+pub fn service(finance: &mut FinanceComponent, available: f64, dt_days: f64) -> Servicing {
+    let mut cash = available.max(0.0);
+    let mut servicing = Servicing {
+        due: 0.0,
+        paid: 0.0,
+        capitalised: 0.0,
+    };
+    for loan in finance.loans.iter_mut() {
+        let (interest, principal) = scheduled(loan, dt_days);
+        let principal_due = (principal + loan.arrears).min(loan.outstanding);
+        servicing.due += interest + principal_due;
+
+        let interest_paid = interest.min(cash);
+        cash -= interest_paid;
+        let unpaid_interest = interest - interest_paid;
+        let principal_paid = principal_due.min(cash);
+        cash -= principal_paid;
+
+        loan.outstanding += unpaid_interest - principal_paid;
+        loan.arrears = (principal_due - principal_paid + unpaid_interest).min(loan.outstanding);
+        loan.age_days += dt_days;
+        finance.interest_received += interest_paid;
+        finance.repayments += principal_paid;
+        servicing.paid += interest_paid + principal_paid;
+        servicing.capitalised += unpaid_interest;
+    }
+    servicing
+}
+
+pub fn write_off(
+    finance: &mut FinanceComponent,
+    borrower_stress: impl Fn(BorrowerType) -> f64,
+    dt_days: f64,
+) -> f64 {
+    let dt_years = dt_days / DAYS_PER_YEAR;
+    let mut total = 0.0;
+    for loan in finance.loans.iter_mut() {
+        if loan.outstanding <= EPS {
+            continue;
+        }
+        let arrears_share = (loan.arrears / loan.outstanding).clamp(0.0, 1.0);
+        let hazard = finance.default_rate.max(0.0)
+            * (1.0 + borrower_stress(loan.borrower).max(0.0))
+            * (1.0 + finance.arrears_default_multiplier.max(0.0) * arrears_share);
+        let share = (hazard * dt_years).clamp(0.0, 1.0);
+        let loss = loan.outstanding * share;
+        loan.outstanding -= loss;
+        loan.arrears *= 1.0 - share;
+        total += loss;
+    }
+    finance.defaults += total;
+    finance.cumulative_defaults += total;
+    total
+}
+
+pub fn refresh(finance: &mut FinanceComponent) {
+    finance.loans.retain(|l| l.outstanding > EPS);
+    finance.loan_balance = finance.loans.iter().map(|l| l.outstanding).sum();
+    finance.arrears = finance.loans.iter().map(|l| l.arrears).sum();
+}
+
+pub fn age_distribution(finance: &FinanceComponent) -> Vec<LoanAgeBucket> {
+    let mut buckets: Vec<LoanAgeBucket> = AGE_BUCKETS
+        .iter()
+        .map(|&(min_years, max_years)| LoanAgeBucket {
+            min_years,
+            max_years,
+            loans: 0,
+            outstanding: 0.0,
+        })
+        .collect();
+    for loan in &finance.loans {
+        let years = loan.age_days / DAYS_PER_YEAR;
+        if let Some(bucket) = buckets
+            .iter_mut()
+            .find(|b| b.max_years.map(|max| years < max as f64).unwrap_or(true))
+        {
+            bucket.loans += 1;
+            bucket.outstanding += loan.outstanding;
+        }
+    }
+    buckets
+}
+
+pub fn risk_weighted_assets(finance: &FinanceComponent) -> f64 {
+    finance
+        .loans
+        .iter()
+        .map(|l| {
+            let weight = match l.borrower {
+                BorrowerType::Firm => finance.firm_risk_weight,
+                BorrowerType::Infrastructure => finance.infrastructure_risk_weight,
+            };
+            l.outstanding * weight.max(0.0)
+        })
+        .sum()
+}
+
+pub fn update_ratios(finance: &mut FinanceComponent, household_deposits: f64) {
+    let rwa = risk_weighted_assets(finance);
+    finance.capital_ratio = if rwa > EPS {
+        finance.capital / rwa
+    } else if finance.capital >= 0.0 {
+        1.0
+    } else {
+        0.0
+    };
+    let funding = funding(finance, household_deposits);
+    let liquid = liquid_assets(finance, household_deposits).max(0.0);
+    finance.liquidity_ratio = if funding > EPS {
+        liquid / funding
+    } else if finance.loan_balance <= finance.capital.max(0.0) {
+        1.0
+    } else {
+        0.0
+    };
+    let gap = |ratio: f64, minimum: f64| {
+        if minimum > EPS {
+            (1.0 - ratio / minimum).clamp(0.0, 1.0)
+        } else {
+            0.0
+        }
+    };
+    finance.credit_rationing = gap(finance.capital_ratio, finance.min_capital_ratio)
+        .max(gap(finance.liquidity_ratio, finance.min_liquidity_ratio));
+}
+
+pub fn funding(finance: &FinanceComponent, household_deposits: f64) -> f64 {
+    finance.bank_deposits.max(0.0) + household_deposits.max(0.0) + finance.interbank_borrowing
+}
+
+pub fn liquid_assets(finance: &FinanceComponent, household_deposits: f64) -> f64 {
+    funding(finance, household_deposits) + finance.capital
+        - finance.loan_balance
+        - finance.interbank_lending
+        - finance.government_bonds
+}
+
+pub fn liquidity_surplus(finance: &FinanceComponent, household_deposits: f64) -> f64 {
+    liquid_assets(finance, household_deposits)
+        - finance.min_liquidity_ratio.max(0.0) * funding(finance, household_deposits)
+}
+
+pub fn is_insolvent(finance: &FinanceComponent) -> bool {
+    finance.capital < 0.0
+}
+
+pub fn recapitalisation_need(finance: &FinanceComponent) -> f64 {
+    (finance.recapitalisation_ratio.max(0.0) * risk_weighted_assets(finance) - finance.capital)
+        .max(0.0)
+}
+
+pub fn surplus_capital(finance: &FinanceComponent) -> f64 {
+    (finance.capital - finance.min_capital_ratio.max(0.0) * risk_weighted_assets(finance)).max(0.0)
+}
@@ -1,9 +1,28 @@
+pub mod agents;
+pub mod demography;
+pub mod deposits;
+pub mod distribution;
+pub mod education;
 pub mod engine;
+pub mod fiscal;
+pub mod fx;
+pub mod health;
+pub mod ledger;
+pub mod lending;
+pub mod market;
+pub mod monetary;
+pub mod power;
+pub mod production;
+pub mod projects;
 pub mod rng;
 pub mod scenario;
 pub mod snapshot;
+pub mod storage;
+pub mod supply_chain;
 pub mod systems;
 pub mod technology;
+pub mod transport;
+pub mod weather;
 pub mod web;
 pub mod world;
 
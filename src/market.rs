@@ -0,0 +1,232 @@
+use crate::world::MarketModel;
+
+const EPS: f64 = 1e-9;
+
+#[derive(Debug, Clone, Copy)]
+pub struct MarketRequest {
+    pub price: f64,
+    pub desired: f64,
+    pub demand: f64,
+    pub budget: f64,
+    pub supply: f64,
+    pub target_inventory: f64,
+    pub adjustment_rate: f64,
+    pub cost_floor: f64,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MarketClearing {
+    pub clearing_price: f64,
+    pub volume: f64,
+    pub unfilled_demand: f64,
+    pub unfilled_supply: f64,
+    pub next_price: f64,
+}
+
+pub trait ClearingMechanism: Send + Sync {
+    fn name(&self) -> &'static str;
+    fn clear(&self, request: &MarketRequest) -> MarketClearing;
+}
+
+pub struct PostedPrice;
+
+impl ClearingMechanism for PostedPrice {
+    fn name(&self) -> &'static str {
+        "posted_price"
+    }
+
+    fn clear(&self, request: &MarketRequest) -> MarketClearing {
+        let volume = request.demand.max(0.0).min(request.supply.max(0.0));
+        let shortage_ratio = shortage_ratio(request.desired, volume);
+        let floor = request.cost_floor.max(0.0);
+        let mut next_price = request.price;
+        adjust_price(
+            &mut next_price,
+            shortage_ratio,
+            request.supply - volume,
+            request.target_inventory,
+            request.adjustment_rate,
+        );
+        MarketClearing {
+            clearing_price: request.price.max(floor),
+            volume,
+            unfilled_demand: (request.demand - volume).max(0.0),
+            unfilled_supply: (request.supply - volume).max(0.0),
+            next_price: next_price.max(floor),
+        }
+    }
+}
+
+pub struct CallAuction {
+    pub levels: usize,
+    pub spread: f64,
+}
+
+struct Order {
+    limit: f64,
+    quantity: f64,
+}
+
+impl CallAuction {
+    fn bids(&self, quantity: f64, high: f64, low: f64) -> Vec<Order> {
+        let levels = self.levels.max(1);
+        let slice = quantity / levels as f64;
+        (0..levels)
+            .map(|level| {
+                let t = if levels > 1 {
+                    level as f64 / (levels - 1) as f64
+                } else {
+                    0.0
+                };
+                Order {
+                    limit: high + (low - high) * t,
+                    quantity: slice,
+                }
+            })
+            .collect()
+    }
+
+    fn asks(
+        &self,
+        quantity: f64,
+        reference: f64,
+        target_inventory: f64,
+        rate: f64,
+        floor: f64,
+    ) -> Vec<Order> {
+        let levels = self.levels.max(1);
+        let slice = quantity / levels as f64;
+        let target = target_inventory.max(EPS);
+        (0..levels)
+            .map(|level| {
+                let cover = ((quantity - level as f64 * slice) / target).max(EPS);
+                Order {
+                    limit: (reference
+                        * cover
+                            .powf(-rate.clamp(0.0, 1.0))
+                            .min(1.0 + self.spread.clamp(0.0, 0.9)))
+                    .max(floor),
+                    quantity: slice,
+                }
+            })
+            .collect()
+    }
+}
+
+impl ClearingMechanism for CallAuction {
+    fn name(&self) -> &'static str {
+        "order_book"
+    }
+
+    fn clear(&self, request: &MarketRequest) -> MarketClearing {
+        let spread = self.spread.clamp(0.0, 0.9);
+        let floor = request.cost_floor.max(0.0);
+        let reference = request.price.max(0.1);
+        let bid_quantity = request.demand.max(0.0);
+        let ask_quantity = request.supply.max(0.0);
+
+        let affordable = if bid_quantity > EPS {
+            (request.budget / (bid_quantity * reference)).clamp(1.0 - spread, 1.0 + spread)
+        } else {
+            1.0
+        };
+        let ceiling = reference * affordable;
+        let bids = self.bids(bid_quantity, ceiling * (1.0 + spread), ceiling);
+        let asks = self.asks(
+            ask_quantity,
+            reference,
+            request.target_inventory,
+            request.adjustment_rate,
+            floor,
+        );
+
+        let mut volume = 0.0;
+        let mut last_bid = bids.first().map(|o| o.limit).unwrap_or(reference);
+        let mut last_ask = asks.first().map(|o| o.limit).unwrap_or(reference);
+        let (mut b, mut a) = (0, 0);
+        let mut bid_left = bids.first().map(|o| o.quantity).unwrap_or(0.0);
+        let mut ask_left = asks.first().map(|o| o.quantity).unwrap_or(0.0);
+        while b < bids.len() && a < asks.len() && bids[b].limit >= asks[a].limit {
+            let traded = bid_left.min(ask_left);
+            volume += traded;
+            last_bid = bids[b].limit;
+            last_ask = asks[a].limit;
+            bid_left -= traded;
+            ask_left -= traded;
+            if bid_left <= EPS {
+                b += 1;
+                bid_left = bids.get(b).map(|o| o.quantity).unwrap_or(0.0);
+            }
+            if ask_left <= EPS {
+                a += 1;
+                ask_left = asks.get(a).map(|o| o.quantity).unwrap_or(0.0);
+            }
+        }
+        let clearing_price = if volume > EPS {
+            let next_bid = bids.get(b).map(|o| o.limit).unwrap_or(f64::NEG_INFINITY);
+            let next_ask = asks.get(a).map(|o| o.limit).unwrap_or(f64::INFINITY);
+            let low = last_ask.max(next_bid);
+            let high = last_bid.min(next_ask);
+            (0.5 * (low + high.max(low))).max(0.1)
+        } else if bid_quantity > EPS && ask_quantity > EPS {
+            (0.5 * (last_bid + last_ask)).max(0.1)
+        } else {
+            reference
+        }
+        .max(floor);
+        MarketClearing {
+            clearing_price,
+            volume,
+            unfilled_demand: (bid_quantity - volume).max(0.0),
+            unfilled_supply: (ask_quantity - volume).max(0.0),
+            next_price: clearing_price,
+        }
+    }
+}
+
+pub fn clear(
+    model: MarketModel,
+    levels: usize,
+    spread: f64,
+    request: &MarketRequest,
+) -> MarketClearing {
+    let auction = CallAuction { levels, spread };
+    let mechanism: &dyn ClearingMechanism = match model {
+        MarketModel::PostedPrice => &PostedPrice,
+        MarketModel::OrderBook => &auction,
+    };
+    mechanism.clear(request)
+}
+
+pub fn shortage_ratio(desired: f64, sold: f64) -> f64 {
+    if desired > EPS {
+        ((desired - sold).max(0.0) / desired).clamp(0.0, 1.0)
+    } else {
+        0.0
+    }
+}
+
+pub fn adjust_price(
+    price: &mut f64,
+    shortage_ratio: f64,
+    inventory: f64,
+    target_inventory: f64,
+    adjustment_rate: f64,
+) {
+    let mut next_price = *price;
+    if shortage_ratio > 0.001 {
+        let pressure = shortage_ratio.min(1.0);
+        next_price *= 1.0 + adjustment_rate * pressure;
+    } else {
+        let ratio = if target_inventory > EPS {
+            inventory / target_inventory
+        } else {
+            1.0
+        };
+        if ratio > 1.15 {
+            let drop = ((ratio - 1.0) / ratio).min(0.5);
+            next_price *= 1.0 - adjustment_rate * drop;
+        }
+    }
+    *price = next_price.max(0.1);
+}
@@ -0,0 +1,112 @@
+use crate::{
+    fx,
+    world::{CentralBank, EntityId, GapMeasure, World},
+};
+
+const EPS: f64 = 1e-9;
+const DAYS_PER_YEAR: f64 = 365.0;
+
+pub struct Conditions {
+    pub price_level: f64,
+    pub unemployment_rate: f64,
+    pub output: f64,
+}
+
+pub fn conditions(world: &World, members: &[EntityId]) -> Conditions {
+    let (mut basket_cost, mut basket_units) = (0.0, 0.0);
+    let (mut unemployed, mut labour_force) = (0.0, 0.0);
+    let mut revenue = 0.0;
+    for id in members {
+        let (Some(population), Some(economy)) = (world.population(*id), world.economy(*id)) else {
+            continue;
+        };
+        let citizens = population.citizens as f64;
+        basket_cost += citizens
+            * (economy.food_price * population.food_consumption_per_capita
+                + economy.energy_price * population.energy_consumption_per_capita);
+        basket_units += citizens
+            * (population.food_consumption_per_capita + population.energy_consumption_per_capita);
+        labour_force += population.labour_force as f64;
+        unemployed += population.labour_force.saturating_sub(population.employed) as f64;
+        revenue += economy.sales_revenue;
+    }
+    let price_level = if basket_units > EPS {
+        basket_cost / basket_units
+    } else {
+        0.0
+    };
+    Conditions {
+        price_level,
+        unemployment_rate: if labour_force > EPS {
+            unemployed / labour_force
+        } else {
+            0.0
+        },
+        output: if price_level > EPS {
+            revenue / price_level
+        } else {
+            0.0
+        },
+    }
+}
+
+pub fn annualised_inflation(bank: &CentralBank, elapsed_days: f64) -> f64 {
+    if bank.reference_price_level <= EPS || bank.price_level <= EPS || elapsed_days <= EPS {
+        return 0.0;
+    }
+    (bank.price_level / bank.reference_price_level).powf(DAYS_PER_YEAR / elapsed_days) - 1.0
+}
+
+pub fn activity_gap(bank: &CentralBank, conditions: &Conditions) -> f64 {
+    match bank.gap_measure {
+        GapMeasure::Unemployment => bank.target_unemployment_rate - conditions.unemployment_rate,
+        GapMeasure::Output => {
+            if bank.potential_output > EPS {
+                conditions.output / bank.potential_output - 1.0
+            } else {
+                0.0
+            }
+        }
+    }
+}
+
+pub fn taylor_rate(bank: &CentralBank) -> f64 {
+    bank.neutral_rate
+        + bank.inflation
+        + bank.inflation_weight * (bank.inflation - bank.inflation_target)
+        + bank.gap_weight * bank.gap
+}
+
+pub fn decide(bank: &CentralBank, tick: u64) -> f64 {
+    if let Some(guidance) = &bank.forward_guidance {
+        if tick < guidance.until_tick {
+            return guidance.rate.max(bank.effective_lower_bound);
+        }
+    }
+    let smoothing = bank.smoothing.clamp(0.0, 1.0);
+    let rate = smoothing * bank.policy_rate + (1.0 - smoothing) * taylor_rate(bank);
+    rate.max(bank.effective_lower_bound)
+}
+
+pub fn anchor_policy_rate(world: &World, bank: &CentralBank) -> Option<f64> {
+    let code = bank.currency.as_ref()?;
+    let currency = world.fx().currencies.iter().find(|c| &c.code == code)?;
+    let anchor = fx::anchor(world.fx(), currency)?;
+    if let Some(issuer) = world
+        .central_banks()
+        .iter()
+        .find(|b| b.currency.as_ref() == Some(&anchor.code))
+    {
+        return Some(issuer.policy_rate);
+    }
+    let rates: Vec<f64> = anchor
+        .members
+        .iter()
+        .filter_map(|id| world.finance(*id).map(|f| f.policy_rate))
+        .collect();
+    if rates.is_empty() {
+        None
+    } else {
+        Some(rates.iter().sum::<f64>() / rates.len() as f64)
+    }
+}
@@ -0,0 +1,110 @@
+use crate::world::InfrastructureComponent;
+
+const EPS: f64 = 1e-9;
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Dispatch {
+    pub dispatched: f64,
+    pub curtailed: f64,
+    pub unmet: f64,
+    pub price: Option<f64>,
+}
+
+pub fn condition(infra: &InfrastructureComponent) -> f64 {
+    let in_service = infra.nameplate_capacity - infra.forced_out_capacity.max(0.0);
+    if in_service > EPS {
+        (infra.power_capacity / in_service).clamp(0.0, 1.0)
+    } else {
+        1.0
+    }
+}
+
+pub fn merit_order(infra: &InfrastructureComponent) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..infra.plants.len()).collect();
+    order.sort_by(|a, b| {
+        infra.plants[*a]
+            .marginal_cost
+            .total_cmp(&infra.plants[*b].marginal_cost)
+            .then(infra.plants[*a].id.cmp(&infra.plants[*b].id))
+    });
+    order
+}
+
+pub fn availability(infra: &mut InfrastructureComponent, fuel: Option<f64>, dt_days: f64) -> f64 {
+    let condition = condition(infra);
+    let mut fuel_left = fuel.map(|fuel| fuel.max(0.0));
+    let mut capacity = 0.0;
+    for idx in merit_order(infra) {
+        let plant = &mut infra.plants[idx];
+        plant.available = if plant.online {
+            plant.capacity.max(0.0) * plant.capacity_factor.clamp(0.0, 1.0) * condition * dt_days
+        } else {
+            0.0
+        };
+        if let Some(left) = fuel_left.as_mut() {
+            if plant.fuel_per_unit > EPS {
+                plant.available = plant.available.min(*left / plant.fuel_per_unit);
+                *left = (*left - plant.available * plant.fuel_per_unit).max(0.0);
+            }
+        }
+        capacity += plant.available;
+    }
+    capacity
+}
+
+pub fn dispatch(
+    infra: &mut InfrastructureComponent,
+    generation: f64,
+    requirement: f64,
+    fuel: Option<f64>,
+    dt_days: f64,
+) -> Dispatch {
+    let capacity = availability(infra, fuel, dt_days);
+    let target = generation.max(0.0).min(requirement.max(0.0));
+    let mut remaining = target;
+    let mut setter = None;
+    let mut highest = None;
+    infra.emissions = 0.0;
+    for idx in merit_order(infra) {
+        let plant = &mut infra.plants[idx];
+        plant.output = remaining.min(plant.available);
+        remaining -= plant.output;
+        plant.utilisation = if plant.available > EPS {
+            plant.output / plant.available
+        } else {
+            0.0
+        };
+        plant.fuel_used = plant.output * plant.fuel_per_unit.max(0.0);
+        plant.emissions = plant.output * plant.emissions_per_unit.max(0.0);
+        infra.emissions += plant.emissions;
+        if plant.available > EPS {
+            highest = Some((plant.id, plant.marginal_cost));
+        }
+        if plant.output > EPS {
+            setter = Some((plant.id, plant.marginal_cost));
+        }
+    }
+    infra.cumulative_emissions += infra.emissions;
+    let dispatched = target - remaining;
+    let unmet = (requirement - capacity).max(0.0);
+    let marginal = if unmet > EPS {
+        highest
+    } else {
+        setter.or(highest)
+    };
+    infra.price_setter = marginal.map(|(id, _)| id);
+    let scarcity = if requirement > EPS {
+        unmet / requirement
+    } else {
+        0.0
+    };
+    let price = marginal
+        .map(|(_, cost)| cost.max(0.0) * (1.0 + infra.scarcity_premium.max(0.0) * scarcity));
+    infra.marginal_price = price.unwrap_or(0.0);
+    Dispatch {
+        dispatched,
+        curtailed: generation.max(0.0) - dispatched,
+        unmet,
+        price,
+    }
+}
@@ -0,0 +1,77 @@
+use crate::world::CapitalComponent;
+
+const EPS: f64 = 1e-9;
+
+fn capital_ratio(capital: &CapitalComponent, workers: f64) -> f64 {
+    if workers <= EPS || capital.reference_per_worker <= EPS {
+        return 1.0;
+    }
+    (capital.stock.max(0.0) / (workers * capital.reference_per_worker)).max(EPS)
+}
+
+fn rho(capital: &CapitalComponent) -> Option<f64> {
+    let sigma = capital.substitution_elasticity.max(0.05);
+    if (sigma - 1.0).abs() < 1e-6 {
+        None
+    } else {
+        Some((sigma - 1.0) / sigma)
+    }
+}
+
+pub fn output_multiplier(capital: &CapitalComponent, workers: f64) -> f64 {
+    let alpha = capital.capital_share.clamp(0.0, 0.95);
+    let ratio = capital_ratio(capital, workers);
+    match rho(capital) {
+        None => ratio.powf(alpha),
+        Some(rho) => (alpha * ratio.powf(rho) + (1.0 - alpha)).powf(1.0 / rho),
+    }
+}
+
+pub fn capital_income_share(capital: &CapitalComponent, workers: f64) -> f64 {
+    let alpha = capital.capital_share.clamp(0.0, 0.95);
+    match rho(capital) {
+        None => alpha,
+        Some(rho) => {
+            let weighted = alpha * capital_ratio(capital, workers).powf(rho);
+            weighted / (weighted + 1.0 - alpha)
+        }
+    }
+}
+
+pub fn steady_state_capital(
+    capital_share: f64,
+    annual_revenue: f64,
+    loan_rate: f64,
+    depreciation_rate: f64,
+) -> f64 {
+    let user_cost = (loan_rate.max(0.0) + depreciation_rate.max(0.0)).max(0.01);
+    capital_share.clamp(0.0, 0.95) * annual_revenue.max(0.0) / user_cost
+}
+
+pub fn invest(
+    capital: &mut CapitalComponent,
+    workers: f64,
+    revenue: f64,
+    loan_rate: f64,
+    dt_days: f64,
+) -> f64 {
+    let annual_revenue = if dt_days > EPS {
+        revenue * 365.0 / dt_days
+    } else {
+        0.0
+    };
+    capital.desired_stock = steady_state_capital(
+        capital_income_share(capital, workers),
+        annual_revenue,
+        loan_rate,
+        capital.depreciation_rate,
+    );
+    let depreciation =
+        capital.stock.max(0.0) * capital.depreciation_rate.max(0.0) * dt_days / 365.0;
+    let adjustment = (capital.adjustment_speed.max(0.0) * dt_days).min(1.0);
+    let gross = (depreciation + adjustment * (capital.desired_stock - capital.stock)).max(0.0);
+    capital.stock = (capital.stock + gross - depreciation).max(0.0);
+    capital.depreciation = depreciation;
+    capital.investment = gross;
+    gross
+}
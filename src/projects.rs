@@ -0,0 +1,226 @@
+use rand::Rng;
+
+use crate::world::{
+    AssetKind, InfrastructureAsset, InfrastructureComponent, InfrastructureProject,
+    PlantTechnology, PowerPlant, ProjectTemplate,
+};
+
+const EPS: f64 = 1e-9;
+const POWER_SHARE: f64 = 0.6;
+
+pub fn age(infra: &mut InfrastructureComponent, dt: f64) {
+    let base = infra.degradation_rate.max(0.0);
+    let ageing = infra.ageing.max(0.0);
+    for asset in infra.assets.iter_mut() {
+        let factor = 1.0 + ageing * asset.age_days.max(0.0) / 365.0;
+        let rate = if asset.kind.is_power() {
+            base
+        } else {
+            base * 0.8
+        };
+        let degrade = (rate * factor * dt).clamp(0.0, 0.5);
+        asset.condition = (asset.condition * (1.0 - degrade)).clamp(0.0, 1.0);
+        asset.maintenance_cost = asset.base_maintenance.max(0.0) * factor;
+        asset.age_days += dt;
+    }
+}
+
+pub fn refresh_capacity(infra: &mut InfrastructureComponent) {
+    let effective = |power: bool| -> f64 {
+        infra
+            .assets
+            .iter()
+            .filter(|a| a.kind.is_power() == power && a.in_service())
+            .map(|a| a.capacity * a.condition)
+            .sum()
+    };
+    infra.power_capacity = effective(true);
+    infra.forced_out_capacity = infra
+        .assets
+        .iter()
+        .filter(|a| a.plant.is_some() && !a.in_service())
+        .map(|a| a.capacity)
+        .sum();
+    infra.transport_capacity = effective(false);
+    infra.maintenance_cost = infra.assets.iter().map(|a| a.maintenance_cost).sum();
+}
+
+pub fn power_share(infra: &InfrastructureComponent) -> f64 {
+    let spent = infra
+        .assets
+        .iter()
+        .map(|a| (a.kind, a.cost))
+        .chain(infra.projects.iter().map(|p| (p.template.kind, p.funded)));
+    let (power, total) = spent.fold((0.0, 0.0), |(power, total), (kind, cost)| {
+        let power = if kind.is_power() { power + cost } else { power };
+        (power, total + cost)
+    });
+    if total > EPS {
+        power / total
+    } else {
+        0.0
+    }
+}
+
+pub fn choose(
+    infra: &InfrastructureComponent,
+    priority: Option<AssetKind>,
+    energy_shortage: f64,
+    transport_shortfall: f64,
+) -> Option<ProjectTemplate> {
+    let best = |accept: &dyn Fn(&ProjectTemplate) -> bool| {
+        let value = |t: &ProjectTemplate| t.capacity / t.cost.max(EPS);
+        infra
+            .catalogue
+            .iter()
+            .filter(|t| !t.kind.is_power() || !infra.plants.is_empty())
+            .filter(|t| accept(t))
+            .fold(None::<&ProjectTemplate>, |best, t| match best {
+                Some(b) if value(b) >= value(t) => Some(b),
+                _ => Some(t),
+            })
+            .cloned()
+    };
+    if let Some(kind) = priority {
+        if let Some(template) = best(&|t| t.kind == kind) {
+            return Some(template);
+        }
+    }
+    let power = if (energy_shortage - transport_shortfall).abs() > EPS {
+        energy_shortage > transport_shortfall
+    } else {
+        power_share(infra) < POWER_SHARE
+    };
+    best(&|t| t.kind.is_power() == power).or_else(|| best(&|_| true))
+}
+
+pub fn fund(
+    infra: &mut InfrastructureComponent,
+    tick: u64,
+    priority: Option<AssetKind>,
+    energy_shortage: f64,
+    transport_shortfall: f64,
+) {
+    let mut budget = infra.pending_investment.max(0.0);
+    for project in infra.projects.iter_mut() {
+        let take = (project.template.cost - project.funded).clamp(0.0, budget);
+        project.funded += take;
+        budget -= take;
+    }
+    if budget > EPS {
+        if let Some(template) = choose(infra, priority, energy_shortage, transport_shortfall) {
+            let funded = template.cost.max(0.0).min(budget);
+            budget -= funded;
+            infra.projects.push(InfrastructureProject {
+                id: infra.next_asset_id,
+                template,
+                started_tick: tick,
+                funded,
+                elapsed_days: 0.0,
+            });
+            infra.next_asset_id += 1;
+        }
+    }
+    infra.pending_investment = budget;
+}
+
+pub fn advance(
+    infra: &mut InfrastructureComponent,
+    tick: u64,
+    dt: f64,
+) -> Vec<InfrastructureAsset> {
+    let mut completed = Vec::new();
+    let mut building = Vec::new();
+    for mut project in std::mem::take(&mut infra.projects) {
+        let template = &project.template;
+        let funded_share = if template.cost > EPS {
+            (project.funded / template.cost).clamp(0.0, 1.0)
+        } else {
+            1.0
+        };
+        project.elapsed_days =
+            (project.elapsed_days + dt).min(template.build_days.max(0.0) * funded_share);
+        if funded_share >= 1.0 - EPS && project.elapsed_days >= template.build_days - EPS {
+            completed.push(InfrastructureAsset {
+                id: project.id,
+                name: template.name.clone(),
+                kind: template.kind,
+                capacity: template.capacity.max(0.0),
+                cost: template.cost.max(0.0),
+                condition: 1.0,
+                built_tick: tick,
+                age_days: 0.0,
+                base_maintenance: template.maintenance_cost.max(0.0),
+                maintenance_cost: template.maintenance_cost.max(0.0),
+                plant: None,
+                outage_days: 0.0,
+            });
+            if template.kind.is_power() {
+                let id = infra.plants.iter().map(|p| p.id).max().unwrap_or(0) + 1;
+                if let Some(asset) = completed.last_mut() {
+                    asset.plant = Some(id);
+                }
+                infra.plants.push(PowerPlant {
+                    id,
+                    name: template.name.clone(),
+                    technology: template.technology.unwrap_or(PlantTechnology::Gas),
+                    capacity: template.capacity.max(0.0),
+                    marginal_cost: template.marginal_cost.max(0.0),
+                    fuel_per_unit: template.fuel_per_unit.max(0.0),
+                    emissions_per_unit: template.emissions_per_unit.max(0.0),
+                    online: true,
+                    capacity_factor: 1.0,
+                    available: 0.0,
+                    output: 0.0,
+                    utilisation: 0.0,
+                    fuel_used: 0.0,
+                    emissions: 0.0,
+                });
+                infra.nameplate_capacity += template.capacity.max(0.0);
+            }
+        } else {
+            building.push(project);
+        }
+    }
+    infra.projects = building;
+    infra.assets.extend(completed.iter().cloned());
+    completed
+}
+
+pub fn outages<R: Rng + ?Sized>(
+    infra: &mut InfrastructureComponent,
+    rng: &mut R,
+    dt: f64,
+) -> Vec<InfrastructureAsset> {
+    let duration = infra.outage_days.max(0.0) / infra.maintenance_funding.clamp(0.2, 1.0);
+    let hazard = infra.outage_rate.max(0.0) * (1.0 - infra.reliability.clamp(0.0, 1.0));
+    let mut tripped = Vec::new();
+    let mut switched = Vec::new();
+    for asset in infra.assets.iter_mut() {
+        if !asset.in_service() {
+            asset.outage_days = (asset.outage_days - dt).max(0.0);
+            if asset.in_service() {
+                switched.extend(asset.plant.map(|id| (id, true)));
+            }
+            continue;
+        }
+        let online = asset
+            .plant
+            .is_none_or(|id| infra.plants.iter().any(|p| p.id == id && p.online));
+        if !online || asset.capacity <= EPS {
+            continue;
+        }
+        if rng.gen::<f64>() < 1.0 - (-hazard * dt).exp() {
+            asset.outage_days = duration.max(dt);
+            switched.extend(asset.plant.map(|id| (id, false)));
+            tripped.push(asset.clone());
+        }
+    }
+    for (id, online) in switched {
+        if let Some(plant) = infra.plants.iter_mut().find(|p| p.id == id) {
+            plant.online = online;
+        }
+    }
+    infra.outage_count += tripped.len() as u64;
+    tripped
+}
@@ -51,3 +51,49 @@ impl<'a> RngCore for SystemRng<'a> {
         self.inner.try_fill_bytes(dest)
     }
 }
+
+fn standard_normal<R: rand::Rng + ?Sized>(rng: &mut R) -> f64 {
+    let u1: f64 = rng.gen_range(f64::EPSILON..1.0);
+    let u2: f64 = rng.gen();
+    (-2.0 * u1.ln()).sqrt() * (std::f64::consts::TAU * u2).cos()
+}
+
+pub fn poisson<R: rand::Rng + ?Sized>(rng: &mut R, lambda: f64) -> u64 {
+    if !lambda.is_finite() || lambda <= 0.0 {
+        return 0;
+    }
+    if lambda < 30.0 {
+        let limit = (-lambda).exp();
+        let mut product: f64 = rng.gen();
+        let mut count = 0;
+        while product > limit {
+            product *= rng.gen::<f64>();
+            count += 1;
+        }
+        return count;
+    }
+    (lambda + lambda.sqrt() * standard_normal(rng))
+        .round()
+        .max(0.0) as u64
+}
+
+pub fn binomial<R: rand::Rng + ?Sized>(rng: &mut R, trials: u64, probability: f64) -> u64 {
+    let p = probability.clamp(0.0, 1.0);
+    if trials == 0 || p == 0.0 {
+        return 0;
+    }
+    if p == 1.0 {
+        return trials;
+    }
+    if trials < 64 {
+        return (0..trials).filter(|_| rng.gen::<f64>() < p).count() as u64;
+    }
+    let mean = trials as f64 * p;
+    if mean < 30.0 {
+        return poisson(rng, mean).min(trials);
+    }
+    let sd = (mean * (1.0 - p)).sqrt();
+    (mean + sd * standard_normal(rng))
+        .round()
+        .clamp(0.0, trials as f64) as u64
+}
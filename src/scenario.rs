@@ -3,13 +3,24 @@ use std::{
     path::{Path, PathBuf},
 };
 
-use anyhow::{Context, Result};
+use anyhow::{ensure, Context, Result};
+use chrono::NaiveDate;
 use serde::Deserialize;
 
 use crate::world::{
-    EconomyComponent, FinanceComponent, InfrastructureComponent, PolicyComponent,
-    PopulationComponent, RegionComponent, ResourceStock, TechnologyComponent, World,
+    AgeCohort, Amortisation, AssetKind, CapitalComponent, CentralBank, Currency, DemographicEvents,
+    DemographyComponent, DepositKind, Disease, DistributionComponent, EconomyComponent,
+    EducationComponent, EntityId, Epidemic, ExchangeRegime, FinanceComponent, ForwardGuidance,
+    FxMarket, GapMeasure, Good, HealthComponent, HealthState, HouseholdAgent,
+    HouseholdAgentsComponent, HouseholdComponent, IncomeGroup, InfrastructureAsset,
+    InfrastructureComponent, InterbankMarket, Intervention, InterventionKind, LoanTerms,
+    MarketComponent, MarketModel, MarketOutcome, MigrationState, PlantTechnology, PolicyComponent,
+    PopulationComponent, PopulationMode, PowerPlant, ProjectTemplate, RegionComponent,
+    RegionDistance, Resolution, ResourceDeposit, ResourceStock, StorageUnit, SupplyChainComponent,
+    SupplyChainSector, TaxBracket, TechnologyComponent, TransportLink, TransportNetwork,
+    WeatherComponent, World,
 };
+use crate::{deposits, lending, projects, supply_chain, transport, weather};
 
 fn default_dt_days() -> f64 {
     1.0
@@ -91,6 +102,294 @@ fn default_propensity_to_consume() -> f64 {
     0.9
 }
 
+fn default_household_deposits_per_capita() -> f64 {
+    400.0
+}
+
+fn default_household_debt_per_capita() -> f64 {
+    0.0
+}
+
+fn default_target_buffer_days() -> f64 {
+    45.0
+}
+
+fn default_wealth_drawdown_rate() -> f64 {
+    0.01
+}
+
+fn default_income_smoothing() -> f64 {
+    0.05
+}
+
+fn default_borrowing_limit_days() -> f64 {
+    30.0
+}
+
+fn default_migration_base_rate() -> f64 {
+    0.02
+}
+
+fn default_migration_wage_weight() -> f64 {
+    1.0
+}
+
+fn default_migration_unemployment_weight() -> f64 {
+    2.0
+}
+
+fn default_migration_shortage_weight() -> f64 {
+    4.0
+}
+
+fn default_distance_decay() -> f64 {
+    0.01
+}
+
+fn default_region_distance() -> f64 {
+    50.0
+}
+
+fn default_incubation_days() -> f64 {
+    5.0
+}
+
+fn default_infectious_days() -> f64 {
+    7.0
+}
+
+fn default_fatality_rate() -> f64 {
+    0.005
+}
+
+fn default_hospitalisation_rate() -> f64 {
+    0.05
+}
+
+fn default_import_weight() -> f64 {
+    1.0
+}
+
+fn default_work_absence() -> f64 {
+    1.0
+}
+
+fn default_overload_fatality_multiplier() -> f64 {
+    3.0
+}
+
+fn default_bed_cost_per_day() -> f64 {
+    4.0
+}
+
+fn default_bed_adjustment_rate() -> f64 {
+    0.1
+}
+
+fn default_hospital_beds_per_1000() -> f64 {
+    3.0
+}
+
+fn default_health_fraction() -> f64 {
+    0.05
+}
+
+fn default_education_fraction() -> f64 {
+    0.06
+}
+
+fn default_initial_attainment() -> f64 {
+    11.0
+}
+
+fn default_min_attainment() -> f64 {
+    6.0
+}
+
+fn default_max_attainment() -> f64 {
+    18.0
+}
+
+fn default_education_spending_per_capita() -> f64 {
+    0.2
+}
+
+fn default_funding_elasticity() -> f64 {
+    0.5
+}
+
+fn default_schooling_years() -> f64 {
+    4.0
+}
+
+fn default_workforce_turnover_years() -> f64 {
+    12.0
+}
+
+fn default_skill_productivity_elasticity() -> f64 {
+    0.6
+}
+
+fn default_mismatch_sensitivity() -> f64 {
+    1.0
+}
+
+fn default_skill_demand_growth() -> f64 {
+    0.01
+}
+
+fn default_households_per_region() -> usize {
+    100
+}
+
+fn default_members_per_household() -> f64 {
+    2.5
+}
+
+fn default_productivity_dispersion() -> f64 {
+    0.4
+}
+
+fn default_wealth_dispersion() -> f64 {
+    0.8
+}
+
+fn default_size_dispersion() -> f64 {
+    0.3
+}
+
+fn default_neutral_rate() -> f64 {
+    0.02
+}
+
+fn default_inflation_target() -> f64 {
+    0.02
+}
+
+fn default_inflation_weight() -> f64 {
+    0.5
+}
+
+fn default_gap_weight() -> f64 {
+    0.5
+}
+
+fn default_rate_smoothing() -> f64 {
+    0.7
+}
+
+fn default_decision_interval_ticks() -> u64 {
+    30
+}
+
+fn default_working_age_min() -> u32 {
+    15
+}
+
+fn default_working_age_max() -> u32 {
+    64
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_age_cohorts() -> Vec<ScenarioAgeCohort> {
+    [
+        (0, 0.065, 0.0, 0.004, 0.0),
+        (5, 0.064, 0.0, 0.0003, 0.0),
+        (10, 0.063, 0.0, 0.0003, 0.0),
+        (15, 0.062, 0.02, 0.0006, 0.35),
+        (20, 0.063, 0.06, 0.0009, 0.7),
+        (25, 0.067, 0.09, 0.001, 0.82),
+        (30, 0.068, 0.08, 0.0012, 0.84),
+        (35, 0.067, 0.04, 0.0016, 0.85),
+        (40, 0.065, 0.01, 0.0024, 0.85),
+        (45, 0.064, 0.002, 0.0036, 0.84),
+        (50, 0.062, 0.0, 0.0055, 0.8),
+        (55, 0.058, 0.0, 0.0085, 0.7),
+        (60, 0.052, 0.0, 0.013, 0.5),
+        (65, 0.044, 0.0, 0.02, 0.2),
+        (70, 0.035, 0.0, 0.032, 0.08),
+        (75, 0.026, 0.0, 0.055, 0.03),
+        (80, 0.03, 0.0, 0.13, 0.01),
+    ]
+    .into_iter()
+    .map(
+        |(min_age, share, fertility, mortality, participation)| ScenarioAgeCohort {
+            min_age,
+            max_age: if min_age < 80 {
+                Some(min_age + 4)
+            } else {
+                None
+            },
+            share,
+            fertility,
+            mortality,
+            participation,
+        },
+    )
+    .collect()
+}
+
+fn default_depreciation_rate() -> f64 {
+    0.06
+}
+
+fn default_capital_share() -> f64 {
+    0.3
+}
+
+fn default_substitution_elasticity() -> f64 {
+    1.0
+}
+
+fn default_capital_adjustment_speed() -> f64 {
+    0.01
+}
+
+fn default_initial_capital_ratio() -> f64 {
+    1.0
+}
+
+fn default_poverty_line_ratio() -> f64 {
+    0.6
+}
+
+fn default_income_groups() -> Vec<ScenarioIncomeGroup> {
+    [
+        ("q1", 0.45, 0.75, 1.12, 2.0),
+        ("q2", 0.7, 0.92, 1.05, 1.4),
+        ("q3", 0.95, 1.0, 1.0, 1.0),
+        ("q4", 1.25, 1.05, 0.95, 0.6),
+        ("q5", 2.1, 1.08, 0.88, 0.3),
+    ]
+    .into_iter()
+    .map(
+        |(name, wage, employment, propensity, transfer)| ScenarioIncomeGroup {
+            name: name.to_string(),
+            population_share: 0.2,
+            wage_multiplier: wage,
+            employment_weight: employment,
+            propensity_multiplier: propensity,
+            transfer_weight: transfer,
+        },
+    )
+    .collect()
+}
+
+fn default_group_multiplier() -> f64 {
+    1.0
+}
+
+fn default_auction_levels() -> usize {
+    10
+}
+
+fn default_auction_spread() -> f64 {
+    0.1
+}
+
 fn default_initial_deposits() -> f64 {
     5_000_000.0
 }
@@ -123,6 +422,329 @@ fn default_infrastructure_spend_fraction() -> f64 {
     0.12
 }
 
+fn default_loan_maturity_years() -> f64 {
+    3.0
+}
+
+fn default_firm_loans() -> ScenarioLoanTerms {
+    ScenarioLoanTerms::default()
+}
+
+fn default_infrastructure_loans() -> ScenarioLoanTerms {
+    ScenarioLoanTerms {
+        maturity_years: 15.0,
+        amortisation: Amortisation::Linear,
+    }
+}
+
+fn default_loan_cohort_days() -> f64 {
+    30.0
+}
+
+fn default_initial_loan_cohorts() -> usize {
+    6
+}
+
+fn default_arrears_default_multiplier() -> f64 {
+    4.0
+}
+
+fn default_initial_bank_capital_ratio() -> f64 {
+    0.12
+}
+
+fn default_min_capital_ratio() -> f64 {
+    0.08
+}
+
+fn default_min_liquidity_ratio() -> f64 {
+    0.1
+}
+
+fn default_firm_risk_weight() -> f64 {
+    1.0
+}
+
+fn default_infrastructure_risk_weight() -> f64 {
+    0.5
+}
+
+fn default_recapitalisation_ratio() -> f64 {
+    0.1
+}
+
+fn default_bond_maturity_years() -> f64 {
+    5.0
+}
+
+fn default_term_premium() -> f64 {
+    0.01
+}
+
+fn default_debt_threshold() -> f64 {
+    0.6
+}
+
+fn default_debt_sensitivity() -> f64 {
+    0.05
+}
+
+fn default_approval_sensitivity() -> f64 {
+    0.03
+}
+
+fn default_household_bond_share() -> f64 {
+    0.3
+}
+
+fn default_surplus_repayment_share() -> f64 {
+    0.35
+}
+
+fn default_interbank_spread() -> f64 {
+    0.0025
+}
+
+fn default_interbank_stress_premium() -> f64 {
+    0.02
+}
+
+fn default_interbank_limit_share() -> f64 {
+    0.5
+}
+
+fn default_interbank_stress_sensitivity() -> f64 {
+    1.5
+}
+
+fn default_interbank_recovery_rate() -> f64 {
+    0.4
+}
+
+fn default_fx_trade_sensitivity() -> f64 {
+    2.0
+}
+
+fn default_fx_interest_sensitivity() -> f64 {
+    5.0
+}
+
+fn default_trade_openness() -> f64 {
+    0.5
+}
+
+fn default_fx_max_daily_change() -> f64 {
+    0.02
+}
+
+fn default_fx_history_ticks() -> usize {
+    365
+}
+
+fn default_exchange_rate() -> f64 {
+    1.0
+}
+
+fn default_bpr_alpha() -> f64 {
+    0.15
+}
+
+fn default_bpr_beta() -> f64 {
+    4.0
+}
+
+fn default_time_value() -> f64 {
+    1.0
+}
+
+fn default_assignment_steps() -> u32 {
+    4
+}
+
+fn default_passenger_weight() -> f64 {
+    1.0
+}
+
+fn default_link_speed() -> f64 {
+    50.0
+}
+
+fn default_scarcity_premium() -> f64 {
+    0.5
+}
+
+fn default_plant_online() -> bool {
+    true
+}
+
+fn default_asset_ageing() -> f64 {
+    0.1
+}
+
+fn default_outage_rate() -> f64 {
+    0.0
+}
+
+fn default_outage_days() -> f64 {
+    3.0
+}
+
+fn default_storage_efficiency() -> f64 {
+    0.85
+}
+
+fn default_solar_mean() -> f64 {
+    0.5
+}
+
+fn default_solar_amplitude() -> f64 {
+    0.5
+}
+
+fn default_peak_solar_day() -> f64 {
+    172.0
+}
+
+fn default_cloud_volatility() -> f64 {
+    0.3
+}
+
+fn default_wind_mean() -> f64 {
+    0.5
+}
+
+fn default_wind_amplitude() -> f64 {
+    0.2
+}
+
+fn default_wind_volatility() -> f64 {
+    0.3
+}
+
+fn default_wind_persistence() -> f64 {
+    0.8
+}
+
+fn default_temperature_mean() -> f64 {
+    12.0
+}
+
+fn default_temperature_amplitude() -> f64 {
+    8.0
+}
+
+fn default_warmest_day() -> f64 {
+    200.0
+}
+
+fn default_temperature_noise() -> f64 {
+    2.0
+}
+
+fn default_precipitation_mean() -> f64 {
+    1.0
+}
+
+fn default_precipitation_amplitude() -> f64 {
+    0.3
+}
+
+fn default_wettest_day() -> f64 {
+    320.0
+}
+
+fn default_precipitation_noise() -> f64 {
+    0.2
+}
+
+fn default_optimal_temperature() -> f64 {
+    18.0
+}
+
+fn default_temperature_tolerance() -> f64 {
+    20.0
+}
+
+fn default_drought_threshold() -> f64 {
+    0.6
+}
+
+fn default_yield_sensitivity() -> f64 {
+    0.3
+}
+
+fn default_heating_threshold() -> f64 {
+    15.0
+}
+
+fn default_heating_sensitivity() -> f64 {
+    0.01
+}
+
+fn default_quality_elasticity() -> f64 {
+    0.5
+}
+
+fn default_start_date() -> NaiveDate {
+    NaiveDate::from_ymd_opt(2025, 1, 1).expect("valid start date")
+}
+
+fn default_supply_chain_sectors() -> Vec<ScenarioSector> {
+    [
+        ("agriculture", Some(Good::Food)),
+        ("manufacturing", None),
+        ("services", None),
+        ("energy", Some(Good::Energy)),
+    ]
+    .into_iter()
+    .map(|(name, good)| ScenarioSector {
+        name: name.into(),
+        good,
+    })
+    .collect()
+}
+
+fn default_project_catalogue() -> Vec<ScenarioProjectTemplate> {
+    let template =
+        |name: &str, kind, capacity, cost, build_days, maintenance_cost| ScenarioProjectTemplate {
+            name: name.into(),
+            kind,
+            capacity,
+            cost,
+            build_days,
+            maintenance_cost,
+            technology: None,
+            marginal_cost: 0.0,
+            fuel_per_unit: 0.0,
+            emissions_per_unit: 0.0,
+        };
+    let mut plant = template(
+        "Gas plant",
+        AssetKind::Plant,
+        10_000.0,
+        60_000.0,
+        60.0,
+        600.0,
+    );
+    plant.technology = Some(PlantTechnology::Gas);
+    plant.marginal_cost = 1.2;
+    plant.fuel_per_unit = 0.2;
+    plant.emissions_per_unit = 0.4;
+    vec![
+        plant,
+        template("Road", AssetKind::Road, 8_000.0, 30_000.0, 30.0, 400.0),
+        template("Port", AssetKind::Port, 12_000.0, 90_000.0, 90.0, 900.0),
+        template(
+            "Warehouse",
+            AssetKind::Warehouse,
+            4_000.0,
+            15_000.0,
+            14.0,
+            150.0,
+        ),
+    ]
+}
+
 fn default_power_capacity() -> f64 {
     65_000.0
 }
@@ -139,50 +761,522 @@ fn default_infrastructure_degradation_rate() -> f64 {
     0.003
 }
 
-fn default_tax_rate() -> f64 {
-    0.24
+fn default_tax_rate() -> f64 {
+    0.24
+}
+
+fn default_public_investment_fraction() -> f64 {
+    0.18
+}
+
+fn default_rnd_fraction() -> f64 {
+    0.12
+}
+
+fn default_target_unemployment() -> f64 {
+    0.07
+}
+
+fn default_target_primary_balance() -> f64 {
+    0.0
+}
+
+fn default_rnd_budget_per_capita() -> f64 {
+    8.0
+}
+
+fn default_research_efficiency_param() -> f64 {
+    1.0
+}
+
+fn default_starting_techs() -> Vec<String> {
+    Vec::new()
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Scenario {
+    pub name: String,
+    pub description: Option<String>,
+    pub seed: u64,
+    #[serde(default = "default_dt_days")]
+    pub dt_days: f64,
+    #[serde(default = "default_start_date")]
+    pub start_date: NaiveDate,
+    #[serde(default)]
+    pub ticks: Option<u64>,
+    #[serde(default = "default_snapshot_interval_ticks")]
+    pub snapshot_interval_ticks: u64,
+    #[serde(default)]
+    pub supply_chain: ScenarioSupplyChain,
+    #[serde(default)]
+    pub markets: ScenarioMarkets,
+    #[serde(default)]
+    pub migration: ScenarioMigration,
+    #[serde(default)]
+    pub demographic_events: DemographicEvents,
+    #[serde(default)]
+    pub health: ScenarioHealth,
+    #[serde(default)]
+    pub central_banks: Vec<ScenarioCentralBank>,
+    #[serde(default)]
+    pub interbank: ScenarioInterbank,
+    #[serde(default)]
+    pub currencies: ScenarioCurrencies,
+    #[serde(default)]
+    pub transport: ScenarioTransport,
+    #[serde(default = "default_project_catalogue")]
+    pub infrastructure_projects: Vec<ScenarioProjectTemplate>,
+    #[serde(default)]
+    pub population_mode: PopulationMode,
+    #[serde(default)]
+    pub agents: ScenarioAgents,
+    pub regions: Vec<ScenarioRegion>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ScenarioCentralBank {
+    pub name: String,
+    #[serde(default)]
+    pub currency: Option<String>,
+    #[serde(default)]
+    pub members: Vec<String>,
+    #[serde(default)]
+    pub initial_rate: Option<f64>,
+    #[serde(default = "default_neutral_rate")]
+    pub neutral_rate: f64,
+    #[serde(default = "default_inflation_target")]
+    pub inflation_target: f64,
+    #[serde(default = "default_inflation_weight")]
+    pub inflation_weight: f64,
+    #[serde(default = "default_gap_weight")]
+    pub gap_weight: f64,
+    #[serde(default)]
+    pub gap_measure: GapMeasure,
+    #[serde(default = "default_target_unemployment")]
+    pub target_unemployment_rate: f64,
+    #[serde(default)]
+    pub effective_lower_bound: f64,
+    #[serde(default = "default_rate_smoothing")]
+    pub smoothing: f64,
+    #[serde(default = "default_decision_interval_ticks")]
+    pub decision_interval_ticks: u64,
+    #[serde(default)]
+    pub forward_guidance: Option<ScenarioForwardGuidance>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ScenarioForwardGuidance {
+    pub until_tick: u64,
+    pub rate: f64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ScenarioAgents {
+    #[serde(default = "default_households_per_region")]
+    pub households_per_region: usize,
+    #[serde(default = "default_members_per_household")]
+    pub members_per_household: f64,
+    #[serde(default = "default_productivity_dispersion")]
+    pub productivity_dispersion: f64,
+    #[serde(default = "default_wealth_dispersion")]
+    pub wealth_dispersion: f64,
+    #[serde(default = "default_size_dispersion")]
+    pub size_dispersion: f64,
+}
+
+impl Default for ScenarioAgents {
+    fn default() -> Self {
+        Self {
+            households_per_region: default_households_per_region(),
+            members_per_household: default_members_per_household(),
+            productivity_dispersion: default_productivity_dispersion(),
+            wealth_dispersion: default_wealth_dispersion(),
+            size_dispersion: default_size_dispersion(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ScenarioHealth {
+    #[serde(default)]
+    pub diseases: Vec<ScenarioDisease>,
+    #[serde(default)]
+    pub interventions: Vec<ScenarioIntervention>,
+    #[serde(default = "default_import_weight")]
+    pub import_weight: f64,
+    #[serde(default = "default_work_absence")]
+    pub work_absence: f64,
+    #[serde(default = "default_overload_fatality_multiplier")]
+    pub overload_fatality_multiplier: f64,
+    #[serde(default = "default_bed_cost_per_day")]
+    pub bed_cost_per_day: f64,
+    #[serde(default = "default_bed_adjustment_rate")]
+    pub bed_adjustment_rate: f64,
+}
+
+impl Default for ScenarioHealth {
+    fn default() -> Self {
+        Self {
+            diseases: Vec::new(),
+            interventions: Vec::new(),
+            import_weight: default_import_weight(),
+            work_absence: default_work_absence(),
+            overload_fatality_multiplier: default_overload_fatality_multiplier(),
+            bed_cost_per_day: default_bed_cost_per_day(),
+            bed_adjustment_rate: default_bed_adjustment_rate(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ScenarioDisease {
+    pub name: String,
+    pub transmission_rate: f64,
+    #[serde(default = "default_incubation_days")]
+    pub incubation_days: f64,
+    #[serde(default = "default_infectious_days")]
+    pub infectious_days: f64,
+    #[serde(default = "default_fatality_rate")]
+    pub fatality_rate: f64,
+    #[serde(default = "default_hospitalisation_rate")]
+    pub hospitalisation_rate: f64,
+    #[serde(default)]
+    pub seeds: Vec<ScenarioOutbreakSeed>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ScenarioOutbreakSeed {
+    pub region: String,
+    pub infected: f64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ScenarioIntervention {
+    pub kind: InterventionKind,
+    #[serde(default)]
+    pub region: Option<String>,
+    #[serde(default)]
+    pub disease: Option<String>,
+    #[serde(default)]
+    pub start_tick: u64,
+    #[serde(default)]
+    pub duration_ticks: Option<u64>,
+    pub strength: f64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ScenarioRegionHealth {
+    #[serde(default = "default_hospital_beds_per_1000")]
+    pub hospital_beds_per_1000: f64,
+}
+
+impl Default for ScenarioRegionHealth {
+    fn default() -> Self {
+        Self {
+            hospital_beds_per_1000: default_hospital_beds_per_1000(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ScenarioInterbank {
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(default = "default_interbank_spread")]
+    pub spread: f64,
+    #[serde(default = "default_interbank_stress_premium")]
+    pub stress_premium: f64,
+    #[serde(default = "default_interbank_limit_share")]
+    pub limit_share: f64,
+    #[serde(default = "default_interbank_stress_sensitivity")]
+    pub stress_sensitivity: f64,
+    #[serde(default = "default_interbank_recovery_rate")]
+    pub recovery_rate: f64,
+}
+
+impl Default for ScenarioInterbank {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            spread: default_interbank_spread(),
+            stress_premium: default_interbank_stress_premium(),
+            limit_share: default_interbank_limit_share(),
+            stress_sensitivity: default_interbank_stress_sensitivity(),
+            recovery_rate: default_interbank_recovery_rate(),
+        }
+    }
+}
+
+impl ScenarioInterbank {
+    fn market(&self) -> InterbankMarket {
+        InterbankMarket {
+            enabled: self.enabled,
+            spread: self.spread,
+            stress_premium: self.stress_premium.max(0.0),
+            limit_share: self.limit_share.max(0.0),
+            stress_sensitivity: self.stress_sensitivity.max(0.0),
+            recovery_rate: self.recovery_rate.clamp(0.0, 1.0),
+            ..InterbankMarket::default()
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ScenarioCurrencies {
+    #[serde(default = "default_fx_trade_sensitivity")]
+    pub trade_sensitivity: f64,
+    #[serde(default = "default_fx_interest_sensitivity")]
+    pub interest_sensitivity: f64,
+    #[serde(default = "default_trade_openness")]
+    pub trade_openness: f64,
+    #[serde(default = "default_fx_max_daily_change")]
+    pub max_daily_change: f64,
+    #[serde(default = "default_fx_history_ticks")]
+    pub history_ticks: usize,
+    #[serde(default)]
+    pub areas: Vec<ScenarioCurrencyArea>,
+}
+
+impl Default for ScenarioCurrencies {
+    fn default() -> Self {
+        Self {
+            trade_sensitivity: default_fx_trade_sensitivity(),
+            interest_sensitivity: default_fx_interest_sensitivity(),
+            trade_openness: default_trade_openness(),
+            max_daily_change: default_fx_max_daily_change(),
+            history_ticks: default_fx_history_ticks(),
+            areas: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ScenarioCurrencyArea {
+    pub code: String,
+    #[serde(default)]
+    pub regions: Vec<String>,
+    #[serde(default)]
+    pub regime: ExchangeRegime,
+    #[serde(default)]
+    pub anchor: Option<String>,
+    #[serde(default = "default_exchange_rate")]
+    pub peg: f64,
+    #[serde(default = "default_exchange_rate")]
+    pub initial_rate: f64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ScenarioTransport {
+    #[serde(default = "default_bpr_alpha")]
+    pub bpr_alpha: f64,
+    #[serde(default = "default_bpr_beta")]
+    pub bpr_beta: f64,
+    #[serde(default = "default_time_value")]
+    pub time_value: f64,
+    #[serde(default = "default_assignment_steps")]
+    pub assignment_steps: u32,
+    #[serde(default = "default_passenger_weight")]
+    pub passenger_weight: f64,
+    #[serde(default = "default_link_speed")]
+    pub speed: f64,
+    #[serde(default)]
+    pub links: Vec<ScenarioLink>,
+}
+
+impl Default for ScenarioTransport {
+    fn default() -> Self {
+        Self {
+            bpr_alpha: default_bpr_alpha(),
+            bpr_beta: default_bpr_beta(),
+            time_value: default_time_value(),
+            assignment_steps: default_assignment_steps(),
+            passenger_weight: default_passenger_weight(),
+            speed: default_link_speed(),
+            links: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ScenarioLink {
+    pub from: String,
+    pub to: String,
+    pub capacity: f64,
+    pub length: f64,
+    #[serde(default)]
+    pub cost: f64,
+    #[serde(default)]
+    pub speed: Option<f64>,
+}
+
+impl ScenarioTransport {
+    fn network(&self) -> TransportNetwork {
+        let links = self
+            .links
+            .iter()
+            .enumerate()
+            .map(|(idx, link)| {
+                let speed = link.speed.unwrap_or(self.speed).max(f64::EPSILON);
+                TransportLink {
+                    id: idx as u64 + 1,
+                    from: link.from.clone(),
+                    to: link.to.clone(),
+                    capacity: link.capacity.max(0.0),
+                    length: link.length.max(0.0),
+                    cost: link.cost.max(0.0),
+                    free_flow_time: link.length.max(0.0) / speed,
+                    flow: 0.0,
+                    utilisation: 0.0,
+                    travel_time: 0.0,
+                    generalised_cost: 0.0,
+                }
+            })
+            .collect();
+        let mut network = TransportNetwork {
+            bpr_alpha: self.bpr_alpha.max(0.0),
+            bpr_beta: self.bpr_beta.max(0.0),
+            time_value: self.time_value.max(0.0),
+            assignment_steps: self.assignment_steps.max(1),
+            passenger_weight: self.passenger_weight.max(0.0),
+            links,
+            ..TransportNetwork::default()
+        };
+        transport::refresh(&mut network);
+        network
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ScenarioMigration {
+    #[serde(default = "default_migration_base_rate")]
+    pub annual_base_rate: f64,
+    #[serde(default = "default_migration_wage_weight")]
+    pub wage_weight: f64,
+    #[serde(default = "default_migration_unemployment_weight")]
+    pub unemployment_weight: f64,
+    #[serde(default = "default_migration_shortage_weight")]
+    pub shortage_weight: f64,
+    #[serde(default = "default_distance_decay")]
+    pub distance_decay: f64,
+    #[serde(default = "default_region_distance")]
+    pub default_distance: f64,
+    #[serde(default)]
+    pub distances: Vec<ScenarioDistance>,
 }
 
-fn default_public_investment_fraction() -> f64 {
-    0.18
+impl Default for ScenarioMigration {
+    fn default() -> Self {
+        Self {
+            annual_base_rate: default_migration_base_rate(),
+            wage_weight: default_migration_wage_weight(),
+            unemployment_weight: default_migration_unemployment_weight(),
+            shortage_weight: default_migration_shortage_weight(),
+            distance_decay: default_distance_decay(),
+            default_distance: default_region_distance(),
+            distances: Vec::new(),
+        }
+    }
 }
 
-fn default_rnd_fraction() -> f64 {
-    0.12
+#[derive(Debug, Clone, Deserialize)]
+pub struct ScenarioDistance {
+    pub from: String,
+    pub to: String,
+    pub distance: f64,
 }
 
-fn default_target_unemployment() -> f64 {
-    0.07
+#[derive(Debug, Clone, Deserialize)]
+pub struct ScenarioMarkets {
+    #[serde(default)]
+    pub market_model: MarketModel,
+    #[serde(default)]
+    pub food: Option<MarketModel>,
+    #[serde(default)]
+    pub energy: Option<MarketModel>,
+    #[serde(default = "default_auction_levels")]
+    pub auction_levels: usize,
+    #[serde(default = "default_auction_spread")]
+    pub auction_spread: f64,
 }
 
-fn default_target_primary_balance() -> f64 {
-    0.0
+impl Default for ScenarioMarkets {
+    fn default() -> Self {
+        Self {
+            market_model: MarketModel::default(),
+            food: None,
+            energy: None,
+            auction_levels: default_auction_levels(),
+            auction_spread: default_auction_spread(),
+        }
+    }
 }
 
-fn default_rnd_budget_per_capita() -> f64 {
-    8.0
+#[derive(Debug, Clone, Deserialize)]
+pub struct ScenarioSupplyChain {
+    #[serde(default = "default_supply_chain_sectors")]
+    pub sectors: Vec<ScenarioSector>,
+    #[serde(default)]
+    pub coefficients: Vec<Vec<f64>>,
+    #[serde(default)]
+    pub latency_ticks: u32,
 }
 
-fn default_research_efficiency_param() -> f64 {
-    1.0
+impl Default for ScenarioSupplyChain {
+    fn default() -> Self {
+        Self {
+            sectors: default_supply_chain_sectors(),
+            coefficients: Vec::new(),
+            latency_ticks: 0,
+        }
+    }
 }
 
-fn default_starting_techs() -> Vec<String> {
-    Vec::new()
+impl ScenarioSupplyChain {
+    pub fn validate(&self) -> Result<()> {
+        if self.coefficients.is_empty() {
+            return Ok(());
+        }
+        let n = self.sectors.len();
+        for (idx, sector) in self.sectors.iter().enumerate() {
+            ensure!(
+                !self.sectors[..idx].iter().any(|s| s.name == sector.name),
+                "supply chain sector {} is listed twice",
+                sector.name
+            );
+            ensure!(
+                sector.good.is_none() || !self.sectors[..idx].iter().any(|s| s.good == sector.good),
+                "supply chain sector {} produces a good another sector already supplies",
+                sector.name
+            );
+        }
+        ensure!(
+            self.coefficients.len() == n && self.coefficients.iter().all(|row| row.len() == n),
+            "supply chain coefficients must be a {n}x{n} matrix over the sectors {:?}",
+            self.sectors
+                .iter()
+                .map(|s| s.name.as_str())
+                .collect::<Vec<_>>()
+        );
+        ensure!(
+            self.coefficients
+                .iter()
+                .flatten()
+                .all(|a| a.is_finite() && *a >= 0.0),
+            "supply chain coefficients must be finite and non-negative"
+        );
+        ensure!(
+            supply_chain::is_productive(&self.coefficients),
+            "supply chain coefficients are not productive: no output level covers its own inputs"
+        );
+        Ok(())
+    }
 }
 
 #[derive(Debug, Clone, Deserialize)]
-pub struct Scenario {
+pub struct ScenarioSector {
     pub name: String,
-    pub description: Option<String>,
-    pub seed: u64,
-    #[serde(default = "default_dt_days")]
-    pub dt_days: f64,
     #[serde(default)]
-    pub ticks: Option<u64>,
-    #[serde(default = "default_snapshot_interval_ticks")]
-    pub snapshot_interval_ticks: u64,
-    pub regions: Vec<ScenarioRegion>,
+    pub good: Option<Good>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -205,15 +1299,195 @@ pub struct ScenarioRegion {
     #[serde(default)]
     pub economy: ScenarioEconomy,
     #[serde(default)]
+    pub age_structure: ScenarioAgeStructure,
+    #[serde(default)]
+    pub capital: ScenarioCapital,
+    #[serde(default)]
+    pub households: ScenarioHouseholds,
+    #[serde(default)]
+    pub distribution: ScenarioDistribution,
+    #[serde(default)]
     pub finance: ScenarioFinance,
     #[serde(default)]
     pub infrastructure: ScenarioInfrastructure,
     #[serde(default)]
+    pub weather: ScenarioWeather,
+    #[serde(default)]
+    pub deposits: Vec<ScenarioDeposit>,
+    #[serde(default)]
     pub technology: ScenarioTechnology,
     #[serde(default)]
+    pub education: ScenarioEducation,
+    #[serde(default)]
+    pub health: ScenarioRegionHealth,
+    #[serde(default)]
     pub policy: ScenarioPolicy,
 }
 
+impl ScenarioRegion {
+    fn household_deposits(&self) -> f64 {
+        (self.households.deposits_per_capita.max(0.0) * self.citizens as f64)
+            .min(self.finance.initial_deposits.max(0.0))
+    }
+
+    fn household_deposits_per_capita(&self) -> f64 {
+        if self.citizens > 0 {
+            self.household_deposits() / self.citizens as f64
+        } else {
+            0.0
+        }
+    }
+
+    fn finance_component(&self) -> FinanceComponent {
+        let config = &self.finance;
+        let mut finance = FinanceComponent {
+            bank_deposits: config.initial_deposits.max(0.0) - self.household_deposits(),
+            loan_balance: 0.0,
+            policy_rate: config.policy_rate,
+            loan_rate_spread: config.loan_rate_spread,
+            deposit_rate: config.deposit_rate,
+            default_rate: config.default_rate,
+            target_loan_to_deposit: config.target_loan_to_deposit,
+            infrastructure_spend_fraction: config.infrastructure_spend_fraction,
+            credit_stress: 0.0,
+            cumulative_defaults: 0.0,
+            firm_loan_terms: config.firm_loans.terms(),
+            infrastructure_loan_terms: config.infrastructure_loans.terms(),
+            cohort_days: config.loan_cohort_days.max(1.0),
+            arrears_default_multiplier: config.arrears_default_multiplier,
+            loans: Vec::new(),
+            next_loan_id: 0,
+            flows_tick: None,
+            new_lending: 0.0,
+            repayments: 0.0,
+            interest_received: 0.0,
+            defaults: 0.0,
+            arrears: 0.0,
+            capital: 0.0,
+            min_capital_ratio: config.min_capital_ratio,
+            min_liquidity_ratio: config.min_liquidity_ratio,
+            firm_risk_weight: config.firm_risk_weight,
+            infrastructure_risk_weight: config.infrastructure_risk_weight,
+            recapitalisation_ratio: config.recapitalisation_ratio,
+            resolution: config.resolution,
+            capital_ratio: 0.0,
+            liquidity_ratio: 0.0,
+            credit_rationing: 0.0,
+            insolvencies: 0,
+            resolution_cost: 0.0,
+            last_insolvency_tick: None,
+            interbank_lending: 0.0,
+            interbank_borrowing: 0.0,
+            interbank_proceeds: 0.0,
+            interbank_rate: 0.0,
+            interbank_losses: 0.0,
+            government_bonds: 0.0,
+        };
+        lending::seed(
+            &mut finance,
+            config.initial_loans,
+            config.initial_loan_cohorts.max(1),
+        );
+        finance.capital =
+            config.initial_capital_ratio.max(0.0) * lending::risk_weighted_assets(&finance);
+        lending::update_ratios(&mut finance, self.household_deposits());
+        finance
+    }
+
+    fn education_component(&self) -> EducationComponent {
+        let config = &self.education;
+        let max_attainment = config.max_attainment.max(config.min_attainment);
+        let attainment = config
+            .initial_attainment
+            .clamp(config.min_attainment, max_attainment);
+        let mut component = EducationComponent {
+            attainment,
+            graduate_attainment: attainment,
+            reference_attainment: config.reference_attainment.unwrap_or(attainment).max(0.0),
+            min_attainment: config.min_attainment,
+            max_attainment,
+            reference_spending_per_capita: config.reference_spending_per_capita.max(0.0),
+            funding_elasticity: config.funding_elasticity,
+            schooling_years: config.schooling_years,
+            workforce_turnover_years: config.workforce_turnover_years,
+            productivity_elasticity: config.productivity_elasticity,
+            mismatch_sensitivity: config.mismatch_sensitivity,
+            skill_demand_growth: config.skill_demand_growth,
+            pending_funding: 0.0,
+            spending_per_capita: config.reference_spending_per_capita.max(0.0),
+            skill_index: 1.0,
+            required_skill: 1.0,
+            skill_mismatch: 0.0,
+            productivity_multiplier: 1.0,
+            matching_multiplier: 1.0,
+        };
+        crate::education::refresh(&mut component);
+        component
+    }
+
+    fn demography_component(&self) -> DemographyComponent {
+        let structure = &self.age_structure;
+        let share_total: f64 = structure.cohorts.iter().map(|c| c.share.max(0.0)).sum();
+        let weight = |cohort: &ScenarioAgeCohort| {
+            if share_total > 0.0 {
+                cohort.share.max(0.0) / share_total
+            } else {
+                0.0
+            }
+        };
+        let (fertility_scale, mortality_scale) = if structure.calibrate_to_crude_rates {
+            let crude_birth: f64 = structure
+                .cohorts
+                .iter()
+                .map(|c| weight(c) * c.fertility.max(0.0))
+                .sum();
+            let crude_death: f64 = structure
+                .cohorts
+                .iter()
+                .map(|c| weight(c) * c.mortality.max(0.0))
+                .sum();
+            (
+                if crude_birth > 0.0 {
+                    self.annual_birth_rate / crude_birth
+                } else {
+                    1.0
+                },
+                if crude_death > 0.0 {
+                    self.annual_death_rate / crude_death
+                } else {
+                    1.0
+                },
+            )
+        } else {
+            (1.0, 1.0)
+        };
+        let mut cohorts: Vec<AgeCohort> = structure
+            .cohorts
+            .iter()
+            .map(|cohort| AgeCohort {
+                min_age: cohort.min_age,
+                max_age: cohort.max_age,
+                population: self.citizens as f64 * weight(cohort),
+                fertility_rate: cohort.fertility.max(0.0) * fertility_scale,
+                mortality_rate: cohort.mortality.max(0.0) * mortality_scale,
+                participation_rate: cohort.participation.clamp(0.0, 1.0),
+            })
+            .collect();
+        cohorts.sort_by_key(|c| c.min_age);
+        let mut demography = DemographyComponent {
+            cohorts,
+            working_age_min: structure.working_age_min,
+            working_age_max: structure.working_age_max,
+            labour_force: 0.0,
+            dependency_ratio: 0.0,
+            births: 0.0,
+            deaths: 0.0,
+        };
+        crate::demography::refresh_indicators(&mut demography);
+        demography
+    }
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct ResourceInit {
     pub food: f64,
@@ -281,6 +1555,135 @@ impl Default for ScenarioEconomy {
     }
 }
 
+#[derive(Debug, Clone, Deserialize)]
+pub struct ScenarioAgeStructure {
+    #[serde(default = "default_working_age_min")]
+    pub working_age_min: u32,
+    #[serde(default = "default_working_age_max")]
+    pub working_age_max: u32,
+    #[serde(default = "default_true")]
+    pub calibrate_to_crude_rates: bool,
+    #[serde(default = "default_age_cohorts")]
+    pub cohorts: Vec<ScenarioAgeCohort>,
+}
+
+impl Default for ScenarioAgeStructure {
+    fn default() -> Self {
+        Self {
+            working_age_min: default_working_age_min(),
+            working_age_max: default_working_age_max(),
+            calibrate_to_crude_rates: true,
+            cohorts: default_age_cohorts(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ScenarioAgeCohort {
+    pub min_age: u32,
+    #[serde(default)]
+    pub max_age: Option<u32>,
+    pub share: f64,
+    #[serde(default)]
+    pub fertility: f64,
+    #[serde(default)]
+    pub mortality: f64,
+    #[serde(default)]
+    pub participation: f64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ScenarioCapital {
+    #[serde(default = "default_depreciation_rate")]
+    pub depreciation_rate: f64,
+    #[serde(default = "default_capital_share")]
+    pub capital_share: f64,
+    #[serde(default = "default_substitution_elasticity")]
+    pub substitution_elasticity: f64,
+    #[serde(default = "default_capital_adjustment_speed")]
+    pub adjustment_speed: f64,
+    #[serde(default = "default_initial_capital_ratio")]
+    pub initial_capital_ratio: f64,
+}
+
+impl Default for ScenarioCapital {
+    fn default() -> Self {
+        Self {
+            depreciation_rate: default_depreciation_rate(),
+            capital_share: default_capital_share(),
+            substitution_elasticity: default_substitution_elasticity(),
+            adjustment_speed: default_capital_adjustment_speed(),
+            initial_capital_ratio: default_initial_capital_ratio(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ScenarioHouseholds {
+    #[serde(default = "default_household_deposits_per_capita")]
+    pub deposits_per_capita: f64,
+    #[serde(default = "default_household_debt_per_capita")]
+    pub debt_per_capita: f64,
+    #[serde(default = "default_target_buffer_days")]
+    pub target_buffer_days: f64,
+    #[serde(default = "default_wealth_drawdown_rate")]
+    pub wealth_drawdown_rate: f64,
+    #[serde(default = "default_income_smoothing")]
+    pub income_smoothing: f64,
+    #[serde(default = "default_borrowing_limit_days")]
+    pub borrowing_limit_days: f64,
+}
+
+impl Default for ScenarioHouseholds {
+    fn default() -> Self {
+        Self {
+            deposits_per_capita: default_household_deposits_per_capita(),
+            debt_per_capita: default_household_debt_per_capita(),
+            target_buffer_days: default_target_buffer_days(),
+            wealth_drawdown_rate: default_wealth_drawdown_rate(),
+            income_smoothing: default_income_smoothing(),
+            borrowing_limit_days: default_borrowing_limit_days(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ScenarioDistribution {
+    #[serde(default = "default_poverty_line_ratio")]
+    pub poverty_line_ratio: f64,
+    #[serde(default = "default_income_groups")]
+    pub groups: Vec<ScenarioIncomeGroup>,
+}
+
+impl Default for ScenarioDistribution {
+    fn default() -> Self {
+        Self {
+            poverty_line_ratio: default_poverty_line_ratio(),
+            groups: default_income_groups(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ScenarioIncomeGroup {
+    pub name: String,
+    pub population_share: f64,
+    #[serde(default = "default_group_multiplier")]
+    pub wage_multiplier: f64,
+    #[serde(default = "default_group_multiplier")]
+    pub employment_weight: f64,
+    #[serde(default = "default_group_multiplier")]
+    pub propensity_multiplier: f64,
+    #[serde(default = "default_group_multiplier")]
+    pub transfer_weight: f64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ScenarioTaxBracket {
+    pub threshold: f64,
+    pub rate: f64,
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct ScenarioFinance {
     #[serde(default = "default_initial_deposits")]
@@ -299,6 +1702,30 @@ pub struct ScenarioFinance {
     pub target_loan_to_deposit: f64,
     #[serde(default = "default_infrastructure_spend_fraction")]
     pub infrastructure_spend_fraction: f64,
+    #[serde(default = "default_firm_loans")]
+    pub firm_loans: ScenarioLoanTerms,
+    #[serde(default = "default_infrastructure_loans")]
+    pub infrastructure_loans: ScenarioLoanTerms,
+    #[serde(default = "default_loan_cohort_days")]
+    pub loan_cohort_days: f64,
+    #[serde(default = "default_initial_loan_cohorts")]
+    pub initial_loan_cohorts: usize,
+    #[serde(default = "default_arrears_default_multiplier")]
+    pub arrears_default_multiplier: f64,
+    #[serde(default = "default_initial_bank_capital_ratio")]
+    pub initial_capital_ratio: f64,
+    #[serde(default = "default_min_capital_ratio")]
+    pub min_capital_ratio: f64,
+    #[serde(default = "default_min_liquidity_ratio")]
+    pub min_liquidity_ratio: f64,
+    #[serde(default = "default_firm_risk_weight")]
+    pub firm_risk_weight: f64,
+    #[serde(default = "default_infrastructure_risk_weight")]
+    pub infrastructure_risk_weight: f64,
+    #[serde(default = "default_recapitalisation_ratio")]
+    pub recapitalisation_ratio: f64,
+    #[serde(default)]
+    pub resolution: Resolution,
 }
 
 impl Default for ScenarioFinance {
@@ -312,6 +1739,44 @@ impl Default for ScenarioFinance {
             default_rate: default_default_rate(),
             target_loan_to_deposit: default_target_loan_to_deposit(),
             infrastructure_spend_fraction: default_infrastructure_spend_fraction(),
+            firm_loans: default_firm_loans(),
+            infrastructure_loans: default_infrastructure_loans(),
+            loan_cohort_days: default_loan_cohort_days(),
+            initial_loan_cohorts: default_initial_loan_cohorts(),
+            arrears_default_multiplier: default_arrears_default_multiplier(),
+            initial_capital_ratio: default_initial_bank_capital_ratio(),
+            min_capital_ratio: default_min_capital_ratio(),
+            min_liquidity_ratio: default_min_liquidity_ratio(),
+            firm_risk_weight: default_firm_risk_weight(),
+            infrastructure_risk_weight: default_infrastructure_risk_weight(),
+            recapitalisation_ratio: default_recapitalisation_ratio(),
+            resolution: Resolution::default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ScenarioLoanTerms {
+    #[serde(default = "default_loan_maturity_years")]
+    pub maturity_years: f64,
+    #[serde(default)]
+    pub amortisation: Amortisation,
+}
+
+impl Default for ScenarioLoanTerms {
+    fn default() -> Self {
+        Self {
+            maturity_years: default_loan_maturity_years(),
+            amortisation: Amortisation::default(),
+        }
+    }
+}
+
+impl ScenarioLoanTerms {
+    fn terms(&self) -> LoanTerms {
+        LoanTerms {
+            maturity_days: self.maturity_years.max(0.0) * 365.0,
+            amortisation: self.amortisation,
         }
     }
 }
@@ -326,6 +1791,20 @@ pub struct ScenarioInfrastructure {
     pub maintenance_cost: f64,
     #[serde(default = "default_infrastructure_degradation_rate")]
     pub degradation_rate: f64,
+    #[serde(default = "default_scarcity_premium")]
+    pub scarcity_premium: f64,
+    #[serde(default)]
+    pub power_plants: Vec<ScenarioPowerPlant>,
+    #[serde(default = "default_asset_ageing")]
+    pub ageing: f64,
+    #[serde(default)]
+    pub assets: Vec<ScenarioAsset>,
+    #[serde(default = "default_outage_rate")]
+    pub outage_rate: f64,
+    #[serde(default = "default_outage_days")]
+    pub outage_days: f64,
+    #[serde(default)]
+    pub storage: Vec<ScenarioStorage>,
 }
 
 impl Default for ScenarioInfrastructure {
@@ -335,7 +1814,379 @@ impl Default for ScenarioInfrastructure {
             transport_capacity: default_transport_capacity(),
             maintenance_cost: default_infrastructure_maintenance_cost(),
             degradation_rate: default_infrastructure_degradation_rate(),
+            scarcity_premium: default_scarcity_premium(),
+            power_plants: Vec::new(),
+            ageing: default_asset_ageing(),
+            assets: Vec::new(),
+            outage_rate: default_outage_rate(),
+            outage_days: default_outage_days(),
+            storage: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ScenarioAsset {
+    pub name: String,
+    pub kind: AssetKind,
+    pub capacity: f64,
+    #[serde(default)]
+    pub age_years: f64,
+    #[serde(default)]
+    pub maintenance_cost: Option<f64>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ScenarioStorage {
+    pub name: String,
+    pub capacity: f64,
+    pub charge_rate: f64,
+    pub discharge_rate: f64,
+    #[serde(default = "default_storage_efficiency")]
+    pub efficiency: f64,
+    #[serde(default)]
+    pub initial_level: f64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ScenarioWeather {
+    #[serde(default = "default_solar_mean")]
+    pub solar_mean: f64,
+    #[serde(default = "default_solar_amplitude")]
+    pub solar_amplitude: f64,
+    #[serde(default = "default_peak_solar_day")]
+    pub peak_solar_day: f64,
+    #[serde(default = "default_cloud_volatility")]
+    pub cloud_volatility: f64,
+    #[serde(default = "default_wind_mean")]
+    pub wind_mean: f64,
+    #[serde(default = "default_wind_amplitude")]
+    pub wind_amplitude: f64,
+    #[serde(default = "default_wind_volatility")]
+    pub wind_volatility: f64,
+    #[serde(default = "default_wind_persistence")]
+    pub wind_persistence: f64,
+    #[serde(default = "default_temperature_mean")]
+    pub temperature_mean: f64,
+    #[serde(default = "default_temperature_amplitude")]
+    pub temperature_amplitude: f64,
+    #[serde(default = "default_warmest_day")]
+    pub warmest_day: f64,
+    #[serde(default = "default_temperature_noise")]
+    pub temperature_noise: f64,
+    #[serde(default = "default_precipitation_mean")]
+    pub precipitation_mean: f64,
+    #[serde(default = "default_precipitation_amplitude")]
+    pub precipitation_amplitude: f64,
+    #[serde(default = "default_wettest_day")]
+    pub wettest_day: f64,
+    #[serde(default = "default_precipitation_noise")]
+    pub precipitation_noise: f64,
+    #[serde(default = "default_optimal_temperature")]
+    pub optimal_temperature: f64,
+    #[serde(default = "default_temperature_tolerance")]
+    pub temperature_tolerance: f64,
+    #[serde(default = "default_drought_threshold")]
+    pub drought_threshold: f64,
+    #[serde(default = "default_yield_sensitivity")]
+    pub yield_sensitivity: f64,
+    #[serde(default = "default_heating_threshold")]
+    pub heating_threshold: f64,
+    #[serde(default = "default_heating_sensitivity")]
+    pub heating_sensitivity: f64,
+}
+
+impl Default for ScenarioWeather {
+    fn default() -> Self {
+        Self {
+            solar_mean: default_solar_mean(),
+            solar_amplitude: default_solar_amplitude(),
+            peak_solar_day: default_peak_solar_day(),
+            cloud_volatility: default_cloud_volatility(),
+            wind_mean: default_wind_mean(),
+            wind_amplitude: default_wind_amplitude(),
+            wind_volatility: default_wind_volatility(),
+            wind_persistence: default_wind_persistence(),
+            temperature_mean: default_temperature_mean(),
+            temperature_amplitude: default_temperature_amplitude(),
+            warmest_day: default_warmest_day(),
+            temperature_noise: default_temperature_noise(),
+            precipitation_mean: default_precipitation_mean(),
+            precipitation_amplitude: default_precipitation_amplitude(),
+            wettest_day: default_wettest_day(),
+            precipitation_noise: default_precipitation_noise(),
+            optimal_temperature: default_optimal_temperature(),
+            temperature_tolerance: default_temperature_tolerance(),
+            drought_threshold: default_drought_threshold(),
+            yield_sensitivity: default_yield_sensitivity(),
+            heating_threshold: default_heating_threshold(),
+            heating_sensitivity: default_heating_sensitivity(),
+        }
+    }
+}
+
+impl ScenarioWeather {
+    fn component(&self) -> WeatherComponent {
+        let solar_mean = self.solar_mean.clamp(0.0, 1.0);
+        let wind_mean = self.wind_mean.clamp(0.0, 1.0);
+        let mut component = WeatherComponent {
+            day_of_year: 0.0,
+            solar_mean,
+            solar_amplitude: self.solar_amplitude.clamp(0.0, 1.0),
+            peak_solar_day: self.peak_solar_day,
+            cloud_volatility: self.cloud_volatility.clamp(0.0, 1.0),
+            wind_mean,
+            wind_amplitude: self.wind_amplitude.clamp(0.0, 1.0),
+            wind_volatility: self.wind_volatility.max(0.0),
+            wind_persistence: self.wind_persistence.clamp(0.0, 1.0),
+            wind_anomaly: 0.0,
+            solar: solar_mean,
+            wind: wind_mean,
+            temperature_mean: self.temperature_mean,
+            temperature_amplitude: self.temperature_amplitude.max(0.0),
+            warmest_day: self.warmest_day,
+            temperature_noise: self.temperature_noise.max(0.0),
+            precipitation_mean: self.precipitation_mean.max(0.0),
+            precipitation_amplitude: self.precipitation_amplitude.clamp(0.0, 1.0),
+            wettest_day: self.wettest_day,
+            precipitation_noise: self.precipitation_noise.clamp(0.0, 1.0),
+            optimal_temperature: self.optimal_temperature,
+            temperature_tolerance: self.temperature_tolerance.max(1e-6),
+            drought_threshold: self.drought_threshold.max(0.0),
+            yield_sensitivity: self.yield_sensitivity.clamp(0.0, 1.0),
+            heating_threshold: self.heating_threshold,
+            heating_sensitivity: self.heating_sensitivity.max(0.0),
+            temperature: self.temperature_mean,
+            precipitation: self.precipitation_mean.max(0.0),
+            crop_yield: 1.0,
+            heating_demand: 1.0,
+            yield_baseline: 1.0,
+            heating_baseline: 1.0,
+        };
+        (component.yield_baseline, component.heating_baseline) = weather::climatology(&component);
+        component
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ScenarioDeposit {
+    pub name: String,
+    pub kind: DepositKind,
+    pub stock: f64,
+    #[serde(default)]
+    pub carrying_capacity: Option<f64>,
+    #[serde(default)]
+    pub regen_rate: Option<f64>,
+    #[serde(default = "default_quality_elasticity")]
+    pub quality_elasticity: f64,
+}
+
+impl ScenarioDeposit {
+    fn deposit(&self) -> ResourceDeposit {
+        let stock = self.stock.max(0.0);
+        let regen_rate = self.regen_rate.unwrap_or(match self.kind {
+            DepositKind::Soil => 0.2,
+            DepositKind::Fish => 0.5,
+            DepositKind::Fuel => 0.0,
+            DepositKind::Water => 1.0,
+        });
+        let mut deposit = ResourceDeposit {
+            name: self.name.clone(),
+            kind: self.kind,
+            initial: stock,
+            remaining: stock,
+            carrying_capacity: self.carrying_capacity.unwrap_or(stock).max(stock),
+            regen_rate: regen_rate.max(0.0),
+            quality_elasticity: self.quality_elasticity.max(0.0),
+            quality: 1.0,
+            extracted: 0.0,
+            last_extraction: 0.0,
+        };
+        deposit.quality = deposits::quality(&deposit);
+        deposit
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ScenarioProjectTemplate {
+    pub name: String,
+    pub kind: AssetKind,
+    pub capacity: f64,
+    pub cost: f64,
+    pub build_days: f64,
+    #[serde(default)]
+    pub maintenance_cost: f64,
+    #[serde(default)]
+    pub technology: Option<PlantTechnology>,
+    #[serde(default)]
+    pub marginal_cost: f64,
+    #[serde(default)]
+    pub fuel_per_unit: f64,
+    #[serde(default)]
+    pub emissions_per_unit: f64,
+}
+
+impl ScenarioProjectTemplate {
+    fn template(&self) -> ProjectTemplate {
+        ProjectTemplate {
+            name: self.name.clone(),
+            kind: self.kind,
+            capacity: self.capacity.max(0.0),
+            cost: self.cost.max(0.0),
+            build_days: self.build_days.max(0.0),
+            maintenance_cost: self.maintenance_cost.max(0.0),
+            technology: self.technology,
+            marginal_cost: self.marginal_cost.max(0.0),
+            fuel_per_unit: self.fuel_per_unit.max(0.0),
+            emissions_per_unit: self.emissions_per_unit.max(0.0),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ScenarioPowerPlant {
+    pub name: String,
+    pub technology: PlantTechnology,
+    pub capacity: f64,
+    pub marginal_cost: f64,
+    #[serde(default)]
+    pub fuel_per_unit: f64,
+    #[serde(default)]
+    pub emissions_per_unit: f64,
+    #[serde(default = "default_plant_online")]
+    pub online: bool,
+}
+
+impl ScenarioInfrastructure {
+    fn assets(&self) -> Vec<ScenarioAsset> {
+        if !self.assets.is_empty() {
+            return self.assets.clone();
         }
+        let seeded = |name: &str, kind, capacity: f64| ScenarioAsset {
+            name: name.into(),
+            kind,
+            capacity,
+            age_years: 0.0,
+            maintenance_cost: None,
+        };
+        let mut assets: Vec<ScenarioAsset> = if self.power_plants.is_empty() {
+            vec![seeded("Power grid", AssetKind::Plant, self.power_capacity)]
+        } else {
+            self.power_plants
+                .iter()
+                .map(|plant| seeded(&plant.name, AssetKind::Plant, plant.capacity))
+                .collect()
+        };
+        assets.push(seeded(
+            "Road network",
+            AssetKind::Road,
+            self.transport_capacity,
+        ));
+        assets
+    }
+
+    fn component(&self, catalogue: &[ScenarioProjectTemplate]) -> InfrastructureComponent {
+        let plants: Vec<PowerPlant> = self
+            .power_plants
+            .iter()
+            .enumerate()
+            .map(|(idx, plant)| PowerPlant {
+                id: idx as u64 + 1,
+                name: plant.name.clone(),
+                technology: plant.technology,
+                capacity: plant.capacity.max(0.0),
+                marginal_cost: plant.marginal_cost.max(0.0),
+                fuel_per_unit: plant.fuel_per_unit.max(0.0),
+                emissions_per_unit: plant.emissions_per_unit.max(0.0),
+                online: plant.online,
+                capacity_factor: 1.0,
+                available: 0.0,
+                output: 0.0,
+                utilisation: 0.0,
+                fuel_used: 0.0,
+                emissions: 0.0,
+            })
+            .collect();
+        let nameplate_capacity: f64 = plants.iter().map(|p| p.capacity).sum();
+        let ageing = self.ageing.max(0.0);
+        let assets = self.assets();
+        let total: f64 = assets.iter().map(|a| a.capacity.max(0.0)).sum();
+        let assets: Vec<InfrastructureAsset> = assets
+            .iter()
+            .enumerate()
+            .map(|(idx, asset)| {
+                let base_maintenance = asset.maintenance_cost.unwrap_or(if total > 0.0 {
+                    self.maintenance_cost * asset.capacity.max(0.0) / total
+                } else {
+                    0.0
+                });
+                InfrastructureAsset {
+                    id: idx as u64 + 1,
+                    name: asset.name.clone(),
+                    kind: asset.kind,
+                    capacity: asset.capacity.max(0.0),
+                    cost: 0.0,
+                    condition: 1.0,
+                    built_tick: 0,
+                    age_days: asset.age_years.max(0.0) * 365.0,
+                    base_maintenance: base_maintenance.max(0.0),
+                    maintenance_cost: base_maintenance.max(0.0)
+                        * (1.0 + ageing * asset.age_years.max(0.0)),
+                    plant: plants
+                        .iter()
+                        .find(|p| asset.kind.is_power() && p.name == asset.name)
+                        .map(|p| p.id),
+                    outage_days: 0.0,
+                }
+            })
+            .collect();
+        let next_asset_id = assets.len() as u64 + 1;
+        let mut component = InfrastructureComponent {
+            power_capacity: 0.0,
+            transport_capacity: 0.0,
+            maintenance_cost: 0.0,
+            degradation_rate: self.degradation_rate,
+            reliability: 1.0,
+            pending_investment: 0.0,
+            plants,
+            nameplate_capacity,
+            scarcity_premium: self.scarcity_premium.max(0.0),
+            price_setter: None,
+            marginal_price: 0.0,
+            emissions: 0.0,
+            cumulative_emissions: 0.0,
+            ageing,
+            assets,
+            projects: Vec::new(),
+            catalogue: catalogue.iter().map(|t| t.template()).collect(),
+            next_asset_id,
+            outage_rate: self.outage_rate.max(0.0),
+            outage_days: self.outage_days.max(0.0),
+            maintenance_funding: 1.0,
+            forced_out_capacity: 0.0,
+            outage_count: 0,
+            storage: self
+                .storage
+                .iter()
+                .enumerate()
+                .map(|(idx, unit)| {
+                    let capacity = unit.capacity.max(0.0);
+                    StorageUnit {
+                        id: idx as u64 + 1,
+                        name: unit.name.clone(),
+                        capacity,
+                        charge_rate: unit.charge_rate.max(0.0),
+                        discharge_rate: unit.discharge_rate.max(0.0),
+                        efficiency: unit.efficiency.clamp(0.0, 1.0),
+                        level: capacity * unit.initial_level.clamp(0.0, 1.0),
+                        charged: 0.0,
+                        discharged: 0.0,
+                    }
+                })
+                .collect(),
+        };
+        projects::refresh_capacity(&mut component);
+        component
     }
 }
 
@@ -359,11 +2210,57 @@ impl Default for ScenarioTechnology {
     }
 }
 
+#[derive(Debug, Clone, Deserialize)]
+pub struct ScenarioEducation {
+    #[serde(default = "default_initial_attainment")]
+    pub initial_attainment: f64,
+    #[serde(default)]
+    pub reference_attainment: Option<f64>,
+    #[serde(default = "default_min_attainment")]
+    pub min_attainment: f64,
+    #[serde(default = "default_max_attainment")]
+    pub max_attainment: f64,
+    #[serde(default = "default_education_spending_per_capita")]
+    pub reference_spending_per_capita: f64,
+    #[serde(default = "default_funding_elasticity")]
+    pub funding_elasticity: f64,
+    #[serde(default = "default_schooling_years")]
+    pub schooling_years: f64,
+    #[serde(default = "default_workforce_turnover_years")]
+    pub workforce_turnover_years: f64,
+    #[serde(default = "default_skill_productivity_elasticity")]
+    pub productivity_elasticity: f64,
+    #[serde(default = "default_mismatch_sensitivity")]
+    pub mismatch_sensitivity: f64,
+    #[serde(default = "default_skill_demand_growth")]
+    pub skill_demand_growth: f64,
+}
+
+impl Default for ScenarioEducation {
+    fn default() -> Self {
+        Self {
+            initial_attainment: default_initial_attainment(),
+            reference_attainment: None,
+            min_attainment: default_min_attainment(),
+            max_attainment: default_max_attainment(),
+            reference_spending_per_capita: default_education_spending_per_capita(),
+            funding_elasticity: default_funding_elasticity(),
+            schooling_years: default_schooling_years(),
+            workforce_turnover_years: default_workforce_turnover_years(),
+            productivity_elasticity: default_skill_productivity_elasticity(),
+            mismatch_sensitivity: default_mismatch_sensitivity(),
+            skill_demand_growth: default_skill_demand_growth(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct ScenarioPolicy {
     #[serde(default = "default_tax_rate")]
     pub tax_rate: f64,
     #[serde(default)]
+    pub tax_brackets: Vec<ScenarioTaxBracket>,
+    #[serde(default)]
     pub transfer_per_capita: Option<f64>,
     #[serde(default = "default_public_investment_fraction")]
     pub public_investment_fraction: f64,
@@ -373,17 +2270,68 @@ pub struct ScenarioPolicy {
     pub target_unemployment_rate: f64,
     #[serde(default = "default_target_primary_balance")]
     pub target_primary_balance: f64,
+    #[serde(default)]
+    pub immigration_cap: Option<f64>,
+    #[serde(default)]
+    pub emigration_cap: Option<f64>,
+    #[serde(default = "default_health_fraction")]
+    pub health_fraction: f64,
+    #[serde(default = "default_education_fraction")]
+    pub education_fraction: f64,
+    #[serde(default)]
+    pub bonds: ScenarioBonds,
+    #[serde(default)]
+    pub infrastructure_priority: Option<AssetKind>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ScenarioBonds {
+    #[serde(default = "default_bond_maturity_years")]
+    pub maturity_years: f64,
+    #[serde(default = "default_term_premium")]
+    pub term_premium: f64,
+    #[serde(default = "default_debt_threshold")]
+    pub debt_threshold: f64,
+    #[serde(default = "default_debt_sensitivity")]
+    pub debt_sensitivity: f64,
+    #[serde(default = "default_approval_sensitivity")]
+    pub approval_sensitivity: f64,
+    #[serde(default = "default_household_bond_share")]
+    pub household_share: f64,
+    #[serde(default = "default_surplus_repayment_share")]
+    pub surplus_repayment_share: f64,
+}
+
+impl Default for ScenarioBonds {
+    fn default() -> Self {
+        Self {
+            maturity_years: default_bond_maturity_years(),
+            term_premium: default_term_premium(),
+            debt_threshold: default_debt_threshold(),
+            debt_sensitivity: default_debt_sensitivity(),
+            approval_sensitivity: default_approval_sensitivity(),
+            household_share: default_household_bond_share(),
+            surplus_repayment_share: default_surplus_repayment_share(),
+        }
+    }
 }
 
 impl Default for ScenarioPolicy {
     fn default() -> Self {
         Self {
             tax_rate: default_tax_rate(),
+            tax_brackets: Vec::new(),
             transfer_per_capita: None,
             public_investment_fraction: default_public_investment_fraction(),
             rnd_fraction: default_rnd_fraction(),
             target_unemployment_rate: default_target_unemployment(),
             target_primary_balance: default_target_primary_balance(),
+            immigration_cap: None,
+            emigration_cap: None,
+            health_fraction: default_health_fraction(),
+            education_fraction: default_education_fraction(),
+            bonds: ScenarioBonds::default(),
+            infrastructure_priority: None,
         }
     }
 }
@@ -405,6 +2353,10 @@ impl ScenarioLoader {
             .with_context(|| format!("Failed to read scenario file {}", path.display()))?;
         let scenario: Scenario = serde_yaml::from_str(&data)
             .with_context(|| format!("Failed to parse {}", path.display()))?;
+        scenario
+            .supply_chain
+            .validate()
+            .with_context(|| format!("Invalid supply chain in {}", path.display()))?;
         Ok(scenario)
     }
 }
@@ -412,10 +2364,24 @@ impl ScenarioLoader {
 impl Scenario {
     pub fn build_world(&self) -> World {
         let mut world = World::new(self.dt_days);
+        world.set_start_date(self.start_date);
         for region in &self.regions {
-            let employed = (region.citizens as f64 * region.employment_rate)
+            let demography = region.demography_component();
+            let labour_force = demography.labour_force;
+            // employment_rate and the per-worker figures are shares of citizens; carry them over
+            // to the labour force so a narrower workforce keeps the same output and wage bill.
+            let labour_scale = if labour_force > 0.0 {
+                region.citizens as f64 / labour_force
+            } else {
+                1.0
+            };
+            let employed = (region.citizens as f64 * region.employment_rate / labour_scale)
+                .min(labour_force)
                 .round()
-                .clamp(0.0, region.citizens as f64) as u64;
+                .max(0.0) as u64;
+            let food_productivity = region.economy.food_productivity_per_worker * labour_scale;
+            let energy_productivity = region.economy.energy_productivity_per_worker * labour_scale;
+            let wage_per_worker = region.economy.wage_per_worker * labour_scale;
             let transfer_per_capita = region
                 .policy
                 .transfer_per_capita
@@ -423,11 +2389,15 @@ impl Scenario {
             let population = PopulationComponent {
                 citizens: region.citizens,
                 employed,
+                labour_force: labour_force.round() as u64,
                 annual_birth_rate: region.annual_birth_rate,
                 annual_death_rate: region.annual_death_rate,
                 food_consumption_per_capita: region.food_consumption_per_capita,
                 energy_consumption_per_capita: region.energy_consumption_per_capita,
-                target_employment_rate: region.employment_rate,
+                target_employment_rate: region.employment_rate / labour_scale,
+                demographic_events: self.demographic_events,
+                birth_accumulator: 0.0,
+                death_accumulator: 0.0,
             };
             let region_component = RegionComponent {
                 name: region.name.clone(),
@@ -439,11 +2409,9 @@ impl Scenario {
                     &region.technology.starting_techs,
                 );
             let economy = EconomyComponent {
-                food_productivity_per_worker: region.economy.food_productivity_per_worker
-                    * food_multiplier,
-                energy_productivity_per_worker: region.economy.energy_productivity_per_worker
-                    * energy_multiplier,
-                wage: region.economy.wage_per_worker,
+                food_productivity_per_worker: food_productivity * food_multiplier,
+                energy_productivity_per_worker: energy_productivity * energy_multiplier,
+                wage: wage_per_worker,
                 target_inventory_days: region.economy.target_inventory_days,
                 price_adjustment_rate: region.economy.price_adjustment_rate,
                 wage_adjustment_rate: region.economy.wage_adjustment_rate,
@@ -457,39 +2425,69 @@ impl Scenario {
                 food_shortage_ratio: 0.0,
                 energy_shortage_ratio: 0.0,
                 wage_bill: 0.0,
+                transfers: 0.0,
+                income_tax: 0.0,
                 sales_revenue: 0.0,
+                exports: 0.0,
+                imports: 0.0,
+                freight_cost: 0.0,
                 energy_dispatched: 0.0,
                 energy_curtailed: 0.0,
                 transport_utilization: 0.0,
                 transport_shortfall: 0.0,
             };
+            let annual_sales = 365.0
+                * region.citizens as f64
+                * (region.food_consumption_per_capita * region.economy.food_price
+                    + region.energy_consumption_per_capita * region.economy.energy_price);
+            let loan_rate = region.finance.policy_rate + region.finance.loan_rate_spread;
+            let steady_state = crate::production::steady_state_capital(
+                region.capital.capital_share,
+                annual_sales,
+                loan_rate,
+                region.capital.depreciation_rate,
+            );
+            let capital = CapitalComponent {
+                stock: steady_state * region.capital.initial_capital_ratio.max(0.0),
+                reference_per_worker: if employed > 0 {
+                    steady_state / employed as f64
+                } else {
+                    0.0
+                },
+                depreciation_rate: region.capital.depreciation_rate,
+                capital_share: region.capital.capital_share,
+                substitution_elasticity: region.capital.substitution_elasticity,
+                adjustment_speed: region.capital.adjustment_speed,
+                desired_stock: steady_state,
+                investment: 0.0,
+                depreciation: 0.0,
+                output_multiplier: 1.0,
+            };
+            let initial_income = wage_per_worker * employed as f64
+                + transfer_per_capita * (labour_force - employed as f64).max(0.0);
+            let household = HouseholdComponent {
+                deposits: region.household_deposits(),
+                debt: region.households.debt_per_capita * region.citizens as f64,
+                permanent_income: initial_income,
+                disposable_income: 0.0,
+                consumption: 0.0,
+                saving: 0.0,
+                target_buffer_days: region.households.target_buffer_days,
+                wealth_drawdown_rate: region.households.wealth_drawdown_rate,
+                income_smoothing: region.households.income_smoothing,
+                borrowing_limit_days: region.households.borrowing_limit_days,
+            };
             let stock = ResourceStock {
                 food: region.resources.food,
                 energy: region.resources.energy,
             };
-            let finance = FinanceComponent {
-                bank_deposits: region.finance.initial_deposits,
-                loan_balance: region.finance.initial_loans,
-                policy_rate: region.finance.policy_rate,
-                loan_rate_spread: region.finance.loan_rate_spread,
-                deposit_rate: region.finance.deposit_rate,
-                default_rate: region.finance.default_rate,
-                target_loan_to_deposit: region.finance.target_loan_to_deposit,
-                infrastructure_spend_fraction: region.finance.infrastructure_spend_fraction,
-                credit_stress: 0.0,
-                cumulative_defaults: 0.0,
-            };
-            let infrastructure = InfrastructureComponent {
-                power_capacity: region.infrastructure.power_capacity,
-                transport_capacity: region.infrastructure.transport_capacity,
-                maintenance_cost: region.infrastructure.maintenance_cost,
-                degradation_rate: region.infrastructure.degradation_rate,
-                reliability: 1.0,
-                pending_investment: 0.0,
-            };
+            let finance = region.finance_component();
+            let infrastructure = region
+                .infrastructure
+                .component(&self.infrastructure_projects);
             let technology = TechnologyComponent {
-                base_food_productivity: region.economy.food_productivity_per_worker,
-                base_energy_productivity: region.economy.energy_productivity_per_worker,
+                base_food_productivity: food_productivity,
+                base_energy_productivity: energy_productivity,
                 unlocked: region.technology.starting_techs.clone(),
                 active_project: None,
                 research_efficiency: region.technology.research_efficiency,
@@ -497,22 +2495,101 @@ impl Scenario {
                 current_allocation: 0.0,
                 innovation_score: 0.0,
             };
+            let share_total: f64 = region
+                .distribution
+                .groups
+                .iter()
+                .map(|g| g.population_share.max(0.0))
+                .sum();
+            let groups = region
+                .distribution
+                .groups
+                .iter()
+                .map(|group| {
+                    let share = if share_total > 0.0 {
+                        group.population_share.max(0.0) / share_total
+                    } else {
+                        0.0
+                    };
+                    IncomeGroup {
+                        name: group.name.clone(),
+                        population_share: share,
+                        wage_multiplier: group.wage_multiplier,
+                        employment_weight: group.employment_weight,
+                        propensity_multiplier: group.propensity_multiplier,
+                        transfer_weight: group.transfer_weight,
+                        members: region.citizens as f64 * share,
+                        employed: 0.0,
+                        gross_income: 0.0,
+                        income_tax: 0.0,
+                        transfers: 0.0,
+                        disposable_income: 0.0,
+                    }
+                })
+                .collect();
+            let distribution = DistributionComponent {
+                groups,
+                poverty_line_ratio: region.distribution.poverty_line_ratio,
+                income_tax: 0.0,
+                gini: 0.0,
+                palma: 0.0,
+                poverty_rate: 0.0,
+            };
+            let mut tax_brackets: Vec<TaxBracket> = region
+                .policy
+                .tax_brackets
+                .iter()
+                .map(|b| TaxBracket {
+                    threshold: b.threshold,
+                    rate: b.rate,
+                })
+                .collect();
+            tax_brackets.sort_by(|a, b| a.threshold.total_cmp(&b.threshold));
             let policy = PolicyComponent {
                 tax_rate: region.policy.tax_rate,
+                tax_brackets,
                 transfer_per_capita,
                 public_investment_fraction: region.policy.public_investment_fraction,
                 rnd_fraction: region.policy.rnd_fraction,
                 target_unemployment_rate: region.policy.target_unemployment_rate,
                 target_primary_balance: region.policy.target_primary_balance,
+                immigration_cap: region.policy.immigration_cap,
+                emigration_cap: region.policy.emigration_cap,
+                health_fraction: region.policy.health_fraction,
+                education_fraction: region.policy.education_fraction,
                 budget_balance: 0.0,
                 public_debt: 0.0,
                 approval_rating: 0.65,
                 last_tax_revenue: 0.0,
+                last_income_tax: 0.0,
                 last_transfers: 0.0,
                 last_public_investment: 0.0,
                 last_rnd_allocation: 0.0,
+                last_health_spending: 0.0,
+                last_education_spending: 0.0,
+                bond_maturity_days: region.policy.bonds.maturity_years.max(0.0) * 365.0,
+                term_premium: region.policy.bonds.term_premium,
+                debt_threshold: region.policy.bonds.debt_threshold,
+                debt_sensitivity: region.policy.bonds.debt_sensitivity,
+                approval_sensitivity: region.policy.bonds.approval_sensitivity,
+                household_bond_share: region.policy.bonds.household_share,
+                surplus_repayment_share: region.policy.bonds.surplus_repayment_share,
+                bonds: Vec::new(),
+                next_bond_id: 0,
+                bond_yield: 0.0,
+                debt_to_gdp: 0.0,
+                treasury_cash: 0.0,
+                pending_bank_support: 0.0,
+                primary_balance: 0.0,
+                last_interest: 0.0,
+                last_bank_support: 0.0,
+                last_bond_issuance: 0.0,
+                last_bond_redemptions: 0.0,
+                last_debt_change: 0.0,
+                last_cash_change: 0.0,
+                infrastructure_priority: region.policy.infrastructure_priority,
             };
-            world.spawn_region(
+            let id = world.spawn_region(
                 region_component,
                 population,
                 economy,
@@ -522,10 +2599,370 @@ impl Scenario {
                 technology,
                 policy,
             );
+            world.demographics.insert(id, demography);
+            world.capital.insert(id, capital);
+            if self.population_mode == PopulationMode::Agents {
+                let agents = self.household_agents_component(
+                    region,
+                    labour_force,
+                    employed as f64,
+                    wage_per_worker,
+                    transfer_per_capita,
+                );
+                world.household_agents.insert(id, agents);
+            }
+            world.households.insert(id, household);
+            world.distributions.insert(id, distribution);
+            world.markets.insert(id, self.market_component());
+            world.education.insert(id, region.education_component());
+            world.health.insert(id, self.health_component(region));
+            world.weather.insert(id, region.weather.component());
+            if !region.deposits.is_empty() {
+                world.deposits.insert(
+                    id,
+                    region
+                        .deposits
+                        .iter()
+                        .map(ScenarioDeposit::deposit)
+                        .collect(),
+                );
+            }
+            if !self.supply_chain.coefficients.is_empty() {
+                world
+                    .supply_chains
+                    .insert(id, self.supply_chain_component());
+            }
         }
+        world.migration = self.migration_state(&world);
+        world.epidemiology = self.health_state(&world);
+        world.fx = self.fx_market(&world);
+        world.central_banks = self.central_banks(&world);
+        world.interbank = self.interbank.market();
+        world.transport = self.transport.network();
         world
     }
 
+    fn fx_market(&self, world: &World) -> FxMarket {
+        let ids = world.entity_ids();
+        let named = |id: &EntityId, names: &[String]| {
+            world
+                .region(*id)
+                .map(|r| names.contains(&r.name))
+                .unwrap_or(false)
+        };
+        let area_of = |id: &EntityId| {
+            self.currencies
+                .areas
+                .iter()
+                .position(|area| named(id, &area.regions))
+                .unwrap_or(0)
+        };
+        let currencies = self
+            .currencies
+            .areas
+            .iter()
+            .enumerate()
+            .map(|(idx, area)| {
+                let members = ids
+                    .iter()
+                    .copied()
+                    .filter(|id| area_of(id) == idx)
+                    .collect();
+                Currency {
+                    code: area.code.clone(),
+                    members,
+                    regime: area.regime,
+                    anchor: area.anchor.clone(),
+                    peg: area.peg.max(0.0),
+                    rate: area.initial_rate.max(f64::EPSILON),
+                    policy_rate: 0.0,
+                    output: 0.0,
+                    exports: 0.0,
+                    imports: 0.0,
+                    trade_balance: 0.0,
+                    interest_differential: 0.0,
+                    reserves: 0.0,
+                    history: Vec::new(),
+                }
+            })
+            .collect();
+        FxMarket {
+            trade_sensitivity: self.currencies.trade_sensitivity,
+            interest_sensitivity: self.currencies.interest_sensitivity,
+            trade_openness: self.currencies.trade_openness.clamp(0.0, 1.0),
+            max_daily_change: self.currencies.max_daily_change.max(0.0),
+            history_ticks: self.currencies.history_ticks.max(1),
+            currencies,
+        }
+    }
+
+    fn central_banks(&self, world: &World) -> Vec<CentralBank> {
+        self.central_banks
+            .iter()
+            .map(|bank| {
+                let members: Vec<_> = match &bank.currency {
+                    Some(code) => world
+                        .fx()
+                        .currencies
+                        .iter()
+                        .find(|c| &c.code == code)
+                        .map(|c| c.members.clone())
+                        .unwrap_or_default(),
+                    None => world
+                        .entity_ids()
+                        .into_iter()
+                        .filter(|id| {
+                            bank.members.is_empty()
+                                || world
+                                    .region(*id)
+                                    .map(|r| bank.members.contains(&r.name))
+                                    .unwrap_or(false)
+                        })
+                        .collect(),
+                };
+                let current: Vec<f64> = members
+                    .iter()
+                    .filter_map(|id| world.finance(*id).map(|f| f.policy_rate))
+                    .collect();
+                let average = if current.is_empty() {
+                    bank.neutral_rate
+                } else {
+                    current.iter().sum::<f64>() / current.len() as f64
+                };
+                CentralBank {
+                    name: bank.name.clone(),
+                    currency: bank.currency.clone(),
+                    members,
+                    neutral_rate: bank.neutral_rate,
+                    inflation_target: bank.inflation_target,
+                    inflation_weight: bank.inflation_weight,
+                    gap_weight: bank.gap_weight,
+                    gap_measure: bank.gap_measure,
+                    target_unemployment_rate: bank.target_unemployment_rate,
+                    effective_lower_bound: bank.effective_lower_bound,
+                    smoothing: bank.smoothing,
+                    decision_interval_ticks: bank.decision_interval_ticks,
+                    forward_guidance: bank.forward_guidance.as_ref().map(|g| ForwardGuidance {
+                        until_tick: g.until_tick,
+                        rate: g.rate,
+                    }),
+                    policy_rate: bank
+                        .initial_rate
+                        .unwrap_or(average)
+                        .max(bank.effective_lower_bound),
+                    inflation: 0.0,
+                    gap: 0.0,
+                    price_level: 0.0,
+                    reference_price_level: 0.0,
+                    reference_tick: 0,
+                    potential_output: 0.0,
+                }
+            })
+            .collect()
+    }
+
+    fn household_agents_component(
+        &self,
+        region: &ScenarioRegion,
+        labour_force: f64,
+        employed: f64,
+        wage_per_worker: f64,
+        transfer_per_capita: f64,
+    ) -> HouseholdAgentsComponent {
+        let count = self.agents.households_per_region.max(1);
+        let members = self.agents.members_per_household.max(1.0);
+        let productivity =
+            crate::agents::dispersion_factors(count, self.agents.productivity_dispersion);
+        let wealth = crate::agents::dispersion_factors(count, self.agents.wealth_dispersion);
+        let sizes = crate::agents::dispersion_factors(count, self.agents.size_dispersion);
+        let agents = (0..count)
+            .map(|i| {
+                let size = (members * sizes[count - 1 - i]).max(1.0);
+                HouseholdAgent {
+                    weight: region.citizens as f64 / count as f64 / size,
+                    size,
+                    earners: 0.0,
+                    productivity: productivity[i],
+                    employed: false,
+                    gross_income: 0.0,
+                    income_tax: 0.0,
+                    income: 0.0,
+                    permanent_income: 0.0,
+                    deposits: region.household_deposits_per_capita() * size * wealth[i],
+                    debt: region.households.debt_per_capita * size,
+                    planned_consumption: 0.0,
+                    consumption: 0.0,
+                }
+            })
+            .collect();
+        let mut component = HouseholdAgentsComponent { agents };
+        crate::agents::set_participation(
+            &mut component,
+            if region.citizens > 0 {
+                labour_force / region.citizens as f64
+            } else {
+                0.0
+            },
+        );
+        crate::agents::assign_employment(&mut component, employed);
+        for agent in component.agents.iter_mut() {
+            agent.permanent_income = if agent.employed {
+                wage_per_worker * agent.productivity * agent.earners
+            } else {
+                transfer_per_capita * agent.earners
+            };
+        }
+        component
+    }
+
+    fn health_component(&self, region: &ScenarioRegion) -> HealthComponent {
+        let citizens = region.citizens as f64;
+        let epidemics = self
+            .health
+            .diseases
+            .iter()
+            .map(|disease| {
+                let infected: f64 = disease
+                    .seeds
+                    .iter()
+                    .filter(|seed| seed.region == region.name)
+                    .map(|seed| seed.infected.max(0.0))
+                    .sum::<f64>()
+                    .min(citizens);
+                Epidemic {
+                    disease: disease.name.clone(),
+                    susceptible: citizens - infected,
+                    infectious: infected,
+                    peak_infectious: infected,
+                    ..Epidemic::default()
+                }
+            })
+            .collect();
+        HealthComponent {
+            epidemics,
+            baseline_beds: citizens / 1000.0 * region.health.hospital_beds_per_1000.max(0.0),
+            ..HealthComponent::default()
+        }
+    }
+
+    fn health_state(&self, world: &World) -> HealthState {
+        let find = |name: &str| {
+            world
+                .entity_ids()
+                .into_iter()
+                .find(|id| world.region(*id).map(|r| r.name == name).unwrap_or(false))
+        };
+        let diseases = self
+            .health
+            .diseases
+            .iter()
+            .map(|d| Disease {
+                name: d.name.clone(),
+                transmission_rate: d.transmission_rate.max(0.0),
+                incubation_days: d.incubation_days.max(0.1),
+                infectious_days: d.infectious_days.max(0.1),
+                fatality_rate: d.fatality_rate.clamp(0.0, 1.0),
+                hospitalisation_rate: d.hospitalisation_rate.clamp(0.0, 1.0),
+            })
+            .collect();
+        let interventions = self
+            .health
+            .interventions
+            .iter()
+            .filter_map(|i| {
+                let region = match &i.region {
+                    Some(name) => Some(find(name)?),
+                    None => None,
+                };
+                Some(Intervention {
+                    kind: i.kind,
+                    region,
+                    disease: i.disease.clone(),
+                    start_tick: i.start_tick,
+                    duration_ticks: i.duration_ticks,
+                    strength: i.strength.clamp(0.0, 1.0),
+                })
+            })
+            .collect();
+        HealthState {
+            diseases,
+            interventions,
+            import_weight: self.health.import_weight.max(0.0),
+            work_absence: self.health.work_absence.clamp(0.0, 1.0),
+            overload_fatality_multiplier: self.health.overload_fatality_multiplier.max(1.0),
+            bed_cost_per_day: self.health.bed_cost_per_day.max(0.0),
+            bed_adjustment_rate: self.health.bed_adjustment_rate.clamp(0.0, 1.0),
+        }
+    }
+
+    fn migration_state(&self, world: &World) -> MigrationState {
+        let find = |name: &str| {
+            world
+                .entity_ids()
+                .into_iter()
+                .find(|id| world.region(*id).map(|r| r.name == name).unwrap_or(false))
+        };
+        let distances = self
+            .migration
+            .distances
+            .iter()
+            .filter_map(|d| {
+                Some(RegionDistance {
+                    from: find(&d.from)?,
+                    to: find(&d.to)?,
+                    distance: d.distance.max(0.0),
+                })
+            })
+            .collect();
+        MigrationState {
+            annual_base_rate: self.migration.annual_base_rate,
+            wage_weight: self.migration.wage_weight,
+            unemployment_weight: self.migration.unemployment_weight,
+            shortage_weight: self.migration.shortage_weight,
+            distance_decay: self.migration.distance_decay,
+            default_distance: self.migration.default_distance,
+            distances,
+            flows: Vec::new(),
+        }
+    }
+
+    fn market_component(&self) -> MarketComponent {
+        let food = self.markets.food.unwrap_or(self.markets.market_model);
+        let energy = self.markets.energy.unwrap_or(self.markets.market_model);
+        MarketComponent {
+            auction_levels: self.markets.auction_levels,
+            auction_spread: self.markets.auction_spread,
+            food: MarketOutcome::new(Good::Food, food),
+            energy: MarketOutcome::new(Good::Energy, energy),
+        }
+    }
+
+    fn supply_chain_component(&self) -> SupplyChainComponent {
+        let sectors: Vec<SupplyChainSector> = self
+            .supply_chain
+            .sectors
+            .iter()
+            .map(|s| SupplyChainSector {
+                name: s.name.clone(),
+                good: s.good,
+            })
+            .collect();
+        let matrix = self.supply_chain.coefficients.clone();
+        SupplyChainComponent {
+            coefficients: supply_chain::requirements(&sectors, &matrix),
+            sector_output: vec![0.0; sectors.len()],
+            sectors,
+            matrix,
+            latency_ticks: self.supply_chain.latency_ticks,
+            in_transit: Default::default(),
+            input_buffer: ResourceStock::default(),
+            intermediate_food: 0.0,
+            intermediate_energy: 0.0,
+            food_throttle: 0.0,
+            energy_throttle: 0.0,
+        }
+    }
+
     pub fn ticks(&self, override_ticks: Option<u64>) -> u64 {
         override_ticks.or(self.ticks).unwrap_or(120)
     }
@@ -5,7 +5,7 @@ use std::{
 
 use anyhow::Result;
 
-use crate::world::World;
+use crate::world::WorldSnapshot;
 
 pub struct SnapshotWriter {
     output_dir: PathBuf,
@@ -20,20 +20,20 @@ impl SnapshotWriter {
         }
     }
 
-    pub fn maybe_write(&self, world: &World, scenario: &str) -> Result<()> {
-        if self.interval_ticks == 0 || world.tick() == 0 {
-            return Ok(());
+    pub fn maybe_write(&self, snapshot: &WorldSnapshot) -> Result<bool> {
+        if self.interval_ticks == 0 || snapshot.tick == 0 {
+            return Ok(false);
         }
 
-        if world.tick() % self.interval_ticks != 0 {
-            return Ok(());
+        if !snapshot.tick.is_multiple_of(self.interval_ticks) {
+            return Ok(false);
         }
 
-        let snapshot_dir = self.output_dir.join(scenario);
+        let snapshot_dir = self.output_dir.join(&snapshot.scenario);
         fs::create_dir_all(&snapshot_dir)?;
-        let filename = snapshot_dir.join(format!("tick_{:06}.json", world.tick()));
-        let payload = serde_json::to_vec_pretty(&world.snapshot(scenario))?;
+        let filename = snapshot_dir.join(format!("tick_{:06}.json", snapshot.tick));
+        let payload = serde_json::to_vec_pretty(snapshot)?;
         fs::write(filename, payload)?;
-        Ok(())
+        Ok(true)
     }
 }
@@ -0,0 +1,50 @@
+use crate::world::StorageUnit;
+
+const EPS: f64 = 1e-9;
+
+pub fn efficiency(unit: &StorageUnit, bonus: f64) -> f64 {
+    (unit.efficiency + bonus.max(0.0)).clamp(0.0, 1.0)
+}
+
+pub fn reset(units: &mut [StorageUnit]) {
+    for unit in units.iter_mut() {
+        unit.charged = 0.0;
+        unit.discharged = 0.0;
+    }
+}
+
+pub fn charge(units: &mut [StorageUnit], surplus: f64, dt: f64, bonus: f64) -> f64 {
+    let mut remaining = surplus.max(0.0);
+    for unit in units.iter_mut() {
+        let efficiency = efficiency(unit, bonus);
+        if remaining <= EPS || efficiency <= EPS {
+            continue;
+        }
+        let room = (unit.capacity - unit.level).max(0.0) / efficiency;
+        let absorbed = remaining
+            .min(unit.charge_rate.max(0.0) * dt)
+            .min(room)
+            .max(0.0);
+        unit.level += absorbed * efficiency;
+        unit.charged += absorbed;
+        remaining -= absorbed;
+    }
+    surplus.max(0.0) - remaining
+}
+
+pub fn discharge(units: &mut [StorageUnit], deficit: f64, dt: f64) -> f64 {
+    let mut remaining = deficit.max(0.0);
+    for unit in units.iter_mut() {
+        if remaining <= EPS {
+            break;
+        }
+        let released = remaining
+            .min(unit.discharge_rate.max(0.0) * dt)
+            .min(unit.level)
+            .max(0.0);
+        unit.level -= released;
+        unit.discharged += released;
+        remaining -= released;
+    }
+    deficit.max(0.0) - remaining
+}
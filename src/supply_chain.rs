@@ -0,0 +1,273 @@
+use crate::world::{
+    Good, InputCoefficient, ResourceStock, Shipment, SupplyChainComponent, SupplyChainSector,
+};
+
+const EPS: f64 = 1e-9;
+
+pub fn leontief_inverse(matrix: &[Vec<f64>]) -> Option<Vec<Vec<f64>>> {
+    let n = matrix.len();
+    let mut lhs: Vec<Vec<f64>> = (0..n)
+        .map(|i| {
+            (0..n)
+                .map(|j| if i == j { 1.0 } else { 0.0 } - matrix[i][j])
+                .collect()
+        })
+        .collect();
+    let mut inverse: Vec<Vec<f64>> = (0..n)
+        .map(|i| (0..n).map(|j| if i == j { 1.0 } else { 0.0 }).collect())
+        .collect();
+    for col in 0..n {
+        let pivot = (col..n).max_by(|a, b| lhs[*a][col].abs().total_cmp(&lhs[*b][col].abs()))?;
+        if lhs[pivot][col].abs() <= EPS {
+            return None;
+        }
+        lhs.swap(col, pivot);
+        inverse.swap(col, pivot);
+        let scale = lhs[col][col];
+        for j in 0..n {
+            lhs[col][j] /= scale;
+            inverse[col][j] /= scale;
+        }
+        for row in 0..n {
+            let factor = lhs[row][col];
+            if row == col || factor == 0.0 {
+                continue;
+            }
+            for j in 0..n {
+                lhs[row][j] -= factor * lhs[col][j];
+                inverse[row][j] -= factor * inverse[col][j];
+            }
+        }
+    }
+    Some(inverse)
+}
+
+pub fn is_productive(matrix: &[Vec<f64>]) -> bool {
+    leontief_inverse(matrix)
+        .map(|inverse| inverse.iter().flatten().all(|v| *v >= -EPS))
+        .unwrap_or(false)
+}
+
+fn intermediate(sectors: &[SupplyChainSector]) -> Vec<usize> {
+    (0..sectors.len())
+        .filter(|i| sectors[*i].good.is_none())
+        .collect()
+}
+
+fn intermediate_inverse(sectors: &[SupplyChainSector], matrix: &[Vec<f64>]) -> Vec<Vec<f64>> {
+    let inner = intermediate(sectors);
+    let block: Vec<Vec<f64>> = inner
+        .iter()
+        .map(|i| inner.iter().map(|j| matrix[*i][*j]).collect())
+        .collect();
+    leontief_inverse(&block).unwrap_or_else(|| vec![vec![0.0; inner.len()]; inner.len()])
+}
+
+pub fn requirements(sectors: &[SupplyChainSector], matrix: &[Vec<f64>]) -> Vec<InputCoefficient> {
+    let inner = intermediate(sectors);
+    let inverse = intermediate_inverse(sectors, matrix);
+    let direct = |row: usize, good: Good| -> f64 {
+        sectors
+            .iter()
+            .enumerate()
+            .filter(|(_, s)| s.good == Some(good))
+            .map(|(j, _)| matrix[row][j])
+            .sum()
+    };
+    let mut coefficients = Vec::new();
+    for (row, sector) in sectors.iter().enumerate() {
+        let Some(output) = sector.good else {
+            continue;
+        };
+        for input in goods(sectors) {
+            let embodied: f64 = inner
+                .iter()
+                .enumerate()
+                .map(|(a, n)| {
+                    matrix[row][*n]
+                        * inner
+                            .iter()
+                            .enumerate()
+                            .map(|(b, m)| inverse[a][b] * direct(*m, input))
+                            .sum::<f64>()
+                })
+                .sum();
+            let per_unit = direct(row, input) + embodied;
+            if per_unit > EPS {
+                coefficients.push(InputCoefficient {
+                    sector: output,
+                    input,
+                    per_unit,
+                });
+            }
+        }
+    }
+    coefficients
+}
+
+fn goods(sectors: &[SupplyChainSector]) -> Vec<Good> {
+    let mut goods: Vec<Good> = Vec::new();
+    for good in sectors.iter().filter_map(|s| s.good) {
+        if !goods.contains(&good) {
+            goods.push(good);
+        }
+    }
+    goods
+}
+
+fn record_sector_output(chain: &mut SupplyChainComponent, food: f64, energy: f64) {
+    let inner = intermediate(&chain.sectors);
+    let inverse = intermediate_inverse(&chain.sectors, &chain.matrix);
+    let mut output: Vec<f64> = chain
+        .sectors
+        .iter()
+        .map(|s| match s.good {
+            Some(Good::Food) => food,
+            Some(Good::Energy) => energy,
+            None => 0.0,
+        })
+        .collect();
+    let drawn: Vec<f64> = inner
+        .iter()
+        .map(|n| {
+            (0..chain.sectors.len())
+                .filter(|s| chain.sectors[*s].good.is_some())
+                .map(|s| chain.matrix[s][*n] * output[s])
+                .sum()
+        })
+        .collect();
+    for (b, n) in inner.iter().enumerate() {
+        output[*n] = (0..inner.len()).map(|a| inverse[a][b] * drawn[a]).sum();
+    }
+    chain.sector_output = output;
+}
+
+pub fn gross_output(
+    chain: &SupplyChainComponent,
+    final_food: f64,
+    final_energy: f64,
+) -> (f64, f64) {
+    let a_ff = chain.coefficient(Good::Food, Good::Food);
+    let a_ef = chain.coefficient(Good::Food, Good::Energy);
+    let a_fe = chain.coefficient(Good::Energy, Good::Food);
+    let a_ee = chain.coefficient(Good::Energy, Good::Energy);
+    let m11 = 1.0 - a_ff;
+    let m12 = -a_fe;
+    let m21 = -a_ef;
+    let m22 = 1.0 - a_ee;
+    let det = m11 * m22 - m12 * m21;
+    if det <= EPS {
+        return (final_food, final_energy);
+    }
+    let food = (m22 * final_food - m12 * final_energy) / det;
+    let energy = (m11 * final_energy - m21 * final_food) / det;
+    (food.max(final_food), energy.max(final_energy))
+}
+
+fn requirement(chain: &SupplyChainComponent, input: Good, food: f64, energy: f64) -> f64 {
+    chain.coefficient(Good::Food, input) * food + chain.coefficient(Good::Energy, input) * energy
+}
+
+fn receive_shipments(chain: &mut SupplyChainComponent) {
+    for shipment in chain.in_transit.iter_mut() {
+        shipment.due_in_ticks = shipment.due_in_ticks.saturating_sub(1);
+    }
+    while chain
+        .in_transit
+        .front()
+        .map(|s| s.due_in_ticks == 0)
+        .unwrap_or(false)
+    {
+        if let Some(arrived) = chain.in_transit.pop_front() {
+            chain.input_buffer.food += arrived.goods.food;
+            chain.input_buffer.energy += arrived.goods.energy;
+        }
+    }
+}
+
+pub fn produce(
+    chain: &mut SupplyChainComponent,
+    stock: &mut ResourceStock,
+    planned_food: f64,
+    planned_energy: f64,
+) -> (f64, f64) {
+    if chain.latency_ticks > 0 {
+        receive_shipments(chain);
+    }
+    let inputs = goods(&chain.sectors);
+    let food_inputs: Vec<f64> = inputs
+        .iter()
+        .map(|input| chain.coefficient(Good::Food, *input))
+        .collect();
+    let energy_inputs: Vec<f64> = inputs
+        .iter()
+        .map(|input| chain.coefficient(Good::Energy, *input))
+        .collect();
+    let source = if chain.latency_ticks > 0 {
+        &mut chain.input_buffer
+    } else {
+        stock
+    };
+    let mut food_throttle: f64 = 1.0;
+    let mut energy_throttle: f64 = 1.0;
+    for (idx, input) in inputs.iter().copied().enumerate() {
+        let needed = food_inputs[idx] * planned_food + energy_inputs[idx] * planned_energy;
+        if needed <= EPS {
+            continue;
+        }
+        let ratio = (source.get(input).max(0.0) / needed).min(1.0);
+        if food_inputs[idx] > 0.0 {
+            food_throttle = food_throttle.min(ratio);
+        }
+        if energy_inputs[idx] > 0.0 {
+            energy_throttle = energy_throttle.min(ratio);
+        }
+    }
+    let food_output = planned_food * food_throttle;
+    let energy_output = planned_energy * energy_throttle;
+    let mut consumed = ResourceStock::default();
+    for (idx, input) in inputs.iter().copied().enumerate() {
+        let used = (food_inputs[idx] * food_output + energy_inputs[idx] * energy_output)
+            .min(source.get(input).max(0.0));
+        *source.get_mut(input) -= used;
+        *consumed.get_mut(input) = used;
+    }
+    chain.intermediate_food = consumed.food;
+    chain.intermediate_energy = consumed.energy;
+    chain.food_throttle = 1.0 - food_throttle;
+    chain.energy_throttle = 1.0 - energy_throttle;
+    record_sector_output(chain, food_output, energy_output);
+    (food_output, energy_output)
+}
+
+pub fn place_orders(
+    chain: &mut SupplyChainComponent,
+    stock: &mut ResourceStock,
+    next_food: f64,
+    next_energy: f64,
+) {
+    if chain.latency_ticks == 0 {
+        return;
+    }
+    let horizon = (chain.latency_ticks + 1) as f64;
+    let mut order = ResourceStock::default();
+    for input in goods(&chain.sectors) {
+        let target = requirement(chain, input, next_food, next_energy) * horizon;
+        let pipeline: f64 = chain
+            .in_transit
+            .iter()
+            .map(|s| s.goods.get(input))
+            .sum::<f64>()
+            + chain.input_buffer.get(input);
+        let wanted = (target - pipeline).max(0.0);
+        let shipped = wanted.min(stock.get(input).max(0.0));
+        *stock.get_mut(input) -= shipped;
+        *order.get_mut(input) = shipped;
+    }
+    if order.food + order.energy > EPS {
+        chain.in_transit.push_back(Shipment {
+            due_in_ticks: chain.latency_ticks,
+            goods: order,
+        });
+    }
+}
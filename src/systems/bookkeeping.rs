@@ -2,6 +2,7 @@ use anyhow::Result;
 
 use crate::{
     engine::{System, SystemContext},
+    ledger,
     rng::SystemRng,
     world::World,
 };
@@ -36,6 +37,6 @@ impl System for BookkeepingSystem {
         }
         world.bookkeeping.starving_regions.sort();
         world.bookkeeping.starving_regions.dedup();
-        Ok(())
+        ledger::verify(world)
     }
 }
@@ -0,0 +1,98 @@
+use anyhow::Result;
+
+use crate::{
+    engine::{System, SystemContext},
+    monetary,
+    rng::SystemRng,
+    world::{EventKind, World},
+};
+
+const EPS: f64 = 1e-9;
+
+pub struct CentralBankSystem;
+
+impl CentralBankSystem {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for CentralBankSystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl System for CentralBankSystem {
+    fn name(&self) -> &str {
+        "central_bank"
+    }
+
+    fn run(
+        &mut self,
+        ctx: &SystemContext,
+        world: &mut World,
+        _rng: &mut SystemRng<'_>,
+    ) -> Result<()> {
+        let mut order: Vec<usize> = (0..world.central_banks.len()).collect();
+        order.sort_by_key(|idx| {
+            monetary::anchor_policy_rate(world, &world.central_banks[*idx]).is_some()
+        });
+        for idx in order {
+            let conditions = monetary::conditions(world, &world.central_banks[idx].members);
+            let anchored = monetary::anchor_policy_rate(world, &world.central_banks[idx]);
+            let decision = {
+                let bank = &mut world.central_banks[idx];
+                bank.price_level = conditions.price_level;
+                if bank.reference_price_level <= 0.0 {
+                    bank.reference_price_level = conditions.price_level;
+                    bank.reference_tick = ctx.tick;
+                    bank.potential_output = conditions.output;
+                }
+                let trend = (ctx.dt_days / 365.0).clamp(0.0, 1.0);
+                bank.potential_output += (conditions.output - bank.potential_output) * trend;
+                bank.gap = monetary::activity_gap(bank, &conditions);
+
+                let elapsed = ctx.tick.saturating_sub(bank.reference_tick);
+                let due = elapsed >= bank.decision_interval_ticks.max(1);
+                if due {
+                    bank.inflation =
+                        monetary::annualised_inflation(bank, elapsed as f64 * ctx.dt_days);
+                }
+                let previous = bank.policy_rate;
+                bank.policy_rate = match anchored {
+                    Some(rate) => rate,
+                    None if due => monetary::decide(bank, ctx.tick),
+                    None => previous,
+                };
+                if due {
+                    bank.reference_price_level = bank.price_level;
+                    bank.reference_tick = ctx.tick;
+                }
+                if due || (bank.policy_rate - previous).abs() > EPS {
+                    Some(format!(
+                        "{} moves the policy rate from {:.2}% to {:.2}% (inflation {:.2}%, gap {:+.2}%)",
+                        bank.name,
+                        previous * 100.0,
+                        bank.policy_rate * 100.0,
+                        bank.inflation * 100.0,
+                        bank.gap * 100.0
+                    ))
+                } else {
+                    None
+                }
+            };
+            if let Some(message) = decision {
+                world.log_event(ctx.tick, EventKind::PolicyRateDecision, None, message);
+            }
+
+            let bank = &world.central_banks[idx];
+            for id in &bank.members {
+                if let Some(finance) = world.finances.get_mut(id) {
+                    finance.policy_rate = bank.policy_rate;
+                }
+            }
+        }
+        Ok(())
+    }
+}
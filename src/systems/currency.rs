@@ -0,0 +1,91 @@
+use anyhow::Result;
+
+use crate::{
+    engine::{System, SystemContext},
+    fx,
+    rng::SystemRng,
+    world::{ExchangeRegime, World},
+};
+
+pub struct CurrencySystem;
+
+impl CurrencySystem {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for CurrencySystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl System for CurrencySystem {
+    fn name(&self) -> &str {
+        "currency"
+    }
+
+    fn run(
+        &mut self,
+        ctx: &SystemContext,
+        world: &mut World,
+        _rng: &mut SystemRng<'_>,
+    ) -> Result<()> {
+        if world.fx.currencies.is_empty() {
+            return Ok(());
+        }
+        settle_rates(world, ctx.tick, ctx.dt_days);
+        Ok(())
+    }
+}
+
+fn settle_rates(world: &mut World, tick: u64, dt_days: f64) {
+    let mut currencies = std::mem::take(&mut world.fx.currencies);
+    for currency in currencies.iter_mut() {
+        let economies = currency
+            .members
+            .iter()
+            .filter_map(|id| world.economies.get(id));
+        currency.output = economies.clone().map(|e| e.sales_revenue).sum();
+        currency.exports = economies.clone().map(|e| e.exports).sum();
+        currency.imports = economies.map(|e| e.imports).sum();
+        currency.trade_balance = currency.exports - currency.imports;
+        let rates: Vec<f64> = currency
+            .members
+            .iter()
+            .filter_map(|id| world.finances.get(id).map(|f| f.policy_rate))
+            .collect();
+        if !rates.is_empty() {
+            currency.policy_rate = rates.iter().sum::<f64>() / rates.len() as f64;
+        }
+    }
+    world.fx.currencies = currencies;
+
+    let base_policy_rate = world.fx.currencies[0].policy_rate;
+    for idx in 1..world.fx.currencies.len() {
+        let currency = &world.fx.currencies[idx];
+        if currency.regime != ExchangeRegime::Floating {
+            continue;
+        }
+        let change = fx::drift(&world.fx, currency, base_policy_rate, dt_days);
+        let currency = &mut world.fx.currencies[idx];
+        currency.interest_differential = currency.policy_rate - base_policy_rate;
+        currency.rate *= change.exp();
+    }
+    for idx in 0..world.fx.currencies.len() {
+        let Some(anchor_rate) =
+            fx::anchor(&world.fx, &world.fx.currencies[idx]).map(|anchor| anchor.rate)
+        else {
+            continue;
+        };
+        let currency = &mut world.fx.currencies[idx];
+        currency.rate = currency.peg * anchor_rate;
+        currency.interest_differential = currency.policy_rate - base_policy_rate;
+        currency.reserves += currency.trade_balance * currency.rate;
+    }
+    let history_ticks = world.fx.history_ticks;
+    for currency in world.fx.currencies.iter_mut() {
+        fx::record(currency, tick, history_ticks);
+    }
+}
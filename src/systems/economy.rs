@@ -1,9 +1,16 @@
 use anyhow::Result;
 
 use crate::{
+    agents, deposits, distribution,
     engine::{System, SystemContext},
+    ledger,
+    market::{self, MarketClearing, MarketRequest},
+    power, production,
     rng::SystemRng,
-    world::{EntityId, World},
+    storage, supply_chain, technology,
+    world::{
+        EntityId, FlowKind, Good, HouseholdComponent, MarketModel, MarketOutcome, Sector, World,
+    },
 };
 
 const EPS: f64 = 1e-9;
@@ -37,16 +44,27 @@ impl System for EconomySystem {
         let mut ids: Vec<EntityId> = world.economies.keys().cloned().collect();
         ids.sort();
         for id in ids {
-            let (citizens, employed, food_per_capita, energy_per_capita) =
+            let (citizens, employed, labour_force, food_per_capita, energy_per_capita) =
                 match world.populations.get(&id) {
                     Some(pop) => (
                         pop.citizens as f64,
                         pop.employed as f64,
+                        pop.labour_force as f64,
                         pop.food_consumption_per_capita,
                         pop.energy_consumption_per_capita,
                     ),
                     None => continue,
                 };
+            let employed = match world.household_agents.get_mut(&id) {
+                Some(households) => {
+                    let employed = agents::assign_employment(households, employed);
+                    if let Some(pop) = world.populations.get_mut(&id) {
+                        pop.employed = (employed.round().max(0.0) as u64).min(pop.labour_force);
+                    }
+                    employed
+                }
+                None => employed,
+            };
             let stock = match world.resources.get_mut(&id) {
                 Some(stock) => stock,
                 None => continue,
@@ -55,9 +73,9 @@ impl System for EconomySystem {
                 Some(economy) => economy,
                 None => continue,
             };
-            let (power_capacity, transport_capacity) = world
-                .infrastructure
-                .get(&id)
+            let infrastructure = world.infrastructure.get_mut(&id);
+            let (power_capacity, transport_capacity) = infrastructure
+                .as_deref()
                 .map(|infra| (infra.power_capacity, infra.transport_capacity))
                 .unwrap_or((f64::INFINITY, f64::INFINITY));
 
@@ -75,19 +93,61 @@ impl System for EconomySystem {
                 continue;
             }
 
+            let (crop_yield, heating_demand) = world
+                .weather
+                .get(&id)
+                .map(|w| (w.crop_yield, w.heating_demand))
+                .unwrap_or((1.0, 1.0));
             let desired_food = citizens * food_per_capita * dt;
-            let desired_energy = citizens * energy_per_capita * dt;
+            let desired_energy = citizens * energy_per_capita * heating_demand * dt;
 
             let inventory_target_food = desired_food * economy.target_inventory_days;
             let inventory_target_energy = desired_energy * economy.target_inventory_days;
             let food_gap = (inventory_target_food - stock.food).max(0.0);
             let energy_gap = (inventory_target_energy - stock.energy).max(0.0);
 
-            let per_worker_food = (economy.food_productivity_per_worker * dt).max(EPS);
-            let per_worker_energy = (economy.energy_productivity_per_worker * dt).max(EPS);
+            let capital = world.capital.get_mut(&id);
+            let capital_multiplier = capital
+                .as_deref()
+                .map(|capital| production::output_multiplier(capital, employed))
+                .unwrap_or(1.0);
+            let skill_multiplier = world
+                .education
+                .get(&id)
+                .map(|education| education.productivity_multiplier)
+                .unwrap_or(1.0);
+            let labour_multiplier = capital_multiplier * skill_multiplier;
+            let mut reserves = world.deposits.get_mut(&id);
+            let (food_quality, fuel_quality) = reserves
+                .as_deref()
+                .map(|reserves| {
+                    (
+                        deposits::yield_factor(reserves, Good::Food).unwrap_or(1.0),
+                        deposits::yield_factor(reserves, Good::Energy).unwrap_or(1.0),
+                    )
+                })
+                .unwrap_or((1.0, 1.0));
+            let per_worker_food = (economy.food_productivity_per_worker
+                * labour_multiplier
+                * crop_yield
+                * food_quality
+                * dt)
+                .max(EPS);
+            let per_worker_energy =
+                (economy.energy_productivity_per_worker * labour_multiplier * fuel_quality * dt)
+                    .max(EPS);
 
-            let labor_needed_food = (desired_food + food_gap) / per_worker_food;
-            let labor_needed_energy = (desired_energy + energy_gap) / per_worker_energy;
+            let mut supply_chain = world.supply_chains.get_mut(&id);
+            let (gross_food, gross_energy) = match supply_chain.as_deref() {
+                Some(chain) => supply_chain::gross_output(
+                    chain,
+                    desired_food + food_gap,
+                    desired_energy + energy_gap,
+                ),
+                None => (desired_food + food_gap, desired_energy + energy_gap),
+            };
+            let labor_needed_food = gross_food / per_worker_food;
+            let labor_needed_energy = gross_energy / per_worker_energy;
             let total_labor_needed = labor_needed_food + labor_needed_energy;
             economy.labor_demand = total_labor_needed.max(0.0);
 
@@ -100,33 +160,167 @@ impl System for EconomySystem {
                 (employed * 0.5, employed * 0.5)
             };
 
-            stock.food += food_workers * per_worker_food;
-            let energy_output = energy_workers * per_worker_energy;
-            let max_energy_dispatch = if power_capacity.is_finite() {
-                (power_capacity * dt).max(0.0)
-            } else {
-                f64::INFINITY
+            let mut fleet = infrastructure.filter(|infra| !infra.plants.is_empty());
+            let (food_reserve, fuel_reserve) = reserves
+                .as_deref()
+                .map(|reserves| {
+                    (
+                        deposits::available(reserves, Good::Food),
+                        deposits::available(reserves, Good::Energy),
+                    )
+                })
+                .unwrap_or((None, None));
+            let energy_limit = match (fuel_reserve, fleet.as_deref_mut()) {
+                (Some(fuel), Some(infra)) => power::availability(infra, Some(fuel), dt),
+                (Some(fuel), None) => fuel,
+                (None, _) => f64::INFINITY,
             };
-            let energy_dispatched = if max_energy_dispatch.is_finite() {
-                energy_output.min(max_energy_dispatch)
-            } else {
-                energy_output
+            let planned_food =
+                (food_workers * per_worker_food).min(food_reserve.unwrap_or(f64::INFINITY));
+            let planned_energy = (energy_workers * per_worker_energy).min(energy_limit);
+            let (food_output, energy_output) = match supply_chain.as_deref_mut() {
+                Some(chain) => supply_chain::produce(chain, stock, planned_food, planned_energy),
+                None => (planned_food, planned_energy),
+            };
+            if let Some(reserves) = reserves.as_deref_mut() {
+                deposits::extract(reserves, Good::Food, food_output);
+                if fleet.is_none() {
+                    deposits::extract(reserves, Good::Energy, energy_output);
+                }
+            }
+            stock.food += food_output;
+            let mut plant_price = None;
+            let (energy_dispatched, curtailed_energy) = match fleet {
+                Some(infra) => {
+                    let dispatch =
+                        power::dispatch(infra, energy_output, gross_energy, fuel_reserve, dt);
+                    if let Some(reserves) = reserves {
+                        let burned = infra.plants.iter().map(|p| p.fuel_used).sum();
+                        deposits::extract(reserves, Good::Energy, burned);
+                    }
+                    plant_price = dispatch.price;
+                    (dispatch.dispatched, dispatch.curtailed)
+                }
+                None => {
+                    let max_energy_dispatch = if power_capacity.is_finite() {
+                        (power_capacity * dt).max(0.0)
+                    } else {
+                        f64::INFINITY
+                    };
+                    let energy_dispatched = if max_energy_dispatch.is_finite() {
+                        energy_output.min(max_energy_dispatch)
+                    } else {
+                        energy_output
+                    };
+                    (
+                        energy_dispatched,
+                        (energy_output - energy_dispatched).max(0.0),
+                    )
+                }
+            };
+            let (stored, released) = match world.infrastructure.get_mut(&id) {
+                Some(infra) if !infra.storage.is_empty() => {
+                    let bonus = world
+                        .technology
+                        .get(&id)
+                        .map(|tech| technology::storage_efficiency_bonus(&tech.unlocked))
+                        .unwrap_or(0.0);
+                    storage::reset(&mut infra.storage);
+                    let stored = storage::charge(&mut infra.storage, curtailed_energy, dt, bonus);
+                    let deficit = (desired_energy - energy_dispatched).max(0.0);
+                    (stored, storage::discharge(&mut infra.storage, deficit, dt))
+                }
+                _ => (0.0, 0.0),
             };
-            let curtailed_energy = (energy_output - energy_dispatched).max(0.0);
+            let energy_dispatched = energy_dispatched + released;
+            let curtailed_energy = curtailed_energy - stored;
             stock.energy += energy_dispatched;
+            if let Some(chain) = supply_chain {
+                supply_chain::place_orders(chain, stock, planned_food, planned_energy);
+            }
             economy.energy_dispatched = energy_dispatched;
             economy.energy_curtailed = curtailed_energy;
 
-            let wage_income = economy.wage * employed * dt;
-            let unemployed = (citizens - employed).max(0.0);
+            let unemployed = (labour_force - employed).max(0.0);
             let basic_income = economy.basic_income_per_capita * unemployed * dt;
-            let mut budget = (wage_income + basic_income).max(0.0);
-            budget *= economy.propensity_to_consume;
+            let brackets = world
+                .policies
+                .get(&id)
+                .map(|policy| policy.tax_brackets.as_slice())
+                .unwrap_or(&[]);
+            let mut household = world.households.get_mut(&id);
+            let mut households = world.household_agents.get_mut(&id);
+            let (income, budget, wage_income, transfers, income_tax) =
+                match households.as_deref_mut() {
+                    Some(households) => {
+                        let outcome = agents::distribute_income(
+                            households,
+                            brackets,
+                            economy.wage,
+                            basic_income,
+                            dt,
+                        );
+                        if let Some(groups) = world.distributions.get_mut(&id) {
+                            agents::summarise_distribution(households, groups);
+                        }
+                        let budget = agents::plan_consumption(
+                            households,
+                            household.as_deref(),
+                            economy.propensity_to_consume,
+                            dt,
+                        );
+                        (
+                            outcome.disposable.max(0.0),
+                            budget,
+                            outcome.wage_bill,
+                            outcome.transfers,
+                            outcome.income_tax,
+                        )
+                    }
+                    None => {
+                        let wage_income = economy.wage * employed * dt;
+                        let (income, propensity, transfers, income_tax) =
+                            match world.distributions.get_mut(&id) {
+                                Some(groups) => {
+                                    let disposable = distribution::distribute_income(
+                                        groups,
+                                        brackets,
+                                        citizens,
+                                        employed,
+                                        economy.wage,
+                                        basic_income,
+                                        dt,
+                                    );
+                                    (
+                                        disposable.max(0.0),
+                                        economy.propensity_to_consume
+                                            * distribution::propensity_multiplier(groups),
+                                        groups.groups.iter().map(|g| g.transfers).sum(),
+                                        groups.income_tax,
+                                    )
+                                }
+                                None => (
+                                    (wage_income + basic_income).max(0.0),
+                                    economy.propensity_to_consume,
+                                    basic_income,
+                                    0.0,
+                                ),
+                            };
+                        let budget = match household.as_deref_mut() {
+                            Some(household) => plan_consumption(household, income, propensity, dt),
+                            None => income * propensity,
+                        };
+                        (income, budget, wage_income, transfers, income_tax)
+                    }
+                };
             economy.household_budget = budget;
             economy.wage_bill = wage_income;
+            economy.transfers = transfers;
+            economy.income_tax = income_tax;
 
-            let desired_cost =
-                desired_food * economy.food_price + desired_energy * economy.energy_price;
+            let energy_floor = plant_price.unwrap_or(0.0);
+            let energy_price = economy.energy_price.max(energy_floor);
+            let desired_cost = desired_food * economy.food_price + desired_energy * energy_price;
             let demand_scale = if desired_cost > EPS {
                 (budget / desired_cost).clamp(0.0, 1.0)
             } else {
@@ -152,9 +346,56 @@ impl System for EconomySystem {
             let deliverable_food = scaled_food_demand * transport_ratio;
             let deliverable_energy = scaled_energy_demand * transport_ratio;
 
-            let sold_food = deliverable_food.min(stock.food);
+            let (food_model, energy_model, levels, spread) = world
+                .markets
+                .get(&id)
+                .map(|m| {
+                    (
+                        m.food.model,
+                        m.energy.model,
+                        m.auction_levels,
+                        m.auction_spread,
+                    )
+                })
+                .unwrap_or((MarketModel::PostedPrice, MarketModel::PostedPrice, 1, 0.0));
+            let food_cost_share = if desired_cost > EPS {
+                desired_food * economy.food_price / desired_cost
+            } else {
+                0.5
+            };
+            let food_clearing = market::clear(
+                food_model,
+                levels,
+                spread,
+                &MarketRequest {
+                    price: economy.food_price,
+                    desired: desired_food,
+                    demand: deliverable_food,
+                    budget: budget * food_cost_share,
+                    supply: stock.food.max(0.0),
+                    target_inventory: inventory_target_food,
+                    adjustment_rate: economy.price_adjustment_rate,
+                    cost_floor: 0.0,
+                },
+            );
+            let energy_clearing = market::clear(
+                energy_model,
+                levels,
+                spread,
+                &MarketRequest {
+                    price: energy_price,
+                    desired: desired_energy,
+                    demand: deliverable_energy,
+                    budget: budget * (1.0 - food_cost_share),
+                    supply: stock.energy.max(0.0),
+                    target_inventory: inventory_target_energy,
+                    adjustment_rate: economy.price_adjustment_rate,
+                    cost_floor: energy_floor,
+                },
+            );
+            let sold_food = food_clearing.volume;
             stock.food -= sold_food;
-            let sold_energy = deliverable_energy.min(stock.energy);
+            let sold_energy = energy_clearing.volume;
             stock.energy -= sold_energy;
             let delivered_total = sold_food + sold_energy;
             let transport_utilization =
@@ -171,35 +412,45 @@ impl System for EconomySystem {
             economy.transport_utilization = transport_utilization;
             economy.transport_shortfall = transport_shortfall;
 
-            let food_shortage_ratio = if desired_food > EPS {
-                ((desired_food - sold_food).max(0.0) / desired_food).clamp(0.0, 1.0)
-            } else {
-                0.0
-            };
-            let energy_shortage_ratio = if desired_energy > EPS {
-                ((desired_energy - sold_energy).max(0.0) / desired_energy).clamp(0.0, 1.0)
-            } else {
-                0.0
-            };
+            let food_shortage_ratio = market::shortage_ratio(desired_food, sold_food);
+            let energy_shortage_ratio = market::shortage_ratio(desired_energy, sold_energy);
             economy.food_shortage_ratio = food_shortage_ratio;
             economy.energy_shortage_ratio = energy_shortage_ratio;
-            economy.sales_revenue =
-                sold_food * economy.food_price + sold_energy * economy.energy_price;
-
-            adjust_price(
-                &mut economy.food_price,
-                food_shortage_ratio,
-                stock.food,
-                inventory_target_food,
-                economy.price_adjustment_rate,
-            );
-            adjust_price(
-                &mut economy.energy_price,
-                energy_shortage_ratio,
-                stock.energy,
-                inventory_target_energy,
-                economy.price_adjustment_rate,
-            );
+            economy.sales_revenue = sold_food * food_clearing.clearing_price
+                + sold_energy * energy_clearing.clearing_price;
+            economy.food_price = food_clearing.next_price;
+            economy.energy_price = energy_clearing.next_price;
+            if let Some(markets) = world.markets.get_mut(&id) {
+                record_clearing(&mut markets.food, &food_clearing);
+                record_clearing(&mut markets.energy, &energy_clearing);
+            }
+            match households {
+                Some(households) => {
+                    let fulfilment = if budget > EPS {
+                        economy.sales_revenue / budget
+                    } else {
+                        0.0
+                    };
+                    agents::settle(households, fulfilment);
+                    if let Some(household) = household {
+                        agents::summarise_households(households, household);
+                    }
+                }
+                None => {
+                    if let Some(household) = household {
+                        settle_household_budget(household, income, economy.sales_revenue);
+                    }
+                }
+            }
+            if let Some(capital) = capital {
+                let loan_rate = world
+                    .finances
+                    .get(&id)
+                    .map(|f| (f.policy_rate + f.loan_rate_spread).max(0.0))
+                    .unwrap_or(0.0);
+                production::invest(capital, employed, economy.sales_revenue, loan_rate, dt);
+                capital.output_multiplier = capital_multiplier;
+            }
 
             adjust_wages(
                 &mut economy.wage,
@@ -208,34 +459,84 @@ impl System for EconomySystem {
                 employed,
                 citizens,
             );
+            let flows = [
+                (
+                    FlowKind::Wages,
+                    Sector::Firms,
+                    Sector::Households,
+                    economy.wage_bill,
+                ),
+                (
+                    FlowKind::Transfers,
+                    Sector::Government,
+                    Sector::Households,
+                    economy.transfers,
+                ),
+                (
+                    FlowKind::IncomeTax,
+                    Sector::Households,
+                    Sector::Government,
+                    economy.income_tax,
+                ),
+                (
+                    FlowKind::Consumption,
+                    Sector::Households,
+                    Sector::Firms,
+                    economy.sales_revenue,
+                ),
+            ];
+            for (kind, from, to, amount) in flows {
+                ledger::post(&mut world.ledger, Some(id), kind, from, to, amount);
+            }
         }
         Ok(())
     }
 }
 
-fn adjust_price(
-    price: &mut f64,
-    shortage_ratio: f64,
-    inventory: f64,
-    target_inventory: f64,
-    adjustment_rate: f64,
-) {
-    let mut next_price = *price;
-    if shortage_ratio > 0.001 {
-        let pressure = shortage_ratio.min(1.0);
-        next_price *= 1.0 + adjustment_rate * pressure;
+fn plan_consumption(
+    household: &mut HouseholdComponent,
+    income: f64,
+    propensity_to_consume: f64,
+    dt: f64,
+) -> f64 {
+    let daily_income = if dt > EPS { income / dt } else { 0.0 };
+    let smoothing = (household.income_smoothing * dt).clamp(0.0, 1.0);
+    household.permanent_income += smoothing * (daily_income - household.permanent_income);
+    household.permanent_income = household.permanent_income.max(0.0);
+
+    let target_wealth = household.target_buffer_days * household.permanent_income;
+    let wealth_gap = household.net_wealth() - target_wealth;
+    let drawdown = (household.wealth_drawdown_rate * dt).clamp(0.0, 1.0);
+    let planned = propensity_to_consume * household.permanent_income * dt + drawdown * wealth_gap;
+
+    let credit_limit = household.borrowing_limit_days * household.permanent_income;
+    let headroom = (credit_limit - household.debt).max(0.0);
+    let liquidity = income + household.deposits.max(0.0) + headroom;
+    planned.clamp(0.0, liquidity.max(0.0))
+}
+
+fn settle_household_budget(household: &mut HouseholdComponent, income: f64, spending: f64) {
+    let saving = income - spending;
+    if saving >= 0.0 {
+        let repayment = saving.min(household.debt);
+        household.debt -= repayment;
+        household.deposits += saving - repayment;
     } else {
-        let ratio = if target_inventory > EPS {
-            inventory / target_inventory
-        } else {
-            1.0
-        };
-        if ratio > 1.15 {
-            let drop = ((ratio - 1.0) / ratio).min(0.5);
-            next_price *= 1.0 - adjustment_rate * drop;
-        }
+        let need = -saving;
+        let drawn = need.min(household.deposits.max(0.0));
+        household.deposits -= drawn;
+        household.debt += need - drawn;
     }
-    *price = next_price.max(0.1);
+    household.disposable_income = income;
+    household.consumption = spending;
+    household.saving = saving;
+}
+
+fn record_clearing(outcome: &mut MarketOutcome, clearing: &MarketClearing) {
+    outcome.clearing_price = clearing.clearing_price;
+    outcome.volume = clearing.volume;
+    outcome.unfilled_demand = clearing.unfilled_demand;
+    outcome.unfilled_supply = clearing.unfilled_supply;
 }
 
 fn adjust_wages(wage: &mut f64, rate: f64, labor_demand: f64, employed: f64, citizens: f64) {
@@ -0,0 +1,49 @@
+use anyhow::Result;
+
+use crate::{
+    education,
+    engine::{System, SystemContext},
+    rng::SystemRng,
+    world::{EntityId, World},
+};
+
+pub struct EducationSystem;
+
+impl EducationSystem {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for EducationSystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl System for EducationSystem {
+    fn name(&self) -> &str {
+        "education"
+    }
+
+    fn run(
+        &mut self,
+        ctx: &SystemContext,
+        world: &mut World,
+        _rng: &mut SystemRng<'_>,
+    ) -> Result<()> {
+        let mut ids: Vec<EntityId> = world.education.keys().cloned().collect();
+        ids.sort();
+        for id in ids {
+            let citizens = world
+                .populations
+                .get(&id)
+                .map(|p| p.citizens as f64)
+                .unwrap_or(0.0);
+            if let Some(component) = world.education.get_mut(&id) {
+                education::advance(component, citizens, ctx.dt_days);
+            }
+        }
+        Ok(())
+    }
+}
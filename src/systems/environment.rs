@@ -2,9 +2,11 @@ use anyhow::Result;
 use rand::Rng;
 
 use crate::{
+    deposits,
     engine::{System, SystemContext},
     rng::SystemRng,
-    world::World,
+    weather,
+    world::{Good, World},
 };
 
 pub struct EnvironmentSystem;
@@ -35,18 +37,47 @@ impl System for EnvironmentSystem {
         let dt = ctx.dt_days;
         let mut ids: Vec<_> = world.regions.keys().cloned().collect();
         ids.sort();
+        let day_of_year = world.day_of_year();
+        for &id in &ids {
+            let Some(conditions) = world.weather.get_mut(&id) else {
+                continue;
+            };
+            weather::advance(conditions, rng, day_of_year, dt);
+            if let Some(infra) = world.infrastructure.get_mut(&id) {
+                for plant in infra.plants.iter_mut() {
+                    plant.capacity_factor = weather::capacity_factor(plant.technology, conditions);
+                }
+            }
+        }
+
         for id in ids {
+            let (free_food, free_energy) = match world.deposits.get_mut(&id) {
+                Some(reserves) => {
+                    deposits::regenerate(reserves, dt);
+                    (
+                        !deposits::serves(reserves, Good::Food),
+                        !deposits::serves(reserves, Good::Energy),
+                    )
+                }
+                None => (true, true),
+            };
             if let (Some(region), Some(pop), Some(stock)) = (
                 world.regions.get(&id),
                 world.populations.get(&id),
                 world.resources.get_mut(&id),
             ) {
+                let crop_yield = world.weather.get(&id).map(|w| w.crop_yield).unwrap_or(1.0);
                 let thousands = (pop.citizens as f64 / 1_000.0).max(0.1);
                 let fluctuation: f64 = rng.gen_range(0.95..1.05);
-                let food_gain = region.food_regen_per_1000 * thousands * dt * fluctuation;
+                let food_gain =
+                    region.food_regen_per_1000 * thousands * dt * fluctuation * crop_yield;
                 let energy_gain = region.energy_regen_per_1000 * thousands * dt * fluctuation;
-                stock.food += food_gain.max(0.0);
-                stock.energy += energy_gain.max(0.0);
+                if free_food {
+                    stock.food += food_gain.max(0.0);
+                }
+                if free_energy {
+                    stock.energy += energy_gain.max(0.0);
+                }
             }
         }
         Ok(())
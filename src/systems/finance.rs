@@ -1,9 +1,13 @@
 use anyhow::Result;
 
 use crate::{
+    agents,
     engine::{System, SystemContext},
+    fx, ledger, lending,
     rng::SystemRng,
-    world::{EntityId, World},
+    world::{
+        BorrowerType, EntityId, EventKind, FlowKind, InterbankLoan, Resolution, Sector, World,
+    },
 };
 
 const EPS: f64 = 1e-9;
@@ -36,10 +40,11 @@ impl System for FinanceSystem {
         let dt_years = ctx.dt_days / 365.0;
         let mut ids: Vec<EntityId> = world.finances.keys().cloned().collect();
         ids.sort();
-        for id in ids {
+        let mut failed = Vec::new();
+        for id in ids.iter().copied() {
             let economy_snapshot = match world.economies.get(&id) {
                 Some(econ) => (
-                    econ.sales_revenue,
+                    econ.sales_revenue + econ.exports - econ.imports - econ.freight_cost,
                     econ.wage_bill,
                     econ.food_shortage_ratio,
                     econ.energy_shortage_ratio,
@@ -49,52 +54,196 @@ impl System for FinanceSystem {
             };
             let (revenue, wage_bill, food_shortage, energy_shortage, transport_shortfall) =
                 economy_snapshot;
+            let (exports, imports, freight) = world
+                .economies
+                .get(&id)
+                .map(|econ| (econ.exports, econ.imports, econ.freight_cost))
+                .unwrap_or_default();
+            let capital_investment = world.capital.get(&id).map(|c| c.investment).unwrap_or(0.0);
+            let reliability = world
+                .infrastructure
+                .get(&id)
+                .map(|i| i.reliability)
+                .unwrap_or(1.0);
+            let day = ctx.tick as f64 * ctx.dt_days;
             let mut infra_investment = 0.0;
+            let mut granted = 0.0;
+            let mut unfunded_investment = 0.0;
+            let mut postings = vec![
+                (
+                    FlowKind::Exports,
+                    Sector::RestOfWorld,
+                    Sector::Firms,
+                    exports,
+                ),
+                (
+                    FlowKind::Imports,
+                    Sector::Firms,
+                    Sector::RestOfWorld,
+                    imports,
+                ),
+                (
+                    FlowKind::Freight,
+                    Sector::Firms,
+                    Sector::Households,
+                    freight,
+                ),
+            ];
             {
                 let finance = match world.finances.get_mut(&id) {
                     Some(finance) => finance,
                     None => continue,
                 };
+                lending::reset_flows(finance, ctx.tick);
                 let mut net_cash = revenue - wage_bill;
                 if !net_cash.is_finite() {
                     net_cash = 0.0;
                 }
+                let mut cash = net_cash.max(0.0);
+                let servicing =
+                    lending::service(finance, cash + finance.bank_deposits.max(0.0), ctx.dt_days);
+                let from_cash = servicing.paid.min(cash);
+                cash -= from_cash;
+                finance.bank_deposits -= servicing.paid - from_cash;
+
                 if net_cash >= 0.0 {
-                    infra_investment = net_cash * finance.infrastructure_spend_fraction;
-                    finance.bank_deposits += net_cash - infra_investment;
+                    infra_investment = cash * finance.infrastructure_spend_fraction;
+                    finance.bank_deposits += cash - infra_investment;
                 } else {
                     let mut need = -net_cash;
                     if finance.bank_deposits >= need {
                         finance.bank_deposits -= need;
                     } else {
                         need -= finance.bank_deposits;
-                        finance.bank_deposits = 0.0;
-                        finance.loan_balance += need;
+                        let bridged = need.min(finance.interbank_proceeds.max(0.0));
+                        finance.interbank_proceeds -= bridged;
+                        finance.bank_deposits = -bridged;
+                        lending::originate(finance, BorrowerType::Firm, need - bridged, day);
                     }
                 }
 
-                let loan_rate = (finance.policy_rate + finance.loan_rate_spread).max(0.0);
-                if finance.loan_balance > 0.0 {
-                    finance.loan_balance *= 1.0 + loan_rate * dt_years;
+                if capital_investment.is_finite() && capital_investment > 0.0 {
+                    granted = capital_investment * (1.0 - finance.credit_rationing);
+                    unfunded_investment = capital_investment - granted;
+                    lending::originate(finance, BorrowerType::Firm, granted, day);
                 }
+
+                let loan_rate = lending::origination_rate(finance);
+                let mut deposit_interest = 0.0;
                 if finance.bank_deposits > 0.0 {
+                    deposit_interest +=
+                        finance.bank_deposits * finance.deposit_rate.max(0.0) * dt_years;
                     finance.bank_deposits *= 1.0 + finance.deposit_rate.max(0.0) * dt_years;
                 }
+                let firm_deposit_interest = deposit_interest;
+                let (household_deposits_before, household_debt_before) = world
+                    .households
+                    .get(&id)
+                    .map(|h| (h.deposits, h.debt))
+                    .unwrap_or((0.0, 0.0));
+                let mut household_deposits = 0.0;
+                let mut household_interest = 0.0;
+                let deposit_growth = 1.0 + finance.deposit_rate.max(0.0) * dt_years;
+                let debt_growth = 1.0 + loan_rate * dt_years;
+                if let Some(households) = world.household_agents.get_mut(&id) {
+                    agents::accrue_interest(households, deposit_growth, debt_growth);
+                }
+                if let Some(household) = world.households.get_mut(&id) {
+                    match world.household_agents.get(&id) {
+                        Some(households) => agents::summarise_households(households, household),
+                        None => {
+                            if household.deposits > 0.0 {
+                                household.deposits *= deposit_growth;
+                            }
+                            if household.debt > 0.0 {
+                                household.debt *= debt_growth;
+                            }
+                        }
+                    }
+                    household_deposits = household.deposits.max(0.0);
+                    deposit_interest += (household.deposits - household_deposits_before).max(0.0);
+                    household_interest = (household.debt - household_debt_before).max(0.0);
+                    postings.extend([
+                        (
+                            FlowKind::DepositInterest,
+                            Sector::Banks,
+                            Sector::Households,
+                            deposit_interest - firm_deposit_interest,
+                        ),
+                        (
+                            FlowKind::LoanInterest,
+                            Sector::Households,
+                            Sector::Banks,
+                            household_interest,
+                        ),
+                    ]);
+                }
 
                 let stress_signal =
                     (food_shortage + energy_shortage) * 0.5 + transport_shortfall * 0.5;
                 let stress = stress_signal.clamp(0.0, 2.0);
                 finance.credit_stress = finance.credit_stress * 0.85 + stress * 0.15;
 
-                let default_rate = finance.default_rate * (1.0 + finance.credit_stress);
-                if finance.loan_balance > 0.0 {
-                    let defaults = finance.loan_balance * default_rate * dt_years;
-                    finance.loan_balance = (finance.loan_balance - defaults).max(0.0);
-                    finance.cumulative_defaults += defaults;
+                let service_shortfall = if servicing.due > EPS {
+                    (1.0 - servicing.paid / servicing.due).clamp(0.0, 1.0)
+                } else {
+                    0.0
+                };
+                let credit_stress = finance.credit_stress;
+                lending::write_off(
+                    finance,
+                    |borrower| match borrower {
+                        BorrowerType::Firm => credit_stress + service_shortfall,
+                        BorrowerType::Infrastructure => {
+                            credit_stress + (1.0 - reliability).clamp(0.0, 1.0)
+                        }
+                    },
+                    ctx.dt_days,
+                );
+                lending::refresh(finance);
+                let firm_interest = finance.interest_received + servicing.capitalised;
+                finance.capital +=
+                    firm_interest + household_interest - deposit_interest - finance.defaults;
+                postings.extend([
+                    (
+                        FlowKind::LoanInterest,
+                        Sector::Firms,
+                        Sector::Banks,
+                        firm_interest,
+                    ),
+                    (
+                        FlowKind::LoanDefault,
+                        Sector::Banks,
+                        Sector::Firms,
+                        finance.defaults,
+                    ),
+                    (
+                        FlowKind::DepositInterest,
+                        Sector::Banks,
+                        Sector::Firms,
+                        firm_deposit_interest,
+                    ),
+                    (
+                        FlowKind::InfrastructureInvestment,
+                        Sector::Firms,
+                        Sector::Households,
+                        infra_investment,
+                    ),
+                    (
+                        FlowKind::CapitalInvestment,
+                        Sector::Firms,
+                        Sector::Households,
+                        granted,
+                    ),
+                ]);
+                lending::update_ratios(finance, household_deposits);
+                if lending::is_insolvent(finance) {
+                    failed.push(id);
                 }
 
-                let loan_to_deposit = if finance.bank_deposits > EPS {
-                    finance.loan_balance / finance.bank_deposits
+                let funding = finance.bank_deposits + household_deposits;
+                let loan_to_deposit = if funding > EPS {
+                    finance.loan_balance / funding
                 } else if finance.loan_balance > 0.0 {
                     f64::INFINITY
                 } else {
@@ -110,12 +259,271 @@ impl System for FinanceSystem {
                 finance.loan_rate_spread = finance.loan_rate_spread.clamp(0.0, 0.5);
             }
 
+            for (kind, from, to, amount) in postings {
+                ledger::post(&mut world.ledger, Some(id), kind, from, to, amount);
+            }
+            shift_household_deposits(world, id, freight + infra_investment + granted);
+            if unfunded_investment > 0.0 {
+                if let Some(capital) = world.capital.get_mut(&id) {
+                    capital.investment -= unfunded_investment;
+                    capital.stock = (capital.stock - unfunded_investment).max(0.0);
+                }
+            }
             if infra_investment > 0.0 {
                 if let Some(infra) = world.infrastructure.get_mut(&id) {
                     infra.pending_investment += infra_investment;
                 }
             }
         }
+
+        for id in failed {
+            resolve(world, id, &ids, ctx.tick);
+        }
         Ok(())
     }
 }
+
+pub(super) fn household_deposits(world: &World, id: EntityId) -> f64 {
+    world
+        .households
+        .get(&id)
+        .map(|h| h.deposits.max(0.0))
+        .unwrap_or(0.0)
+}
+
+pub(super) fn shift_household_deposits(world: &mut World, id: EntityId, delta: f64) {
+    if delta.abs() <= EPS {
+        return;
+    }
+    if let Some(households) = world.household_agents.get_mut(&id) {
+        let held: f64 = households
+            .agents
+            .iter()
+            .map(|a| a.weight * a.deposits.max(0.0))
+            .sum();
+        let weights: f64 = households.agents.iter().map(|a| a.weight).sum();
+        if held > EPS && held + delta >= 0.0 {
+            let factor = (held + delta) / held;
+            for agent in households.agents.iter_mut() {
+                if agent.deposits > 0.0 {
+                    agent.deposits *= factor;
+                }
+            }
+        } else if weights > EPS {
+            for agent in households.agents.iter_mut() {
+                agent.deposits += delta / weights;
+            }
+        }
+    }
+    if let Some(household) = world.households.get_mut(&id) {
+        match world.household_agents.get(&id) {
+            Some(households) => agents::summarise_households(households, household),
+            None => household.deposits += delta,
+        }
+    }
+}
+
+fn bail_in(world: &mut World, id: EntityId, need: f64) -> f64 {
+    let mut raised = 0.0;
+    if let Some(finance) = world.finances.get_mut(&id) {
+        raised = need.min(finance.bank_deposits.max(0.0));
+        finance.bank_deposits -= raised;
+    }
+    ledger::post(
+        &mut world.ledger,
+        Some(id),
+        FlowKind::BailIn,
+        Sector::Firms,
+        Sector::Banks,
+        raised,
+    );
+    let haircut = (need - raised).min(household_deposits(world, id));
+    if haircut > EPS {
+        shift_household_deposits(world, id, -haircut);
+        ledger::post(
+            &mut world.ledger,
+            Some(id),
+            FlowKind::BailIn,
+            Sector::Households,
+            Sector::Banks,
+            haircut,
+        );
+        raised += haircut;
+    }
+    raised
+}
+
+pub(super) fn resolve(
+    world: &mut World,
+    id: EntityId,
+    banks: &[EntityId],
+    tick: u64,
+) -> Option<EntityId> {
+    let finance = world.finances.get(&id)?;
+    let need = lending::recapitalisation_need(finance);
+    let resolution = finance.resolution;
+    let name = world
+        .regions
+        .get(&id)
+        .map(|r| r.name.clone())
+        .unwrap_or_default();
+
+    let acquirer = match resolution {
+        Resolution::Merger => banks
+            .iter()
+            .copied()
+            .filter(|other| *other != id)
+            .filter_map(|other| {
+                world
+                    .finances
+                    .get(&other)
+                    .map(|f| (other, lending::surplus_capital(f)))
+            })
+            .filter(|(other, surplus)| *surplus >= fx::convert(&world.fx, id, *other, need))
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(other, _)| other),
+        _ => None,
+    };
+
+    let (raised, cost, message) = match (resolution, acquirer) {
+        (Resolution::BailIn, _) => {
+            let raised = bail_in(world, id, need);
+            (
+                raised,
+                raised,
+                format!("{name} bank is insolvent; depositors are bailed in for {raised:.0}"),
+            )
+        }
+        (Resolution::Merger, Some(acquirer)) => {
+            let absorbed = merge(world, id, acquirer);
+            let buyer = world
+                .regions
+                .get(&acquirer)
+                .map(|r| r.name.clone())
+                .unwrap_or_default();
+            (
+                0.0,
+                absorbed,
+                format!(
+                    "{name} bank is insolvent; the {buyer} bank takes over its loans, deposits and interbank positions and absorbs a {absorbed:.0} loss"
+                ),
+            )
+        }
+        _ => {
+            if let Some(policy) = world.policies.get_mut(&id) {
+                policy.pending_bank_support += need;
+            }
+            ledger::post(
+                &mut world.ledger,
+                Some(id),
+                FlowKind::BankSupport,
+                Sector::Government,
+                Sector::Banks,
+                need,
+            );
+            let message = if resolution == Resolution::Merger {
+                format!(
+                    "{name} bank is insolvent and no peer bank has {need:.0} of surplus capital to take it over; it is bailed out with public debt instead"
+                )
+            } else {
+                format!("{name} bank is insolvent and is bailed out with {need:.0} of public debt")
+            };
+            (need, need, message)
+        }
+    };
+
+    let households = household_deposits(world, id);
+    if let Some(finance) = world.finances.get_mut(&id) {
+        finance.capital += raised;
+        finance.insolvencies += 1;
+        finance.resolution_cost += cost;
+        finance.last_insolvency_tick = Some(tick);
+        lending::update_ratios(finance, households);
+    }
+    world.log_event(tick, EventKind::BankInsolvency, Some(id), message);
+    acquirer
+}
+
+fn merge(world: &mut World, failed: EntityId, acquirer: EntityId) -> f64 {
+    let rate = fx::convert(&world.fx, failed, acquirer, 1.0);
+    let Some(target) = world.finances.get_mut(&failed) else {
+        return 0.0;
+    };
+    let loans = std::mem::take(&mut target.loans);
+    let book: f64 = loans.iter().map(|l| l.outstanding).sum();
+    let loan_balance = std::mem::take(&mut target.loan_balance);
+    let arrears = std::mem::take(&mut target.arrears);
+    let deposits = std::mem::take(&mut target.bank_deposits);
+    let lending_out = std::mem::take(&mut target.interbank_lending);
+    let borrowing = std::mem::take(&mut target.interbank_borrowing);
+    let proceeds = std::mem::take(&mut target.interbank_proceeds);
+    let equity = std::mem::take(&mut target.capital);
+
+    let netted = transfer_interbank(&mut world.interbank.loans, failed, acquirer, rate);
+    world
+        .interbank
+        .loans
+        .retain(|loan| loan.lender != loan.borrower);
+
+    let households = household_deposits(world, acquirer);
+    if let Some(buyer) = world.finances.get_mut(&acquirer) {
+        for mut loan in loans {
+            buyer.next_loan_id += 1;
+            loan.id = buyer.next_loan_id;
+            loan.principal *= rate;
+            loan.outstanding *= rate;
+            loan.arrears *= rate;
+            buyer.loans.push(loan);
+        }
+        buyer.loan_balance += loan_balance * rate;
+        buyer.arrears += arrears * rate;
+        buyer.bank_deposits += deposits * rate;
+        buyer.interbank_lending = (buyer.interbank_lending + lending_out * rate - netted).max(0.0);
+        buyer.interbank_borrowing =
+            (buyer.interbank_borrowing + borrowing * rate - netted).max(0.0);
+        buyer.interbank_proceeds += proceeds * rate;
+        buyer.capital += equity * rate;
+        lending::update_ratios(buyer, households);
+    }
+    for (sector, amount) in [(Sector::Firms, deposits - book), (Sector::Banks, equity)] {
+        ledger::post(
+            &mut world.ledger,
+            Some(failed),
+            FlowKind::Merger,
+            sector,
+            Sector::RestOfWorld,
+            amount,
+        );
+        ledger::post(
+            &mut world.ledger,
+            Some(acquirer),
+            FlowKind::Merger,
+            Sector::RestOfWorld,
+            sector,
+            amount * rate,
+        );
+    }
+    (-equity * rate).max(0.0)
+}
+
+pub(super) fn transfer_interbank(
+    loans: &mut [InterbankLoan],
+    from: EntityId,
+    to: EntityId,
+    rate: f64,
+) -> f64 {
+    let mut netted = 0.0;
+    for loan in loans.iter_mut() {
+        if loan.lender == from.raw() {
+            loan.lender = to.raw();
+            loan.amount *= rate;
+        }
+        if loan.borrower == from.raw() {
+            loan.borrower = to.raw();
+        }
+        if loan.lender == to.raw() && loan.borrower == to.raw() {
+            netted += loan.amount;
+        }
+    }
+    netted
+}
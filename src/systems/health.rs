@@ -0,0 +1,193 @@
+use anyhow::Result;
+
+use crate::{
+    agents, demography,
+    engine::{System, SystemContext},
+    health::{self, Exposure},
+    rng::SystemRng,
+    world::{EntityId, InterventionKind, World},
+};
+
+use super::migration::sync_population;
+
+const EPS: f64 = 1e-9;
+
+pub struct HealthSystem;
+
+impl HealthSystem {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for HealthSystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl System for HealthSystem {
+    fn name(&self) -> &str {
+        "health"
+    }
+
+    fn run(
+        &mut self,
+        ctx: &SystemContext,
+        world: &mut World,
+        _rng: &mut SystemRng<'_>,
+    ) -> Result<()> {
+        let dt = ctx.dt_days;
+        let mut ids: Vec<EntityId> = world.health.keys().cloned().collect();
+        ids.sort();
+        let settings = world.epidemiology.clone();
+
+        let prevalence: Vec<(EntityId, Vec<f64>)> = ids
+            .iter()
+            .filter_map(|id| {
+                let citizens = world.populations.get(id)?.citizens as f64;
+                let shares = world.health.get(id)?.epidemics.iter().map(|e| {
+                    if citizens > EPS {
+                        (e.infectious / citizens).clamp(0.0, 1.0)
+                    } else {
+                        0.0
+                    }
+                });
+                Some((*id, shares.collect()))
+            })
+            .collect();
+
+        for id in &ids {
+            let Some(population) = world.populations.get(id) else {
+                continue;
+            };
+            let citizens = population.citizens as f64;
+            let employed = population.employed as f64;
+            let active: Vec<_> = settings
+                .interventions
+                .iter()
+                .filter(|i| i.is_active(ctx.tick, *id))
+                .collect();
+            let imported: Vec<f64> = settings
+                .diseases
+                .iter()
+                .enumerate()
+                .map(|(idx, _)| {
+                    world
+                        .migration
+                        .flows
+                        .iter()
+                        .filter(|f| f.to == id.raw())
+                        .filter_map(|f| {
+                            let (_, shares) = prevalence.iter().find(|(p, _)| p.raw() == f.from)?;
+                            Some(f.people * shares.get(idx).copied().unwrap_or(0.0))
+                        })
+                        .sum::<f64>()
+                        * settings.import_weight
+                })
+                .collect();
+
+            let (deaths, sick_workers) = {
+                let Some(health) = world.health.get_mut(id) else {
+                    continue;
+                };
+                let target_beds = if settings.bed_cost_per_day > EPS && dt > EPS {
+                    health.pending_funding / (settings.bed_cost_per_day * dt)
+                } else {
+                    0.0
+                };
+                health.pending_funding = 0.0;
+                let adjustment = (settings.bed_adjustment_rate * dt).min(1.0);
+                health.funded_beds += (target_beds - health.funded_beds) * adjustment;
+                health.funded_beds = health.funded_beds.max(0.0);
+
+                let mut patients = 0.0;
+                for epidemic in health.epidemics.iter_mut() {
+                    health::rescale(epidemic, citizens);
+                    if let Some(disease) = settings
+                        .diseases
+                        .iter()
+                        .find(|d| d.name == epidemic.disease)
+                    {
+                        patients += epidemic.infectious * disease.hospitalisation_rate;
+                    }
+                }
+                let beds = health.hospital_beds();
+                health.patients = patients;
+                health.hospital_load = if beds > EPS {
+                    patients / beds
+                } else if patients > EPS {
+                    f64::INFINITY
+                } else {
+                    0.0
+                };
+                if patients > beds {
+                    health.overload_days += dt;
+                }
+                let fatality_multiplier = health::fatality_multiplier(
+                    patients,
+                    beds,
+                    settings.overload_fatality_multiplier,
+                );
+
+                let mut deaths = 0.0;
+                let mut npi_strength: f64 = 0.0;
+                for (idx, disease) in settings.diseases.iter().enumerate() {
+                    let Some(epidemic) = health
+                        .epidemics
+                        .iter_mut()
+                        .find(|e| e.disease == disease.name)
+                    else {
+                        continue;
+                    };
+                    let applicable = active.iter().filter(|i| i.applies_to(&disease.name));
+                    let (contact_reduction, vaccination_rate) =
+                        applicable.fold((0.0_f64, 0.0), |(npi, vax), i| match i.kind {
+                            InterventionKind::Npi => (npi.max(i.strength), vax),
+                            InterventionKind::Vaccination => (npi, vax + i.strength),
+                        });
+                    npi_strength = npi_strength.max(contact_reduction);
+                    let exposure = Exposure {
+                        population: citizens,
+                        imported_infectious: imported[idx],
+                        contact_reduction,
+                        vaccination_rate,
+                        fatality_multiplier,
+                    };
+                    deaths += health::step(epidemic, disease, &exposure, dt);
+                }
+                health.npi_strength = npi_strength;
+                health.deaths = deaths;
+                let remaining = (citizens - deaths).max(0.0);
+                let sick_workers =
+                    employed * health::infectious_share(health, remaining) * settings.work_absence;
+                health.sick_workers = sick_workers;
+                (deaths, sick_workers)
+            };
+
+            if deaths > EPS {
+                if let Some(households) = world.household_agents.get_mut(id) {
+                    agents::remove_persons(households, deaths);
+                }
+                match world.demographics.get_mut(id) {
+                    Some(demography) => {
+                        demography::remove_proportionally(demography, deaths);
+                    }
+                    None => {
+                        if let Some(population) = world.populations.get_mut(id) {
+                            population.citizens =
+                                population.citizens.saturating_sub(deaths.round() as u64);
+                        }
+                    }
+                }
+                sync_population(world, *id);
+            }
+            if let Some(population) = world.populations.get_mut(id) {
+                population.employed = population
+                    .employed
+                    .saturating_sub(sick_workers.round() as u64);
+            }
+        }
+        Ok(())
+    }
+}
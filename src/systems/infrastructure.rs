@@ -1,9 +1,11 @@
 use anyhow::Result;
 
+use super::finance::shift_household_deposits;
 use crate::{
     engine::{System, SystemContext},
+    ledger, lending, projects,
     rng::SystemRng,
-    world::{EntityId, World},
+    world::{BorrowerType, EntityId, EventKind, FlowKind, Sector, World},
 };
 
 const EPS: f64 = 1e-9;
@@ -31,7 +33,7 @@ impl System for InfrastructureSystem {
         &mut self,
         ctx: &SystemContext,
         world: &mut World,
-        _rng: &mut SystemRng<'_>,
+        rng: &mut SystemRng<'_>,
     ) -> Result<()> {
         let dt = ctx.dt_days;
         let mut ids: Vec<EntityId> = world.infrastructure.keys().cloned().collect();
@@ -45,22 +47,24 @@ impl System for InfrastructureSystem {
                     econ.energy_dispatched,
                 )
             });
-            let maintenance_cost = {
+            let priority = world
+                .policies
+                .get(&id)
+                .and_then(|policy| policy.infrastructure_priority);
+            let (maintenance_cost, completed, tripped) = {
                 let infra = match world.infrastructure.get_mut(&id) {
                     Some(infra) => infra,
                     None => continue,
                 };
                 let degrade = (infra.degradation_rate * dt).clamp(0.0, 0.5);
-                infra.power_capacity = (infra.power_capacity * (1.0 - degrade)).max(0.0);
-                infra.transport_capacity =
-                    (infra.transport_capacity * (1.0 - degrade * 0.8)).max(0.0);
-
-                let realized = (infra.pending_investment * 0.2).min(infra.pending_investment);
-                if realized > 0.0 {
-                    infra.power_capacity += realized * 0.6;
-                    infra.transport_capacity += realized * 0.4;
-                    infra.pending_investment -= realized;
-                }
+                projects::age(infra, dt);
+                let tripped = projects::outages(infra, rng, dt);
+                let (energy_signal, transport_signal) = economy_view
+                    .map(|(energy, transport, _, _)| (energy, transport))
+                    .unwrap_or((0.0, 0.0));
+                projects::fund(infra, ctx.tick, priority, energy_signal, transport_signal);
+                let completed = projects::advance(infra, ctx.tick, dt);
+                projects::refresh_capacity(infra);
 
                 if let Some((energy_shortage, transport_shortfall, curtailed, dispatched)) =
                     economy_view
@@ -77,19 +81,74 @@ impl System for InfrastructureSystem {
                 } else {
                     infra.reliability *= 1.0 - degrade * 0.4;
                 }
-                infra.maintenance_cost * dt
+                (infra.maintenance_cost * dt, completed, tripped)
             };
 
+            let name = world
+                .regions
+                .get(&id)
+                .map(|region| region.name.clone())
+                .unwrap_or_default();
+            for asset in completed {
+                let capacity = if asset.kind.is_power() {
+                    "power"
+                } else {
+                    "transport"
+                };
+                world.log_event(
+                    ctx.tick,
+                    EventKind::ProjectCompleted,
+                    Some(id),
+                    format!(
+                        "{name} completes {} (+{:.0} {capacity} capacity)",
+                        asset.name, asset.capacity
+                    ),
+                );
+            }
+            for asset in tripped {
+                world.log_event(
+                    ctx.tick,
+                    EventKind::InfrastructureOutage,
+                    Some(id),
+                    format!(
+                        "{name} loses {} ({:.0} capacity) for {:.0} days",
+                        asset.name, asset.capacity, asset.outage_days
+                    ),
+                );
+            }
+
             if maintenance_cost > 0.0 {
+                let mut paid = maintenance_cost;
                 if let Some(finance) = world.finances.get_mut(&id) {
-                    if finance.bank_deposits >= maintenance_cost {
+                    lending::reset_flows(finance, ctx.tick);
+                    let available = finance.bank_deposits.max(0.0);
+                    if available >= maintenance_cost {
                         finance.bank_deposits -= maintenance_cost;
                     } else {
-                        let remaining = maintenance_cost - finance.bank_deposits;
-                        finance.bank_deposits = 0.0;
-                        finance.loan_balance += remaining;
+                        let remaining = maintenance_cost - available;
+                        let drawn = remaining * (1.0 - finance.credit_rationing.clamp(0.0, 1.0));
+                        paid = available + drawn;
+                        finance.bank_deposits -= available;
+                        lending::originate(
+                            finance,
+                            BorrowerType::Infrastructure,
+                            drawn,
+                            ctx.tick as f64 * ctx.dt_days,
+                        );
                     }
                 }
+                if let Some(infra) = world.infrastructure.get_mut(&id) {
+                    infra.maintenance_funding = (paid / maintenance_cost).clamp(0.0, 1.0);
+                }
+                ledger::post(
+                    &mut world.ledger,
+                    Some(id),
+                    FlowKind::Maintenance,
+                    Sector::Firms,
+                    Sector::Households,
+                    paid,
+                );
+                shift_household_deposits(world, id, paid);
             }
         }
         Ok(())
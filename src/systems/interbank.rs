@@ -0,0 +1,270 @@
+use anyhow::Result;
+
+use super::finance::{household_deposits, resolve, transfer_interbank};
+use crate::{
+    engine::{System, SystemContext},
+    fx, ledger, lending,
+    rng::SystemRng,
+    world::{EntityId, FlowKind, InterbankLoan, Resolution, Sector, World},
+};
+
+const EPS: f64 = 1e-9;
+
+pub struct InterbankSystem;
+
+impl InterbankSystem {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for InterbankSystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl System for InterbankSystem {
+    fn name(&self) -> &str {
+        "interbank"
+    }
+
+    fn run(
+        &mut self,
+        ctx: &SystemContext,
+        world: &mut World,
+        _rng: &mut SystemRng<'_>,
+    ) -> Result<()> {
+        if !world.interbank.enabled {
+            return Ok(());
+        }
+        let dt_years = ctx.dt_days / 365.0;
+        let mut ids: Vec<EntityId> = world.finances.keys().cloned().collect();
+        ids.sort();
+        let lookup = |raw: u64| ids.iter().copied().find(|id| id.raw() == raw);
+
+        let mut loans = std::mem::take(&mut world.interbank.loans);
+        world.interbank.losses = 0.0;
+        world.interbank.contagion_failures = 0;
+        for finance in world.finances.values_mut() {
+            finance.interbank_lending = 0.0;
+            finance.interbank_borrowing = 0.0;
+            finance.interbank_proceeds = 0.0;
+            finance.interbank_rate = 0.0;
+            finance.interbank_losses = 0.0;
+        }
+
+        let recovery = world.interbank.recovery_rate;
+        let defaulted = |world: &World, id: EntityId| {
+            world
+                .finances
+                .get(&id)
+                .map(|f| {
+                    f.last_insolvency_tick == Some(ctx.tick) && f.resolution == Resolution::BailIn
+                })
+                .unwrap_or(false)
+        };
+        let mut failed: Vec<EntityId> = ids
+            .iter()
+            .copied()
+            .filter(|id| defaulted(world, *id))
+            .collect();
+        let mut settled = vec![false; loans.len()];
+        loop {
+            let mut contagion = Vec::new();
+            for (idx, loan) in loans.iter().enumerate() {
+                let (Some(lender), Some(borrower)) = (lookup(loan.lender), lookup(loan.borrower))
+                else {
+                    settled[idx] = true;
+                    continue;
+                };
+                if settled[idx] || !failed.contains(&borrower) {
+                    continue;
+                }
+                settled[idx] = true;
+                let loss = loan.amount * (1.0 - recovery);
+                let relief = fx::convert(&world.fx, lender, borrower, loss);
+                if let Some(finance) = world.finances.get_mut(&borrower) {
+                    finance.capital += relief;
+                }
+                if let Some(finance) = world.finances.get_mut(&lender) {
+                    finance.capital -= loss;
+                    finance.interbank_losses += loss;
+                    if lending::is_insolvent(finance)
+                        && finance.last_insolvency_tick != Some(ctx.tick)
+                        && !contagion.contains(&lender)
+                    {
+                        contagion.push(lender);
+                    }
+                }
+                world.interbank.losses += loss;
+                settle(
+                    world,
+                    lender,
+                    borrower,
+                    FlowKind::InterbankLoss,
+                    loss,
+                    relief,
+                );
+            }
+            if contagion.is_empty() {
+                break;
+            }
+            for id in contagion {
+                if let Some(acquirer) = resolve(world, id, &ids, ctx.tick) {
+                    let rate = fx::convert(&world.fx, id, acquirer, 1.0);
+                    transfer_interbank(&mut loans, id, acquirer, rate);
+                    for (loan, settled) in loans.iter().zip(settled.iter_mut()) {
+                        if loan.lender == loan.borrower {
+                            *settled = true;
+                        }
+                    }
+                }
+                world.interbank.contagion_failures += 1;
+                if defaulted(world, id) {
+                    failed.push(id);
+                }
+            }
+        }
+
+        for (loan, settled) in loans.iter().zip(settled) {
+            if settled {
+                continue;
+            }
+            let interest = loan.amount * loan.rate * dt_years;
+            let (Some(lender), Some(borrower)) = (lookup(loan.lender), lookup(loan.borrower))
+            else {
+                continue;
+            };
+            let cost = fx::convert(&world.fx, lender, borrower, interest);
+            if let Some(finance) = world.finances.get_mut(&borrower) {
+                finance.capital -= cost;
+            }
+            if let Some(finance) = world.finances.get_mut(&lender) {
+                finance.capital += interest;
+            }
+            settle(
+                world,
+                borrower,
+                lender,
+                FlowKind::InterbankInterest,
+                cost,
+                interest,
+            );
+        }
+
+        let positions: Vec<(EntityId, f64)> = ids
+            .iter()
+            .filter_map(|id| {
+                let finance = world.finances.get(id)?;
+                let position = lending::liquidity_surplus(finance, household_deposits(world, *id));
+                (!lending::is_insolvent(finance)).then_some((*id, position))
+            })
+            .collect();
+        let mut lenders: Vec<(EntityId, f64)> = positions
+            .iter()
+            .copied()
+            .filter(|(_, p)| *p > EPS)
+            .collect();
+        lenders.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+        let market = world.interbank.clone();
+        let mut matched = Vec::new();
+        for (borrower, position) in positions.iter().copied().filter(|(_, p)| *p < -EPS) {
+            let stress = world
+                .finances
+                .get(&borrower)
+                .map(|f| f.credit_stress)
+                .unwrap_or(0.0);
+            let appetite = (1.0 - market.stress_sensitivity * stress).clamp(0.0, 1.0);
+            let mut need = -position;
+            for (lender, available) in lenders.iter_mut() {
+                if need <= EPS {
+                    break;
+                }
+                let Some(finance) = world.finances.get(lender) else {
+                    continue;
+                };
+                let limit = market.limit_share * finance.capital.max(0.0) * appetite;
+                let cross = fx::convert(&world.fx, *lender, borrower, 1.0);
+                let amount = (need / cross).min(*available).min(limit);
+                if amount <= EPS {
+                    continue;
+                }
+                let rate =
+                    (finance.policy_rate + market.spread + market.stress_premium * stress).max(0.0);
+                *available -= amount;
+                need -= amount * cross;
+                matched.push(InterbankLoan {
+                    lender: lender.raw(),
+                    borrower: borrower.raw(),
+                    amount,
+                    rate,
+                });
+            }
+        }
+
+        let mut volume = 0.0;
+        let mut weighted_rate = 0.0;
+        for loan in &matched {
+            let (Some(lender), Some(borrower)) = (lookup(loan.lender), lookup(loan.borrower))
+            else {
+                continue;
+            };
+            let base_amount = loan.amount * fx::rate(&world.fx, lender);
+            volume += base_amount;
+            weighted_rate += base_amount * loan.rate;
+            if let Some(finance) = world.finances.get_mut(&lender) {
+                finance.interbank_lending += loan.amount;
+            }
+            let received = fx::convert(&world.fx, lender, borrower, loan.amount);
+            if let Some(finance) = world.finances.get_mut(&borrower) {
+                finance.interbank_rate = (finance.interbank_rate * finance.interbank_borrowing
+                    + loan.rate * received)
+                    / (finance.interbank_borrowing + received);
+                finance.interbank_borrowing += received;
+                finance.interbank_proceeds += received;
+            }
+        }
+        for id in &ids {
+            let households = household_deposits(world, *id);
+            if let Some(finance) = world.finances.get_mut(id) {
+                lending::update_ratios(finance, households);
+            }
+        }
+        world.interbank.volume = volume;
+        world.interbank.average_rate = if volume > EPS {
+            weighted_rate / volume
+        } else {
+            0.0
+        };
+        world.interbank.loans = matched;
+        Ok(())
+    }
+}
+
+fn settle(
+    world: &mut World,
+    payer: EntityId,
+    payee: EntityId,
+    kind: FlowKind,
+    paid: f64,
+    received: f64,
+) {
+    ledger::post(
+        &mut world.ledger,
+        Some(payer),
+        kind,
+        Sector::Banks,
+        Sector::RestOfWorld,
+        paid,
+    );
+    ledger::post(
+        &mut world.ledger,
+        Some(payee),
+        kind,
+        Sector::RestOfWorld,
+        Sector::Banks,
+        received,
+    );
+}
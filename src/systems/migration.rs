@@ -0,0 +1,214 @@
+use anyhow::Result;
+
+use crate::{
+    agents, demography,
+    engine::{System, SystemContext},
+    fx,
+    rng::SystemRng,
+    world::{EntityId, MigrationFlow, World},
+};
+
+const EPS: f64 = 1e-9;
+
+pub struct MigrationSystem;
+
+impl MigrationSystem {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for MigrationSystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct Conditions {
+    id: EntityId,
+    citizens: f64,
+    wage: f64,
+    unemployment: f64,
+    food_shortage: f64,
+    emigration_cap: Option<f64>,
+    immigration_cap: Option<f64>,
+}
+
+impl System for MigrationSystem {
+    fn name(&self) -> &str {
+        "migration"
+    }
+
+    fn run(
+        &mut self,
+        ctx: &SystemContext,
+        world: &mut World,
+        _rng: &mut SystemRng<'_>,
+    ) -> Result<()> {
+        let dt_years = ctx.dt_days / 365.0;
+        let mut ids: Vec<EntityId> = world.populations.keys().cloned().collect();
+        ids.sort();
+        let conditions: Vec<Conditions> = ids
+            .iter()
+            .filter_map(|id| {
+                let population = world.populations.get(id)?;
+                let economy = world.economies.get(id)?;
+                let policy = world.policies.get(id);
+                let unemployment = if population.labour_force > 0 {
+                    (1.0 - population.employed as f64 / population.labour_force as f64).max(0.0)
+                } else {
+                    0.0
+                };
+                Some(Conditions {
+                    id: *id,
+                    citizens: population.citizens as f64,
+                    wage: economy.wage.max(EPS),
+                    unemployment,
+                    food_shortage: economy.food_shortage_ratio,
+                    emigration_cap: policy.and_then(|p| p.emigration_cap),
+                    immigration_cap: policy.and_then(|p| p.immigration_cap),
+                })
+            })
+            .collect();
+
+        let settings = &world.migration;
+        let n = conditions.len();
+        let mut matrix = vec![vec![0.0; n]; n];
+        for (i, origin) in conditions.iter().enumerate() {
+            if origin.citizens <= 0.0 {
+                continue;
+            }
+            for (j, destination) in conditions.iter().enumerate() {
+                if i == j {
+                    continue;
+                }
+                let wage = fx::convert(&world.fx, destination.id, origin.id, destination.wage);
+                let pull = settings.wage_weight * (wage.max(EPS) / origin.wage).ln()
+                    + settings.unemployment_weight
+                        * (origin.unemployment - destination.unemployment)
+                    + settings.shortage_weight * (origin.food_shortage - destination.food_shortage);
+                if pull <= 0.0 {
+                    continue;
+                }
+                let distance = settings.distance(origin.id, destination.id);
+                let friction = (-settings.distance_decay.max(0.0) * distance).exp();
+                matrix[i][j] = origin.citizens
+                    * settings.annual_base_rate.max(0.0)
+                    * pull
+                    * friction
+                    * dt_years;
+            }
+        }
+
+        for (i, origin) in conditions.iter().enumerate() {
+            let outflow: f64 = matrix[i].iter().sum();
+            let limit = origin
+                .emigration_cap
+                .map(|cap| cap.max(0.0) * origin.citizens * dt_years)
+                .unwrap_or(f64::INFINITY)
+                .min(origin.citizens * 0.5);
+            if outflow > limit && outflow > EPS {
+                let scale = limit / outflow;
+                matrix[i].iter_mut().for_each(|flow| *flow *= scale);
+            }
+        }
+        for (j, destination) in conditions.iter().enumerate() {
+            let Some(cap) = destination.immigration_cap else {
+                continue;
+            };
+            let inflow: f64 = matrix.iter().map(|row| row[j]).sum();
+            let limit = cap.max(0.0) * destination.citizens * dt_years;
+            if inflow > limit && inflow > EPS {
+                let scale = limit / inflow;
+                matrix.iter_mut().for_each(|row| row[j] *= scale);
+            }
+        }
+
+        let mut flows = Vec::new();
+        for (i, origin) in conditions.iter().enumerate() {
+            for (j, destination) in conditions.iter().enumerate() {
+                let people = matrix[i][j];
+                if people <= EPS {
+                    continue;
+                }
+                let moved = relocate(world, origin.id, destination.id, people);
+                if moved > EPS {
+                    flows.push(MigrationFlow {
+                        from: origin.id.raw(),
+                        to: destination.id.raw(),
+                        people: moved,
+                    });
+                }
+            }
+        }
+        for id in &ids {
+            sync_population(world, *id);
+        }
+        world.migration.flows = flows;
+        Ok(())
+    }
+}
+
+fn relocate(world: &mut World, from: EntityId, to: EntityId, people: f64) -> f64 {
+    let moved = match world.demographics.get_mut(&from) {
+        Some(origin) => {
+            let migrants = demography::take_migrants(origin, people);
+            let moved: f64 = migrants.iter().map(|(_, people)| people).sum();
+            match world.demographics.get_mut(&to) {
+                Some(destination) => demography::add_migrants(destination, &migrants),
+                None => {
+                    if let Some(destination) = world.populations.get_mut(&to) {
+                        destination.citizens += moved.round() as u64;
+                    }
+                }
+            }
+            moved
+        }
+        None => {
+            let Some(population) = world.populations.get_mut(&from) else {
+                return 0.0;
+            };
+            let moved = (people.round() as u64).min(population.citizens);
+            population.citizens -= moved;
+            if let Some(destination) = world.populations.get_mut(&to) {
+                destination.citizens += moved;
+            }
+            moved as f64
+        }
+    };
+    if let Some(households) = world.household_agents.get_mut(&from) {
+        agents::remove_persons(households, moved);
+    }
+    if let Some(households) = world.household_agents.get_mut(&to) {
+        agents::add_persons(households, moved);
+    }
+    moved
+}
+
+pub(super) fn sync_population(world: &mut World, id: EntityId) {
+    let Some(population) = world.populations.get_mut(&id) else {
+        return;
+    };
+    if let Some(demography) = world.demographics.get_mut(&id) {
+        demography::refresh_indicators(demography);
+        population.citizens = demography::total(demography).round().max(0.0) as u64;
+        population.labour_force =
+            (demography.labour_force.round().max(0.0) as u64).min(population.citizens);
+    } else {
+        population.labour_force = population.labour_force.min(population.citizens);
+    }
+    if let Some(households) = world.household_agents.get_mut(&id) {
+        agents::set_participation(
+            households,
+            if population.citizens > 0 {
+                population.labour_force as f64 / population.citizens as f64
+            } else {
+                0.0
+            },
+        );
+        population.citizens = agents::persons(households).round().max(0.0) as u64;
+        population.labour_force =
+            (agents::labour_force(households).round().max(0.0) as u64).min(population.citizens);
+    }
+    population.employed = population.employed.min(population.labour_force);
+}
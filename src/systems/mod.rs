@@ -1,17 +1,33 @@
 mod bookkeeping;
+mod central_bank;
+mod currency;
 mod economy;
+mod education;
 mod environment;
 mod finance;
+mod health;
 mod infrastructure;
+mod interbank;
+mod migration;
 mod policy;
 mod population;
 mod technology;
+mod trade;
+mod transport;
 
 pub use bookkeeping::BookkeepingSystem;
+pub use central_bank::CentralBankSystem;
+pub use currency::CurrencySystem;
 pub use economy::EconomySystem;
+pub use education::EducationSystem;
 pub use environment::EnvironmentSystem;
 pub use finance::FinanceSystem;
+pub use health::HealthSystem;
 pub use infrastructure::InfrastructureSystem;
+pub use interbank::InterbankSystem;
+pub use migration::MigrationSystem;
 pub use policy::PolicySystem;
 pub use population::PopulationSystem;
 pub use technology::TechnologySystem;
+pub use trade::TradeSystem;
+pub use transport::TransportSystem;
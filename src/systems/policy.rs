@@ -1,9 +1,11 @@
 use anyhow::Result;
 
+use super::finance::{household_deposits, shift_household_deposits};
 use crate::{
     engine::{System, SystemContext},
+    fiscal, ledger, lending,
     rng::SystemRng,
-    world::{EntityId, World},
+    world::{EntityId, FlowKind, Sector, World},
 };
 
 pub struct PolicySystem;
@@ -36,7 +38,11 @@ impl System for PolicySystem {
         ids.sort();
         for id in ids {
             let population = match world.populations.get(&id) {
-                Some(pop) => (pop.citizens as f64, pop.employed as f64),
+                Some(pop) => (
+                    pop.citizens as f64,
+                    pop.employed as f64,
+                    pop.labour_force as f64,
+                ),
                 None => continue,
             };
             if population.0 <= 0.0 {
@@ -48,6 +54,8 @@ impl System for PolicySystem {
                     econ.food_shortage_ratio,
                     econ.energy_shortage_ratio,
                     econ.transport_shortfall,
+                    econ.income_tax,
+                    econ.transfers,
                 ),
                 None => continue,
             };
@@ -56,25 +64,48 @@ impl System for PolicySystem {
                 .get(&id)
                 .map(|tech| tech.baseline_rnd_budget_per_capita)
                 .unwrap_or(0.0);
+            let base_rate = world
+                .finances
+                .get(&id)
+                .map(|finance| finance.policy_rate)
+                .unwrap_or(0.0);
+            let household_capacity = household_deposits(world, id);
             let citizens = population.0;
             let employed = population.1;
-            let unemployment_rate = if citizens > 0.0 {
-                1.0 - (employed / citizens)
+            let labour_force = population.2;
+            let unemployment_rate = if labour_force > 0.0 {
+                (1.0 - (employed / labour_force)).max(0.0)
             } else {
                 0.0
             };
-            let (sales_revenue, food_shortage, energy_shortage, transport_shortfall) =
-                economy_snapshot;
-            let (rnd_allocation, public_investment, updated_transfer) = {
+            let (
+                sales_revenue,
+                food_shortage,
+                energy_shortage,
+                transport_shortfall,
+                income_tax,
+                transfers,
+            ) = economy_snapshot;
+            let (
+                rnd_allocation,
+                public_investment,
+                health_spending,
+                education_spending,
+                updated_transfer,
+                sales_tax,
+                holder_flows,
+                bank_bonds,
+                postings,
+            ) = {
                 let policy = match world.policies.get_mut(&id) {
                     Some(policy) => policy,
                     None => continue,
                 };
                 let gdp = sales_revenue.max(0.0);
-                let tax_revenue = (gdp * policy.tax_rate.max(0.0)).max(0.0);
+                policy.last_income_tax = income_tax.max(0.0);
+                let sales_tax = (gdp * policy.tax_rate.max(0.0)).max(0.0);
+                let tax_revenue = sales_tax + policy.last_income_tax;
                 policy.last_tax_revenue = tax_revenue;
-                let unemployed = (citizens - employed).max(0.0);
-                let transfers = policy.transfer_per_capita.max(0.0) * unemployed * dt;
                 policy.last_transfers = transfers;
                 let discretionary = tax_revenue - transfers;
                 let guaranteed_rnd = citizens * baseline_rnd.max(0.0) * dt;
@@ -85,15 +116,79 @@ impl System for PolicySystem {
                     remaining.max(0.0) * policy.public_investment_fraction.max(0.0);
                 policy.last_public_investment = public_investment;
                 policy.last_rnd_allocation = rnd_allocation;
+                let health_spending = remaining.max(0.0) * policy.health_fraction.max(0.0);
+                policy.last_health_spending = health_spending;
+                let education_spending = tax_revenue.max(0.0) * policy.education_fraction.max(0.0);
+                policy.last_education_spending = education_spending;
+
+                policy.last_bank_support = std::mem::take(&mut policy.pending_bank_support);
+                let spending = transfers
+                    + public_investment
+                    + rnd_allocation
+                    + health_spending
+                    + education_spending
+                    + policy.last_bank_support;
+                policy.debt_to_gdp = fiscal::debt_to_gdp(policy, gdp, dt);
+                policy.bond_yield = fiscal::sovereign_yield(policy, base_rate);
+                let coupons = fiscal::coupons(policy, dt);
+                policy.last_interest = coupons.total();
+                policy.primary_balance = tax_revenue - spending;
+                policy.budget_balance = policy.primary_balance - policy.last_interest;
 
-                let spending = transfers + public_investment + rnd_allocation;
-                policy.budget_balance = tax_revenue - spending;
-                if policy.budget_balance < 0.0 {
-                    policy.public_debt += -policy.budget_balance;
+                let debt_before = policy.public_debt;
+                let cash_before = policy.treasury_cash;
+                let redeemed = fiscal::mature(policy, dt);
+                let flow = policy.budget_balance - redeemed.total();
+                let (issued, bought) = if flow < 0.0 {
+                    let drawn = policy.treasury_cash.max(0.0).min(-flow);
+                    policy.treasury_cash -= drawn;
+                    let capacity = household_capacity + coupons.households + redeemed.households;
+                    (
+                        fiscal::issue(policy, -flow - drawn, capacity),
+                        fiscal::Holders::default(),
+                    )
                 } else {
-                    policy.public_debt =
-                        (policy.public_debt - policy.budget_balance * 0.35).max(0.0);
-                }
+                    let bought =
+                        fiscal::buy_back(policy, flow * policy.surplus_repayment_share.max(0.0));
+                    policy.treasury_cash += flow - bought.total();
+                    (fiscal::Holders::default(), bought)
+                };
+                policy.last_bond_issuance = issued.total();
+                policy.last_bond_redemptions = redeemed.total() + bought.total();
+                policy.last_debt_change = policy.public_debt - debt_before;
+                policy.last_cash_change = policy.treasury_cash - cash_before;
+                let holder_flows = fiscal::Holders {
+                    bank: coupons.bank,
+                    households: coupons.households + redeemed.households + bought.households
+                        - issued.households,
+                };
+                let bank_bonds: f64 = policy.bonds.iter().map(|b| b.bank_holding).sum();
+                let postings = [
+                    (
+                        FlowKind::SalesTax,
+                        Sector::Firms,
+                        Sector::Government,
+                        sales_tax,
+                    ),
+                    (
+                        FlowKind::PublicProcurement,
+                        Sector::Government,
+                        Sector::Firms,
+                        public_investment + rnd_allocation + health_spending + education_spending,
+                    ),
+                    (
+                        FlowKind::BondCoupon,
+                        Sector::Government,
+                        Sector::Banks,
+                        coupons.bank,
+                    ),
+                    (
+                        FlowKind::BondCoupon,
+                        Sector::Government,
+                        Sector::Households,
+                        coupons.households,
+                    ),
+                ];
 
                 let unemployment_gap = unemployment_rate - policy.target_unemployment_rate;
                 if unemployment_gap > 0.01 {
@@ -106,7 +201,7 @@ impl System for PolicySystem {
                     policy.tax_rate *= 1.0 + 0.05 * pressure;
                 }
 
-                let balance_gap = policy.budget_balance - policy.target_primary_balance;
+                let balance_gap = policy.primary_balance - policy.target_primary_balance;
                 if balance_gap < 0.0 {
                     let severity = (-balance_gap / (tax_revenue.abs() + 1.0)).clamp(0.0, 0.1);
                     policy.tax_rate *= 1.0 + severity;
@@ -126,9 +221,29 @@ impl System for PolicySystem {
                 (
                     rnd_allocation,
                     public_investment,
+                    health_spending,
+                    education_spending,
                     policy.transfer_per_capita,
+                    sales_tax,
+                    holder_flows,
+                    bank_bonds,
+                    postings,
                 )
             };
+            for (kind, from, to, amount) in postings {
+                ledger::post(&mut world.ledger, Some(id), kind, from, to, amount);
+            }
+
+            shift_household_deposits(world, id, holder_flows.households);
+            let households = household_deposits(world, id);
+            if let Some(finance) = world.finances.get_mut(&id) {
+                finance.bank_deposits +=
+                    public_investment + rnd_allocation + health_spending + education_spending
+                        - sales_tax;
+                finance.government_bonds = bank_bonds;
+                finance.capital += holder_flows.bank;
+                lending::update_ratios(finance, households);
+            }
 
             if let Some(econ) = world.economies.get_mut(&id) {
                 econ.basic_income_per_capita = updated_transfer;
@@ -140,6 +255,14 @@ impl System for PolicySystem {
                 }
             }
 
+            if let Some(health) = world.health.get_mut(&id) {
+                health.pending_funding += health_spending;
+            }
+
+            if let Some(education) = world.education.get_mut(&id) {
+                education.pending_funding += education_spending;
+            }
+
             if let Some(tech) = world.technology.get_mut(&id) {
                 tech.current_allocation = rnd_allocation.max(0.0);
             }
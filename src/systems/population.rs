@@ -2,9 +2,10 @@ use anyhow::Result;
 use rand::Rng;
 
 use crate::{
+    agents, demography,
     engine::{System, SystemContext},
-    rng::SystemRng,
-    world::{EntityId, World},
+    rng::{self, SystemRng},
+    world::{DemographicEvents, EntityId, World},
 };
 
 pub struct PopulationSystem;
@@ -62,28 +63,94 @@ impl System for PopulationSystem {
                         0.0,
                     )
                 });
+            let skill_match = world
+                .education
+                .get(&id)
+                .map(|education| education.matching_multiplier)
+                .unwrap_or(1.0);
+            let matching_efficiency = matching_efficiency * skill_match;
             let dt_years = ctx.dt_days / 365.0;
-            let births = (population.citizens as f64 * population.annual_birth_rate * dt_years)
-                .round() as i64;
-            let deaths = (population.citizens as f64 * population.annual_death_rate * dt_years)
-                .round() as i64;
-            let mut net_delta = births - deaths;
-
             let starvation_penalty =
                 (population.citizens as f64 * food_shortage_ratio * 0.05).ceil() as i64;
             if starvation_penalty > 0 {
-                net_delta -= starvation_penalty;
                 world.bookkeeping.starving_regions.push(region_name.clone());
             }
 
+            let (next_citizens, labour_force, births, deaths) = match world
+                .demographics
+                .get_mut(&id)
+            {
+                Some(demography) => {
+                    demography::advance(
+                        demography,
+                        dt_years,
+                        population.demographic_events,
+                        &mut *rng,
+                    );
+                    if starvation_penalty > 0 {
+                        demography::remove_proportionally(demography, starvation_penalty as f64);
+                    }
+                    demography::refresh_indicators(demography);
+                    let citizens = demography::total(demography).round().max(0.0) as u64;
+                    (
+                        citizens,
+                        demography.labour_force,
+                        demography.births,
+                        demography.deaths,
+                    )
+                }
+                None => {
+                    let citizens = population.citizens as f64;
+                    let birth_hazard = (population.annual_birth_rate * dt_years).max(0.0);
+                    let death_hazard = (population.annual_death_rate * dt_years).clamp(0.0, 1.0);
+                    let (births, deaths) = match population.demographic_events {
+                        DemographicEvents::Deterministic => {
+                            population.birth_accumulator += citizens * birth_hazard;
+                            population.death_accumulator += citizens * death_hazard;
+                            let births = population.birth_accumulator.floor();
+                            let deaths = population.death_accumulator.floor();
+                            population.birth_accumulator -= births;
+                            population.death_accumulator -= deaths;
+                            (births as i64, deaths as i64)
+                        }
+                        DemographicEvents::Stochastic => (
+                            rng::poisson(&mut *rng, citizens * birth_hazard) as i64,
+                            rng::binomial(&mut *rng, population.citizens, death_hazard) as i64,
+                        ),
+                    };
+                    let net_delta = births - deaths - starvation_penalty.max(0);
+                    let citizens = (population.citizens as i64 + net_delta).max(0) as u64;
+                    (citizens, citizens as f64, births as f64, deaths as f64)
+                }
+            };
+            let (next_citizens, labour_force) = match world.household_agents.get_mut(&id) {
+                Some(households) => {
+                    agents::add_births(households, births);
+                    agents::remove_persons(households, deaths);
+                    agents::remove_starving(households, starvation_penalty.max(0) as f64);
+                    agents::set_participation(
+                        households,
+                        if next_citizens > 0 {
+                            labour_force / next_citizens as f64
+                        } else {
+                            0.0
+                        },
+                    );
+                    (
+                        agents::persons(households).round().max(0.0) as u64,
+                        agents::labour_force(households),
+                    )
+                }
+                None => (next_citizens, labour_force),
+            };
+
             let shock: f64 = rng.gen_range(0.975..1.025);
             let desired_employment = (labor_demand * matching_efficiency * shock).round() as i64;
-            let employed = desired_employment
-                .clamp(0, population.citizens as i64)
-                .max(0) as u64;
+            let employable = (labour_force * skill_match).round().max(0.0) as i64;
+            let employed = desired_employment.clamp(0, employable).max(0) as u64;
 
-            let next_citizens = (population.citizens as i64 + net_delta).max(0) as u64;
             population.citizens = next_citizens;
+            population.labour_force = (labour_force.round().max(0.0) as u64).min(next_citizens);
             population.employed = employed.min(population.citizens);
         }
         Ok(())
@@ -0,0 +1,212 @@
+use anyhow::Result;
+
+use crate::{
+    engine::{System, SystemContext},
+    fx,
+    rng::SystemRng,
+    transport,
+    world::{Consignment, EntityId, Good, World},
+};
+
+const EPS: f64 = 1e-9;
+
+pub struct TradeSystem;
+
+impl TradeSystem {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for TradeSystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl System for TradeSystem {
+    fn name(&self) -> &str {
+        "trade"
+    }
+
+    fn run(
+        &mut self,
+        ctx: &SystemContext,
+        world: &mut World,
+        _rng: &mut SystemRng<'_>,
+    ) -> Result<()> {
+        world.transport.pending.clear();
+        if world.fx.currencies.is_empty() && world.transport.links.is_empty() {
+            return Ok(());
+        }
+        let mut ids: Vec<EntityId> = world.economies.keys().cloned().collect();
+        ids.sort();
+        for economy in world.economies.values_mut() {
+            economy.exports = 0.0;
+            economy.imports = 0.0;
+        }
+        transport::refresh_components(&mut world.transport);
+        for pool in trade_pools(world, &ids) {
+            let freight = landed_freight(world, &pool);
+            for good in [Good::Food, Good::Energy] {
+                trade(world, &pool, &freight, good, ctx.dt_days);
+            }
+        }
+        Ok(())
+    }
+}
+
+fn trade_pools(world: &World, ids: &[EntityId]) -> Vec<Vec<EntityId>> {
+    if world.transport.links.is_empty() {
+        return vec![ids.to_vec()];
+    }
+    let names: Vec<String> = ids
+        .iter()
+        .map(|id| {
+            world
+                .region(*id)
+                .map(|r| r.name.clone())
+                .unwrap_or_default()
+        })
+        .collect();
+    let labels = transport::components(&world.transport, &names);
+    let mut roots = labels.clone();
+    roots.sort();
+    roots.dedup();
+    roots
+        .into_iter()
+        .map(|root| {
+            ids.iter()
+                .zip(&labels)
+                .filter(|(_, label)| **label == root)
+                .map(|(id, _)| *id)
+                .collect::<Vec<_>>()
+        })
+        .filter(|pool| pool.len() > 1)
+        .collect()
+}
+
+fn landed_freight(world: &World, ids: &[EntityId]) -> Vec<f64> {
+    if world.transport.links.is_empty() || ids.len() < 2 {
+        return vec![0.0; ids.len()];
+    }
+    let names: Vec<String> = ids
+        .iter()
+        .map(|id| {
+            world
+                .region(*id)
+                .map(|r| r.name.clone())
+                .unwrap_or_default()
+        })
+        .collect();
+    names
+        .iter()
+        .map(|name| {
+            let costs = transport::route_costs(&world.transport, name);
+            let routes: Vec<f64> = names
+                .iter()
+                .filter(|other| *other != name)
+                .filter_map(|other| costs.get(other).copied())
+                .collect();
+            if routes.is_empty() {
+                0.0
+            } else {
+                routes.iter().sum::<f64>() / routes.len() as f64
+            }
+        })
+        .collect()
+}
+
+fn trade(world: &mut World, ids: &[EntityId], freight: &[f64], good: Good, dt_days: f64) {
+    let openness = world.fx.trade_openness.clamp(0.0, 1.0);
+    let rates: Vec<f64> = ids.iter().map(|id| fx::rate(&world.fx, *id)).collect();
+    let mut demand = vec![0.0; ids.len()];
+    let mut surplus = vec![0.0; ids.len()];
+    let mut shortage = vec![0.0; ids.len()];
+    let mut prices = vec![0.0; ids.len()];
+    for (idx, id) in ids.iter().enumerate() {
+        let (Some(pop), Some(economy), Some(stock)) = (
+            world.populations.get(id),
+            world.economies.get(id),
+            world.resources.get(id),
+        ) else {
+            continue;
+        };
+        let (per_capita, unmet, price) = match good {
+            Good::Food => (
+                pop.food_consumption_per_capita,
+                economy.food_shortage_ratio,
+                economy.food_price,
+            ),
+            Good::Energy => (
+                pop.energy_consumption_per_capita,
+                economy.energy_shortage_ratio,
+                economy.energy_price,
+            ),
+        };
+        demand[idx] = pop.citizens as f64 * per_capita * dt_days;
+        surplus[idx] = (stock.get(good) - demand[idx]).max(0.0);
+        shortage[idx] = demand[idx] * unmet.clamp(0.0, 1.0);
+        prices[idx] = price;
+    }
+    let weight: f64 = demand.iter().sum();
+    if weight <= EPS {
+        return;
+    }
+    let reference = (0..ids.len())
+        .map(|idx| demand[idx] * prices[idx] * rates[idx])
+        .sum::<f64>()
+        / weight;
+    if reference <= EPS {
+        return;
+    }
+    let gap = |landed: f64| ((landed - reference) / reference).clamp(-1.0, 1.0);
+    let offers: Vec<f64> = (0..ids.len())
+        .map(|idx| {
+            let landed = prices[idx] * rates[idx] + freight[idx];
+            surplus[idx] * openness * (-gap(landed)).max(0.0)
+        })
+        .collect();
+    let bids: Vec<f64> = (0..ids.len())
+        .map(|idx| {
+            let landed = prices[idx] * rates[idx] - freight[idx];
+            shortage[idx] + demand[idx] * openness * gap(landed).max(0.0)
+        })
+        .collect();
+    let offered: f64 = offers.iter().sum();
+    let bid: f64 = bids.iter().sum();
+    let volume = offered.min(bid);
+    if volume <= EPS {
+        return;
+    }
+    let value: f64 = (0..ids.len())
+        .map(|idx| offers[idx] / offered * volume * prices[idx] * rates[idx])
+        .sum();
+    let routed = !world.transport.links.is_empty();
+    for (idx, id) in ids.iter().enumerate() {
+        let shipped = offers[idx] / offered * volume;
+        let received = bids[idx] / bid * volume;
+        if routed && shipped > EPS {
+            for (other, to) in ids.iter().enumerate() {
+                let share = shipped * bids[other] / bid;
+                if other != idx && share > EPS {
+                    world.transport.pending.push(Consignment {
+                        from: id.raw(),
+                        to: to.raw(),
+                        good: Some(good),
+                        volume: share,
+                        route: Vec::new(),
+                        unit_cost: 0.0,
+                    });
+                }
+            }
+        }
+        if let Some(stock) = world.resources.get_mut(id) {
+            *stock.get_mut(good) += received - shipped;
+        }
+        if let Some(economy) = world.economies.get_mut(id) {
+            economy.exports += shipped * prices[idx];
+            economy.imports += received / volume * value / rates[idx];
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use std::collections::HashMap;
+
+use anyhow::Result;
+
+use crate::{
+    engine::{System, SystemContext},
+    rng::SystemRng,
+    transport,
+    world::{Consignment, EntityId, World},
+};
+
+const EPS: f64 = 1e-9;
+
+pub struct TransportSystem;
+
+impl TransportSystem {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for TransportSystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl System for TransportSystem {
+    fn name(&self) -> &str {
+        "transport"
+    }
+
+    fn run(
+        &mut self,
+        _ctx: &SystemContext,
+        world: &mut World,
+        _rng: &mut SystemRng<'_>,
+    ) -> Result<()> {
+        if world.transport.links.is_empty() {
+            return Ok(());
+        }
+        let names: HashMap<u64, String> = world
+            .regions
+            .iter()
+            .map(|(id, region)| (id.raw(), region.name.clone()))
+            .collect();
+        let mut shipments = std::mem::take(&mut world.transport.pending);
+        let passenger_weight = world.transport.passenger_weight.max(0.0);
+        for flow in &world.migration.flows {
+            let volume = flow.people * passenger_weight;
+            if volume > EPS {
+                shipments.push(Consignment {
+                    from: flow.from,
+                    to: flow.to,
+                    good: None,
+                    volume,
+                    route: Vec::new(),
+                    unit_cost: 0.0,
+                });
+            }
+        }
+        transport::assign(&mut world.transport, &mut shipments, &names);
+
+        let mut ids: Vec<EntityId> = world.economies.keys().cloned().collect();
+        ids.sort();
+        for economy in world.economies.values_mut() {
+            economy.freight_cost = 0.0;
+        }
+        for shipment in shipments.iter().filter(|s| s.good.is_some()) {
+            let Some(to) = ids.iter().find(|id| id.raw() == shipment.to) else {
+                continue;
+            };
+            if let Some(economy) = world.economies.get_mut(to) {
+                economy.freight_cost += shipment.volume * shipment.unit_cost;
+            }
+        }
+        world.transport.freight_cost = world.economies.values().map(|e| e.freight_cost).sum();
+        world.transport.shipments = shipments;
+        Ok(())
+    }
+}
@@ -7,6 +7,7 @@ pub struct TechDefinition {
     pub difficulty: f64,
     pub food_multiplier: f64,
     pub energy_multiplier: f64,
+    pub storage_efficiency_bonus: f64,
     pub prerequisites: &'static [&'static str],
 }
 
@@ -17,6 +18,7 @@ const TECH_TREE: &[TechDefinition] = &[
         difficulty: 12_000.0,
         food_multiplier: 1.08,
         energy_multiplier: 1.0,
+        storage_efficiency_bonus: 0.0,
         prerequisites: &[],
     },
     TechDefinition {
@@ -25,6 +27,7 @@ const TECH_TREE: &[TechDefinition] = &[
         difficulty: 16_000.0,
         food_multiplier: 1.0,
         energy_multiplier: 1.12,
+        storage_efficiency_bonus: 0.1,
         prerequisites: &["adaptive_farming"],
     },
     TechDefinition {
@@ -33,6 +36,7 @@ const TECH_TREE: &[TechDefinition] = &[
         difficulty: 22_500.0,
         food_multiplier: 1.06,
         energy_multiplier: 1.05,
+        storage_efficiency_bonus: 0.0,
         prerequisites: &["adaptive_farming"],
     },
     TechDefinition {
@@ -41,6 +45,7 @@ const TECH_TREE: &[TechDefinition] = &[
         difficulty: 30_000.0,
         food_multiplier: 1.04,
         energy_multiplier: 1.08,
+        storage_efficiency_bonus: 0.0,
         prerequisites: &["grid_storage", "automation_lines"],
     },
 ];
@@ -70,3 +75,11 @@ pub fn aggregate_productivity_multipliers(unlocked: &[String]) -> (f64, f64) {
     }
     (food, energy)
 }
+
+pub fn storage_efficiency_bonus(unlocked: &[String]) -> f64 {
+    unlocked
+        .iter()
+        .filter_map(|tech| definition(tech))
+        .map(|def| def.storage_efficiency_bonus)
+        .sum()
+}
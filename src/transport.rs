@@ -0,0 +1,203 @@
+use std::collections::HashMap;
+
+use crate::world::{Consignment, TransportLink, TransportNetwork};
+
+const EPS: f64 = 1e-9;
+
+pub fn travel_time(link: &TransportLink, alpha: f64, beta: f64) -> f64 {
+    if link.capacity <= EPS {
+        return f64::INFINITY;
+    }
+    link.free_flow_time * (1.0 + alpha * (link.flow / link.capacity).powf(beta))
+}
+
+pub fn refresh(network: &mut TransportNetwork) {
+    let (alpha, beta, time_value) = (network.bpr_alpha, network.bpr_beta, network.time_value);
+    for link in network.links.iter_mut() {
+        link.travel_time = travel_time(link, alpha, beta);
+        link.utilisation = if link.capacity > EPS {
+            link.flow / link.capacity
+        } else {
+            0.0
+        };
+        link.generalised_cost = link.cost + time_value * link.travel_time;
+    }
+}
+
+fn usable(link: &TransportLink) -> bool {
+    link.generalised_cost.is_finite()
+}
+
+fn nodes(network: &TransportNetwork) -> Vec<&str> {
+    let mut nodes: Vec<&str> = Vec::new();
+    for link in &network.links {
+        for name in [link.from.as_str(), link.to.as_str()] {
+            if !nodes.contains(&name) {
+                nodes.push(name);
+            }
+        }
+    }
+    nodes
+}
+
+type Search = (Vec<f64>, Vec<Option<(usize, usize)>>);
+
+fn search(network: &TransportNetwork, nodes: &[&str], start: usize, goal: Option<usize>) -> Search {
+    let mut cost = vec![f64::INFINITY; nodes.len()];
+    let mut via: Vec<Option<(usize, usize)>> = vec![None; nodes.len()];
+    let mut done = vec![false; nodes.len()];
+    cost[start] = 0.0;
+    while let Some(node) = (0..nodes.len())
+        .filter(|n| !done[*n] && cost[*n].is_finite())
+        .min_by(|a, b| cost[*a].total_cmp(&cost[*b]))
+    {
+        if Some(node) == goal {
+            break;
+        }
+        done[node] = true;
+        for (idx, link) in network.links.iter().enumerate() {
+            if !usable(link) {
+                continue;
+            }
+            let next = if link.from == nodes[node] {
+                link.to.as_str()
+            } else if link.to == nodes[node] {
+                link.from.as_str()
+            } else {
+                continue;
+            };
+            let Some(next) = nodes.iter().position(|n| *n == next) else {
+                continue;
+            };
+            let candidate = cost[node] + link.generalised_cost;
+            if candidate < cost[next] {
+                cost[next] = candidate;
+                via[next] = Some((node, idx));
+            }
+        }
+    }
+    (cost, via)
+}
+
+pub fn shortest_path(
+    network: &TransportNetwork,
+    from: &str,
+    to: &str,
+) -> Option<(Vec<usize>, f64)> {
+    let nodes = nodes(network);
+    let start = nodes.iter().position(|n| *n == from)?;
+    let goal = nodes.iter().position(|n| *n == to)?;
+    let (cost, via) = search(network, &nodes, start, Some(goal));
+    if !cost[goal].is_finite() {
+        return None;
+    }
+    let mut path = Vec::new();
+    let mut node = goal;
+    while let Some((previous, link)) = via[node] {
+        path.push(link);
+        node = previous;
+    }
+    path.reverse();
+    Some((path, cost[goal]))
+}
+
+pub fn route_costs(network: &TransportNetwork, from: &str) -> HashMap<String, f64> {
+    let nodes = nodes(network);
+    let Some(start) = nodes.iter().position(|n| *n == from) else {
+        return HashMap::new();
+    };
+    let (cost, _) = search(network, &nodes, start, None);
+    nodes
+        .iter()
+        .zip(cost)
+        .filter(|(_, cost)| cost.is_finite())
+        .map(|(name, cost)| (name.to_string(), cost))
+        .collect()
+}
+
+fn root(parent: &mut [usize], mut node: usize) -> usize {
+    while parent[node] != node {
+        parent[node] = parent[parent[node]];
+        node = parent[node];
+    }
+    node
+}
+
+pub fn refresh_components(network: &mut TransportNetwork) {
+    let topology: Vec<bool> = network.links.iter().map(usable).collect();
+    if topology == network.topology && !network.components.is_empty() {
+        return;
+    }
+    let nodes = nodes(network);
+    let mut parent: Vec<usize> = (0..nodes.len()).collect();
+    for link in network.links.iter().filter(|l| usable(l)) {
+        let a = nodes.iter().position(|n| *n == link.from);
+        let b = nodes.iter().position(|n| *n == link.to);
+        if let (Some(a), Some(b)) = (a, b) {
+            let (a, b) = (root(&mut parent, a), root(&mut parent, b));
+            parent[a.max(b)] = a.min(b);
+        }
+    }
+    let components = (0..nodes.len())
+        .map(|node| (nodes[node].to_string(), root(&mut parent, node)))
+        .collect();
+    network.components = components;
+    network.topology = topology;
+}
+
+pub fn components(network: &TransportNetwork, names: &[String]) -> Vec<usize> {
+    let mut labels: Vec<usize> = (0..names.len()).collect();
+    for (idx, name) in names.iter().enumerate() {
+        let Some(component) = network.components.get(name) else {
+            continue;
+        };
+        if let Some(first) = names[..idx]
+            .iter()
+            .position(|other| network.components.get(other) == Some(component))
+        {
+            labels[idx] = first;
+        }
+    }
+    labels
+}
+
+pub fn assign(
+    network: &mut TransportNetwork,
+    shipments: &mut [Consignment],
+    names: &HashMap<u64, String>,
+) {
+    for link in network.links.iter_mut() {
+        link.flow = 0.0;
+    }
+    refresh(network);
+    let steps = network.assignment_steps.max(1);
+    let mut routable = vec![true; shipments.len()];
+    network.unrouted = 0.0;
+    for shipment in shipments.iter_mut() {
+        shipment.route.clear();
+        shipment.unit_cost = 0.0;
+    }
+    for _ in 0..steps {
+        for (idx, shipment) in shipments.iter_mut().enumerate() {
+            if !routable[idx] || shipment.volume <= EPS {
+                continue;
+            }
+            let path = match (names.get(&shipment.from), names.get(&shipment.to)) {
+                (Some(from), Some(to)) if from != to => shortest_path(network, from, to),
+                _ => None,
+            };
+            let Some((path, cost)) = path else {
+                routable[idx] = false;
+                network.unrouted += shipment.volume;
+                continue;
+            };
+            let part = shipment.volume / steps as f64;
+            for link in &path {
+                network.links[*link].flow += part;
+            }
+            shipment.unit_cost += cost / steps as f64;
+            shipment.route = path.iter().map(|l| network.links[*l].id).collect();
+        }
+        refresh(network);
+    }
+}
@@ -0,0 +1,116 @@
+use std::f64::consts::TAU;
+
+use rand::Rng;
+
+use crate::world::{PlantTechnology, WeatherComponent};
+
+pub fn cycle(day_of_year: f64, peak_day: f64) -> f64 {
+    (TAU * (day_of_year - peak_day) / 365.0).cos()
+}
+
+pub fn season(weather: &WeatherComponent) -> f64 {
+    cycle(weather.day_of_year, weather.peak_solar_day)
+}
+
+pub fn advance<R: Rng + ?Sized>(
+    weather: &mut WeatherComponent,
+    rng: &mut R,
+    day_of_year: f64,
+    dt: f64,
+) {
+    weather.day_of_year = day_of_year;
+    let season = season(weather);
+    let cloud: f64 = rng.gen_range(0.0..1.0) * weather.cloud_volatility.clamp(0.0, 1.0);
+    weather.solar = (weather.solar_mean * (1.0 + weather.solar_amplitude * season) * (1.0 - cloud))
+        .clamp(0.0, 1.0);
+
+    let persistence = weather.wind_persistence.clamp(0.0, 1.0).powf(dt.max(0.0));
+    let shock: f64 = rng.gen_range(-1.0..1.0) * 3f64.sqrt();
+    weather.wind_anomaly = persistence * weather.wind_anomaly
+        + weather.wind_volatility.max(0.0) * (1.0 - persistence * persistence).sqrt() * shock;
+    weather.wind = (weather.wind_mean
+        * (1.0 - weather.wind_amplitude * season)
+        * (1.0 + weather.wind_anomaly))
+        .clamp(0.0, 1.0);
+
+    let noise: f64 = rng.gen_range(-1.0..1.0) * 3f64.sqrt();
+    weather.temperature = weather.temperature_mean
+        + weather.temperature_amplitude * cycle(day_of_year, weather.warmest_day)
+        + weather.temperature_noise.max(0.0) * noise;
+    let noise: f64 = rng.gen_range(-1.0..1.0) * 3f64.sqrt();
+    weather.precipitation = (weather.precipitation_mean
+        * (1.0 + weather.precipitation_amplitude * cycle(day_of_year, weather.wettest_day))
+        * (1.0 + weather.precipitation_noise.max(0.0) * noise))
+        .max(0.0);
+    weather.crop_yield = crop_yield(weather);
+    weather.heating_demand = heating_demand(weather);
+}
+
+const CLIMATE_SAMPLES: usize = 8;
+
+/// Annual mean of the raw crop yield and heating demand, so both factors
+/// average 1.0 and the climate shapes the year without moving its level.
+pub fn climatology(weather: &WeatherComponent) -> (f64, f64) {
+    let mut conditions = weather.clone();
+    let offsets: Vec<f64> = (0..CLIMATE_SAMPLES)
+        .map(|k| ((k as f64 + 0.5) / CLIMATE_SAMPLES as f64 * 2.0 - 1.0) * 3f64.sqrt())
+        .collect();
+    let (mut crop, mut heating, mut samples) = (0.0, 0.0, 0.0);
+    for day in 0..365 {
+        let day = day as f64;
+        let temperature = weather.temperature_mean
+            + weather.temperature_amplitude * cycle(day, weather.warmest_day);
+        let precipitation = weather.precipitation_mean
+            * (1.0 + weather.precipitation_amplitude * cycle(day, weather.wettest_day));
+        for t in &offsets {
+            conditions.temperature = temperature + weather.temperature_noise.max(0.0) * t;
+            heating += raw_heating_demand(&conditions);
+            for p in &offsets {
+                conditions.precipitation =
+                    (precipitation * (1.0 + weather.precipitation_noise.max(0.0) * p)).max(0.0);
+                crop += raw_crop_yield(&conditions);
+            }
+            samples += 1.0;
+        }
+    }
+    let crop = crop / (samples * CLIMATE_SAMPLES as f64);
+    let heating = heating / samples;
+    (
+        if crop > 1e-9 { crop } else { 1.0 },
+        if heating > 1e-9 { heating } else { 1.0 },
+    )
+}
+
+pub fn crop_yield(weather: &WeatherComponent) -> f64 {
+    raw_crop_yield(weather) / weather.yield_baseline.max(1e-9)
+}
+
+pub fn heating_demand(weather: &WeatherComponent) -> f64 {
+    raw_heating_demand(weather) / weather.heating_baseline.max(1e-9)
+}
+
+fn raw_crop_yield(weather: &WeatherComponent) -> f64 {
+    let tolerance = weather.temperature_tolerance.max(1e-6);
+    let thermal =
+        (1.0 - ((weather.temperature - weather.optimal_temperature) / tolerance).powi(2)).max(0.0);
+    let moisture = if weather.drought_threshold > 0.0 {
+        (weather.precipitation / weather.drought_threshold).min(1.0)
+    } else {
+        1.0
+    };
+    let sensitivity = weather.yield_sensitivity.clamp(0.0, 1.0);
+    1.0 - sensitivity * (1.0 - thermal * moisture)
+}
+
+fn raw_heating_demand(weather: &WeatherComponent) -> f64 {
+    let degrees = (weather.heating_threshold - weather.temperature).max(0.0);
+    1.0 + weather.heating_sensitivity.max(0.0) * degrees
+}
+
+pub fn capacity_factor(technology: PlantTechnology, weather: &WeatherComponent) -> f64 {
+    match technology {
+        PlantTechnology::Wind => weather.wind,
+        PlantTechnology::Solar => weather.solar,
+        _ => 1.0,
+    }
+}
@@ -254,9 +254,11 @@ function updateRegionCards(regions) {
     )} citizens</span>`;
     const chip = document.createElement('div');
     chip.className = 'region-chip';
-    const alerting = region.food_shortage_ratio > 0.12 || region.energy_shortage_ratio > 0.12;
+    const blackout = region.infrastructure_outages > 0;
+    const alerting =
+      blackout || region.food_shortage_ratio > 0.12 || region.energy_shortage_ratio > 0.12;
     chip.dataset.state = alerting ? 'alert' : 'stable';
-    chip.textContent = alerting ? 'Shortage' : 'Stable';
+    chip.textContent = blackout ? 'Outage' : alerting ? 'Shortage' : 'Stable';
     header.append(title, chip);
 
     const stats = document.createElement('div');
@@ -291,6 +293,9 @@ function processLogs(snapshot) {
   if (snapshot.starving_regions.length) {
     logs.push({ type: 'alert', text: `Food stress: ${snapshot.starving_regions.join(', ')}` });
   }
+  (snapshot.events || [])
+    .filter((event) => event.kind === 'infrastructure_outage' && event.tick >= state.logTick)
+    .forEach((event) => logs.push({ type: 'alert', text: event.message }));
   const stressed = [...snapshot.regions].sort((a, b) => b.credit_stress - a.credit_stress)[0];
   if (stressed && stressed.credit_stress > 0.25) {
     logs.push({ type: 'alert', text: `${stressed.name} bank stress ${formatPercent(stressed.credit_stress)}` });
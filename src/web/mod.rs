@@ -33,8 +33,10 @@ use crate::{
     engine::{EngineBuilder, EngineSettings},
     scenario::Scenario,
     systems::{
-        BookkeepingSystem, EconomySystem, EnvironmentSystem, FinanceSystem, InfrastructureSystem,
-        PolicySystem, PopulationSystem, TechnologySystem,
+        BookkeepingSystem, CentralBankSystem, CurrencySystem, EconomySystem, EducationSystem,
+        EnvironmentSystem, FinanceSystem, HealthSystem, InfrastructureSystem, InterbankSystem,
+        MigrationSystem, PolicySystem, PopulationSystem, TechnologySystem, TradeSystem,
+        TransportSystem,
     },
     world::WorldSnapshot,
 };
@@ -94,9 +96,17 @@ pub async fn run(config: WebServerConfig) -> Result<()> {
     let mut engine = EngineBuilder::new(settings)
         .with_system(EnvironmentSystem::new())
         .with_system(InfrastructureSystem::new())
+        .with_system(EducationSystem::new())
         .with_system(PopulationSystem::new())
+        .with_system(MigrationSystem::new())
+        .with_system(HealthSystem::new())
         .with_system(EconomySystem::new())
+        .with_system(CentralBankSystem::new())
+        .with_system(TradeSystem::new())
+        .with_system(CurrencySystem::new())
+        .with_system(TransportSystem::new())
         .with_system(FinanceSystem::new())
+        .with_system(InterbankSystem::new())
         .with_system(PolicySystem::new())
         .with_system(TechnologySystem::new())
         .with_system(BookkeepingSystem::new())
@@ -111,7 +121,6 @@ pub async fn run(config: WebServerConfig) -> Result<()> {
     let frames_for_sim = frames.clone();
     let done_for_sim = simulation_done.clone();
     let tx_for_sim = tx.clone();
-    let ticks = ticks;
     let scenario_label = scenario_name.clone();
 
     let sim_handle = tokio::task::spawn_blocking(move || -> Result<()> {
@@ -1,7 +1,10 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 
+use chrono::{Datelike, Days, NaiveDate};
 use serde::{Deserialize, Serialize};
 
+pub const EVENT_CAPACITY: usize = 4096;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub struct EntityId(u64);
 
@@ -18,15 +21,55 @@ pub struct RegionComponent {
     pub energy_regen_per_1000: f64,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DemographicEvents {
+    #[default]
+    Deterministic,
+    Stochastic,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PopulationComponent {
     pub citizens: u64,
     pub employed: u64,
+    pub labour_force: u64,
     pub annual_birth_rate: f64,
     pub annual_death_rate: f64,
     pub food_consumption_per_capita: f64,
     pub energy_consumption_per_capita: f64,
     pub target_employment_rate: f64,
+    pub demographic_events: DemographicEvents,
+    pub birth_accumulator: f64,
+    pub death_accumulator: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AgeCohort {
+    pub min_age: u32,
+    pub max_age: Option<u32>,
+    pub population: f64,
+    pub fertility_rate: f64,
+    pub mortality_rate: f64,
+    pub participation_rate: f64,
+}
+
+impl AgeCohort {
+    pub fn width_years(&self) -> Option<f64> {
+        self.max_age
+            .map(|max| (max.saturating_sub(self.min_age) + 1) as f64)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DemographyComponent {
+    pub cohorts: Vec<AgeCohort>,
+    pub working_age_min: u32,
+    pub working_age_max: u32,
+    pub labour_force: f64,
+    pub dependency_ratio: f64,
+    pub births: f64,
+    pub deaths: f64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -47,13 +90,159 @@ pub struct EconomyComponent {
     pub food_shortage_ratio: f64,
     pub energy_shortage_ratio: f64,
     pub wage_bill: f64,
+    pub transfers: f64,
+    pub income_tax: f64,
     pub sales_revenue: f64,
+    pub exports: f64,
+    pub imports: f64,
+    pub freight_cost: f64,
     pub energy_dispatched: f64,
     pub energy_curtailed: f64,
     pub transport_utilization: f64,
     pub transport_shortfall: f64,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CapitalComponent {
+    pub stock: f64,
+    pub reference_per_worker: f64,
+    pub depreciation_rate: f64,
+    pub capital_share: f64,
+    pub substitution_elasticity: f64,
+    pub adjustment_speed: f64,
+    pub desired_stock: f64,
+    pub investment: f64,
+    pub depreciation: f64,
+    pub output_multiplier: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EducationComponent {
+    pub attainment: f64,
+    pub graduate_attainment: f64,
+    pub reference_attainment: f64,
+    pub min_attainment: f64,
+    pub max_attainment: f64,
+    pub reference_spending_per_capita: f64,
+    pub funding_elasticity: f64,
+    pub schooling_years: f64,
+    pub workforce_turnover_years: f64,
+    pub productivity_elasticity: f64,
+    pub mismatch_sensitivity: f64,
+    pub skill_demand_growth: f64,
+    pub pending_funding: f64,
+    pub spending_per_capita: f64,
+    pub skill_index: f64,
+    pub required_skill: f64,
+    pub skill_mismatch: f64,
+    pub productivity_multiplier: f64,
+    pub matching_multiplier: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HouseholdComponent {
+    pub deposits: f64,
+    pub debt: f64,
+    pub permanent_income: f64,
+    pub disposable_income: f64,
+    pub consumption: f64,
+    pub saving: f64,
+    pub target_buffer_days: f64,
+    pub wealth_drawdown_rate: f64,
+    pub income_smoothing: f64,
+    pub borrowing_limit_days: f64,
+}
+
+impl HouseholdComponent {
+    pub fn net_wealth(&self) -> f64 {
+        self.deposits - self.debt
+    }
+
+    pub fn saving_rate(&self) -> f64 {
+        if self.disposable_income > 0.0 {
+            self.saving / self.disposable_income
+        } else {
+            0.0
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PopulationMode {
+    #[default]
+    Aggregate,
+    Agents,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HouseholdAgent {
+    pub weight: f64,
+    pub size: f64,
+    pub earners: f64,
+    pub productivity: f64,
+    pub employed: bool,
+    pub gross_income: f64,
+    pub income_tax: f64,
+    pub income: f64,
+    pub permanent_income: f64,
+    pub deposits: f64,
+    pub debt: f64,
+    pub planned_consumption: f64,
+    pub consumption: f64,
+}
+
+impl HouseholdAgent {
+    pub fn persons(&self) -> f64 {
+        self.weight * self.size
+    }
+
+    pub fn workers(&self) -> f64 {
+        self.weight * self.earners
+    }
+
+    pub fn net_wealth(&self) -> f64 {
+        self.deposits - self.debt
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct HouseholdAgentsComponent {
+    pub agents: Vec<HouseholdAgent>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IncomeGroup {
+    pub name: String,
+    pub population_share: f64,
+    pub wage_multiplier: f64,
+    pub employment_weight: f64,
+    pub propensity_multiplier: f64,
+    pub transfer_weight: f64,
+    pub members: f64,
+    pub employed: f64,
+    pub gross_income: f64,
+    pub income_tax: f64,
+    pub transfers: f64,
+    pub disposable_income: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DistributionComponent {
+    pub groups: Vec<IncomeGroup>,
+    pub poverty_line_ratio: f64,
+    pub income_tax: f64,
+    pub gini: f64,
+    pub palma: f64,
+    pub poverty_rate: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaxBracket {
+    pub threshold: f64,
+    pub rate: f64,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ResearchProject {
     pub tech_id: String,
@@ -76,27 +265,176 @@ pub struct TechnologyComponent {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PolicyComponent {
     pub tax_rate: f64,
+    pub tax_brackets: Vec<TaxBracket>,
     pub transfer_per_capita: f64,
     pub public_investment_fraction: f64,
     pub rnd_fraction: f64,
     pub target_unemployment_rate: f64,
     pub target_primary_balance: f64,
+    pub immigration_cap: Option<f64>,
+    pub emigration_cap: Option<f64>,
+    pub health_fraction: f64,
+    pub education_fraction: f64,
     pub budget_balance: f64,
     pub public_debt: f64,
     pub approval_rating: f64,
     pub last_tax_revenue: f64,
+    pub last_income_tax: f64,
     pub last_transfers: f64,
     pub last_public_investment: f64,
     pub last_rnd_allocation: f64,
+    pub last_health_spending: f64,
+    pub last_education_spending: f64,
+    pub bond_maturity_days: f64,
+    pub term_premium: f64,
+    pub debt_threshold: f64,
+    pub debt_sensitivity: f64,
+    pub approval_sensitivity: f64,
+    pub household_bond_share: f64,
+    pub surplus_repayment_share: f64,
+    pub bonds: Vec<Bond>,
+    pub next_bond_id: u64,
+    pub bond_yield: f64,
+    pub debt_to_gdp: f64,
+    pub treasury_cash: f64,
+    pub pending_bank_support: f64,
+    pub primary_balance: f64,
+    pub last_interest: f64,
+    pub last_bank_support: f64,
+    pub last_bond_issuance: f64,
+    pub last_bond_redemptions: f64,
+    pub last_debt_change: f64,
+    pub last_cash_change: f64,
+    pub infrastructure_priority: Option<AssetKind>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Bond {
+    pub id: u64,
+    pub coupon_rate: f64,
+    pub maturity_days: f64,
+    pub age_days: f64,
+    pub bank_holding: f64,
+    pub household_holding: f64,
+}
+
+impl Bond {
+    pub fn outstanding(&self) -> f64 {
+        self.bank_holding + self.household_holding
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Good {
+    Food,
+    Energy,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InputCoefficient {
+    pub sector: Good,
+    pub input: Good,
+    pub per_unit: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SupplyChainSector {
+    pub name: String,
+    pub good: Option<Good>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MarketModel {
+    #[default]
+    PostedPrice,
+    OrderBook,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MarketOutcome {
+    pub good: Good,
+    pub model: MarketModel,
+    pub clearing_price: f64,
+    pub volume: f64,
+    pub unfilled_demand: f64,
+    pub unfilled_supply: f64,
+}
+
+impl MarketOutcome {
+    pub fn new(good: Good, model: MarketModel) -> Self {
+        Self {
+            good,
+            model,
+            clearing_price: 0.0,
+            volume: 0.0,
+            unfilled_demand: 0.0,
+            unfilled_supply: 0.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MarketComponent {
+    pub auction_levels: usize,
+    pub auction_spread: f64,
+    pub food: MarketOutcome,
+    pub energy: MarketOutcome,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Shipment {
+    pub due_in_ticks: u32,
+    pub goods: ResourceStock,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SupplyChainComponent {
+    pub sectors: Vec<SupplyChainSector>,
+    pub matrix: Vec<Vec<f64>>,
+    pub coefficients: Vec<InputCoefficient>,
+    pub sector_output: Vec<f64>,
+    pub latency_ticks: u32,
+    pub in_transit: VecDeque<Shipment>,
+    pub input_buffer: ResourceStock,
+    pub intermediate_food: f64,
+    pub intermediate_energy: f64,
+    pub food_throttle: f64,
+    pub energy_throttle: f64,
+}
+
+impl SupplyChainComponent {
+    pub fn coefficient(&self, sector: Good, input: Good) -> f64 {
+        self.coefficients
+            .iter()
+            .filter(|c| c.sector == sector && c.input == input)
+            .map(|c| c.per_unit.max(0.0))
+            .sum()
+    }
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct ResourceStock {
     pub food: f64,
     pub energy: f64,
 }
 
 impl ResourceStock {
+    pub fn get(&self, good: Good) -> f64 {
+        match good {
+            Good::Food => self.food,
+            Good::Energy => self.energy,
+        }
+    }
+
+    pub fn get_mut(&mut self, good: Good) -> &mut f64 {
+        match good {
+            Good::Food => &mut self.food,
+            Good::Energy => &mut self.energy,
+        }
+    }
+
     pub fn clamp_non_negative(&mut self) {
         self.food = self.food.max(0.0);
         self.energy = self.energy.max(0.0);
@@ -115,6 +453,161 @@ pub struct FinanceComponent {
     pub infrastructure_spend_fraction: f64,
     pub credit_stress: f64,
     pub cumulative_defaults: f64,
+    pub firm_loan_terms: LoanTerms,
+    pub infrastructure_loan_terms: LoanTerms,
+    pub cohort_days: f64,
+    pub arrears_default_multiplier: f64,
+    pub loans: Vec<Loan>,
+    pub next_loan_id: u64,
+    pub flows_tick: Option<u64>,
+    pub new_lending: f64,
+    pub repayments: f64,
+    pub interest_received: f64,
+    pub defaults: f64,
+    pub arrears: f64,
+    pub capital: f64,
+    pub min_capital_ratio: f64,
+    pub min_liquidity_ratio: f64,
+    pub firm_risk_weight: f64,
+    pub infrastructure_risk_weight: f64,
+    pub recapitalisation_ratio: f64,
+    pub resolution: Resolution,
+    pub capital_ratio: f64,
+    pub liquidity_ratio: f64,
+    pub credit_rationing: f64,
+    pub insolvencies: u32,
+    pub resolution_cost: f64,
+    pub last_insolvency_tick: Option<u64>,
+    pub interbank_lending: f64,
+    pub interbank_borrowing: f64,
+    pub interbank_proceeds: f64,
+    pub interbank_rate: f64,
+    pub interbank_losses: f64,
+    pub government_bonds: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InterbankLoan {
+    pub lender: u64,
+    pub borrower: u64,
+    pub amount: f64,
+    pub rate: f64,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct InterbankMarket {
+    pub enabled: bool,
+    pub spread: f64,
+    pub stress_premium: f64,
+    pub limit_share: f64,
+    pub stress_sensitivity: f64,
+    pub recovery_rate: f64,
+    pub loans: Vec<InterbankLoan>,
+    pub volume: f64,
+    pub average_rate: f64,
+    pub losses: f64,
+    pub contagion_failures: u32,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExchangeRegime {
+    #[default]
+    Floating,
+    Pegged,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct FxObservation {
+    pub tick: u64,
+    pub rate: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Currency {
+    pub code: String,
+    pub members: Vec<EntityId>,
+    pub regime: ExchangeRegime,
+    pub anchor: Option<String>,
+    pub peg: f64,
+    pub rate: f64,
+    pub policy_rate: f64,
+    pub output: f64,
+    pub exports: f64,
+    pub imports: f64,
+    pub trade_balance: f64,
+    pub interest_differential: f64,
+    pub reserves: f64,
+    pub history: Vec<FxObservation>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct FxMarket {
+    pub trade_sensitivity: f64,
+    pub interest_sensitivity: f64,
+    pub trade_openness: f64,
+    pub max_daily_change: f64,
+    pub history_ticks: usize,
+    pub currencies: Vec<Currency>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Resolution {
+    #[default]
+    BailOut,
+    BailIn,
+    Merger,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BorrowerType {
+    Firm,
+    Infrastructure,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Amortisation {
+    #[default]
+    Annuity,
+    Linear,
+    Bullet,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct LoanTerms {
+    pub maturity_days: f64,
+    pub amortisation: Amortisation,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Loan {
+    pub id: u64,
+    pub borrower: BorrowerType,
+    pub amortisation: Amortisation,
+    pub principal: f64,
+    pub outstanding: f64,
+    pub rate: f64,
+    pub maturity_days: f64,
+    pub age_days: f64,
+    pub cohort: u64,
+    pub arrears: f64,
+}
+
+impl Loan {
+    pub fn remaining_days(&self) -> f64 {
+        (self.maturity_days - self.age_days).max(0.0)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LoanAgeBucket {
+    pub min_years: u32,
+    pub max_years: Option<u32>,
+    pub loans: usize,
+    pub outstanding: f64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -125,6 +618,503 @@ pub struct InfrastructureComponent {
     pub degradation_rate: f64,
     pub reliability: f64,
     pub pending_investment: f64,
+    pub plants: Vec<PowerPlant>,
+    pub nameplate_capacity: f64,
+    pub scarcity_premium: f64,
+    pub price_setter: Option<u64>,
+    pub marginal_price: f64,
+    pub emissions: f64,
+    pub cumulative_emissions: f64,
+    pub ageing: f64,
+    pub assets: Vec<InfrastructureAsset>,
+    pub projects: Vec<InfrastructureProject>,
+    pub catalogue: Vec<ProjectTemplate>,
+    pub next_asset_id: u64,
+    pub outage_rate: f64,
+    pub outage_days: f64,
+    pub maintenance_funding: f64,
+    pub forced_out_capacity: f64,
+    pub outage_count: u64,
+    pub storage: Vec<StorageUnit>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StorageUnit {
+    pub id: u64,
+    pub name: String,
+    pub capacity: f64,
+    pub charge_rate: f64,
+    pub discharge_rate: f64,
+    pub efficiency: f64,
+    pub level: f64,
+    pub charged: f64,
+    pub discharged: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WeatherComponent {
+    pub day_of_year: f64,
+    pub solar_mean: f64,
+    pub solar_amplitude: f64,
+    pub peak_solar_day: f64,
+    pub cloud_volatility: f64,
+    pub wind_mean: f64,
+    pub wind_amplitude: f64,
+    pub wind_volatility: f64,
+    pub wind_persistence: f64,
+    pub wind_anomaly: f64,
+    pub solar: f64,
+    pub wind: f64,
+    pub temperature_mean: f64,
+    pub temperature_amplitude: f64,
+    pub warmest_day: f64,
+    pub temperature_noise: f64,
+    pub precipitation_mean: f64,
+    pub precipitation_amplitude: f64,
+    pub wettest_day: f64,
+    pub precipitation_noise: f64,
+    pub optimal_temperature: f64,
+    pub temperature_tolerance: f64,
+    pub drought_threshold: f64,
+    pub yield_sensitivity: f64,
+    pub heating_threshold: f64,
+    pub heating_sensitivity: f64,
+    pub temperature: f64,
+    pub precipitation: f64,
+    pub crop_yield: f64,
+    pub heating_demand: f64,
+    pub yield_baseline: f64,
+    pub heating_baseline: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DepositKind {
+    Soil,
+    Fish,
+    Fuel,
+    Water,
+}
+
+impl DepositKind {
+    pub fn good(self) -> Good {
+        match self {
+            DepositKind::Fuel => Good::Energy,
+            DepositKind::Soil | DepositKind::Fish | DepositKind::Water => Good::Food,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResourceDeposit {
+    pub name: String,
+    pub kind: DepositKind,
+    pub initial: f64,
+    pub remaining: f64,
+    pub carrying_capacity: f64,
+    pub regen_rate: f64,
+    pub quality_elasticity: f64,
+    pub quality: f64,
+    pub extracted: f64,
+    pub last_extraction: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AssetKind {
+    Plant,
+    Road,
+    Port,
+    Warehouse,
+}
+
+impl AssetKind {
+    pub fn is_power(self) -> bool {
+        self == AssetKind::Plant
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InfrastructureAsset {
+    pub id: u64,
+    pub name: String,
+    pub kind: AssetKind,
+    pub capacity: f64,
+    pub cost: f64,
+    pub condition: f64,
+    pub built_tick: u64,
+    pub age_days: f64,
+    pub base_maintenance: f64,
+    pub maintenance_cost: f64,
+    pub plant: Option<u64>,
+    pub outage_days: f64,
+}
+
+impl InfrastructureAsset {
+    pub fn in_service(&self) -> bool {
+        self.outage_days <= 0.0
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectTemplate {
+    pub name: String,
+    pub kind: AssetKind,
+    pub capacity: f64,
+    pub cost: f64,
+    pub build_days: f64,
+    pub maintenance_cost: f64,
+    pub technology: Option<PlantTechnology>,
+    pub marginal_cost: f64,
+    pub fuel_per_unit: f64,
+    pub emissions_per_unit: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InfrastructureProject {
+    pub id: u64,
+    pub template: ProjectTemplate,
+    pub started_tick: u64,
+    pub funded: f64,
+    pub elapsed_days: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PlantTechnology {
+    Coal,
+    Gas,
+    Oil,
+    Nuclear,
+    Hydro,
+    Wind,
+    Solar,
+    Biomass,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PowerPlant {
+    pub id: u64,
+    pub name: String,
+    pub technology: PlantTechnology,
+    pub capacity: f64,
+    pub marginal_cost: f64,
+    pub fuel_per_unit: f64,
+    pub emissions_per_unit: f64,
+    pub online: bool,
+    pub capacity_factor: f64,
+    pub available: f64,
+    pub output: f64,
+    pub utilisation: f64,
+    pub fuel_used: f64,
+    pub emissions: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransportLink {
+    pub id: u64,
+    pub from: String,
+    pub to: String,
+    pub capacity: f64,
+    pub length: f64,
+    pub cost: f64,
+    pub free_flow_time: f64,
+    pub flow: f64,
+    pub utilisation: f64,
+    pub travel_time: f64,
+    pub generalised_cost: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Consignment {
+    pub from: u64,
+    pub to: u64,
+    pub good: Option<Good>,
+    pub volume: f64,
+    pub route: Vec<u64>,
+    pub unit_cost: f64,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct TransportNetwork {
+    pub bpr_alpha: f64,
+    pub bpr_beta: f64,
+    pub time_value: f64,
+    pub assignment_steps: u32,
+    pub passenger_weight: f64,
+    pub links: Vec<TransportLink>,
+    pub pending: Vec<Consignment>,
+    pub shipments: Vec<Consignment>,
+    pub unrouted: f64,
+    pub freight_cost: f64,
+    pub components: HashMap<String, usize>,
+    pub topology: Vec<bool>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RegionDistance {
+    pub from: EntityId,
+    pub to: EntityId,
+    pub distance: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MigrationFlow {
+    pub from: u64,
+    pub to: u64,
+    pub people: f64,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct MigrationState {
+    pub annual_base_rate: f64,
+    pub wage_weight: f64,
+    pub unemployment_weight: f64,
+    pub shortage_weight: f64,
+    pub distance_decay: f64,
+    pub default_distance: f64,
+    pub distances: Vec<RegionDistance>,
+    pub flows: Vec<MigrationFlow>,
+}
+
+impl MigrationState {
+    pub fn distance(&self, from: EntityId, to: EntityId) -> f64 {
+        self.distances
+            .iter()
+            .find(|d| (d.from == from && d.to == to) || (d.from == to && d.to == from))
+            .map(|d| d.distance)
+            .unwrap_or(self.default_distance)
+    }
+
+    pub fn immigrants(&self, id: EntityId) -> f64 {
+        self.flows
+            .iter()
+            .filter(|f| f.to == id.raw())
+            .map(|f| f.people)
+            .sum()
+    }
+
+    pub fn emigrants(&self, id: EntityId) -> f64 {
+        self.flows
+            .iter()
+            .filter(|f| f.from == id.raw())
+            .map(|f| f.people)
+            .sum()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Disease {
+    pub name: String,
+    pub transmission_rate: f64,
+    pub incubation_days: f64,
+    pub infectious_days: f64,
+    pub fatality_rate: f64,
+    pub hospitalisation_rate: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InterventionKind {
+    Npi,
+    Vaccination,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Intervention {
+    pub kind: InterventionKind,
+    pub region: Option<EntityId>,
+    pub disease: Option<String>,
+    pub start_tick: u64,
+    pub duration_ticks: Option<u64>,
+    pub strength: f64,
+}
+
+impl Intervention {
+    pub fn is_active(&self, tick: u64, region: EntityId) -> bool {
+        let started = tick >= self.start_tick;
+        let running = self
+            .duration_ticks
+            .is_none_or(|duration| tick < self.start_tick + duration);
+        started && running && self.region.is_none_or(|r| r == region)
+    }
+
+    pub fn applies_to(&self, disease: &str) -> bool {
+        self.disease.as_deref().is_none_or(|d| d == disease)
+    }
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct HealthState {
+    pub diseases: Vec<Disease>,
+    pub interventions: Vec<Intervention>,
+    pub import_weight: f64,
+    pub work_absence: f64,
+    pub overload_fatality_multiplier: f64,
+    pub bed_cost_per_day: f64,
+    pub bed_adjustment_rate: f64,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Epidemic {
+    pub disease: String,
+    pub susceptible: f64,
+    pub exposed: f64,
+    pub infectious: f64,
+    pub recovered: f64,
+    pub new_infections: f64,
+    pub deaths: f64,
+    pub cumulative_deaths: f64,
+    pub peak_infectious: f64,
+    pub vaccinated: f64,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct HealthComponent {
+    pub epidemics: Vec<Epidemic>,
+    pub baseline_beds: f64,
+    pub funded_beds: f64,
+    pub pending_funding: f64,
+    pub patients: f64,
+    pub hospital_load: f64,
+    pub overload_days: f64,
+    pub npi_strength: f64,
+    pub sick_workers: f64,
+    pub deaths: f64,
+}
+
+impl HealthComponent {
+    pub fn hospital_beds(&self) -> f64 {
+        self.baseline_beds + self.funded_beds
+    }
+
+    pub fn infectious(&self) -> f64 {
+        self.epidemics.iter().map(|e| e.infectious).sum()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Sector {
+    Households,
+    Firms,
+    Banks,
+    Government,
+    RestOfWorld,
+}
+
+impl Sector {
+    pub const ALL: [Sector; 5] = [
+        Sector::Households,
+        Sector::Firms,
+        Sector::Banks,
+        Sector::Government,
+        Sector::RestOfWorld,
+    ];
+
+    pub fn index(self) -> usize {
+        self as usize
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FlowKind {
+    Wages,
+    Consumption,
+    IncomeTax,
+    SalesTax,
+    Transfers,
+    PublicProcurement,
+    LoanInterest,
+    LoanDefault,
+    DepositInterest,
+    CapitalInvestment,
+    InfrastructureInvestment,
+    Maintenance,
+    BankSupport,
+    BailIn,
+    Merger,
+    BondCoupon,
+    InterbankInterest,
+    InterbankLoss,
+    Exports,
+    Imports,
+    Freight,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LedgerEntry {
+    pub region: Option<u64>,
+    pub kind: FlowKind,
+    pub from: Sector,
+    pub to: Sector,
+    pub amount: f64,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct Ledger {
+    pub tick: u64,
+    pub opening: [f64; 5],
+    pub balances: [f64; 5],
+    pub entries: Vec<LedgerEntry>,
+    pub matrix: [[f64; 5]; 5],
+    pub rates: HashMap<u64, f64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EventKind {
+    PolicyRateDecision,
+    BankInsolvency,
+    ProjectCompleted,
+    InfrastructureOutage,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorldEvent {
+    pub tick: u64,
+    pub kind: EventKind,
+    pub region: Option<u64>,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GapMeasure {
+    #[default]
+    Unemployment,
+    Output,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ForwardGuidance {
+    pub until_tick: u64,
+    pub rate: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CentralBank {
+    pub name: String,
+    pub currency: Option<String>,
+    pub members: Vec<EntityId>,
+    pub neutral_rate: f64,
+    pub inflation_target: f64,
+    pub inflation_weight: f64,
+    pub gap_weight: f64,
+    pub gap_measure: GapMeasure,
+    pub target_unemployment_rate: f64,
+    pub effective_lower_bound: f64,
+    pub smoothing: f64,
+    pub decision_interval_ticks: u64,
+    pub forward_guidance: Option<ForwardGuidance>,
+    pub policy_rate: f64,
+    pub inflation: f64,
+    pub gap: f64,
+    pub price_level: f64,
+    pub reference_price_level: f64,
+    pub reference_tick: u64,
+    pub potential_output: f64,
 }
 
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
@@ -139,24 +1129,109 @@ pub struct RegionSnapshot {
     pub citizens: u64,
     pub employed: u64,
     pub unemployment_rate: f64,
+    pub labour_force: u64,
+    pub dependency_ratio: f64,
+    pub age_pyramid: Vec<AgeCohort>,
+    pub immigrants: f64,
+    pub emigrants: f64,
+    pub net_migration: f64,
+    pub epidemics: Vec<Epidemic>,
+    pub infectious: f64,
+    pub sick_workers: f64,
+    pub disease_deaths: f64,
+    pub hospital_beds: f64,
+    pub hospital_load: f64,
+    pub hospital_overload_days: f64,
+    pub npi_strength: f64,
     pub food: f64,
     pub energy: f64,
     pub wage: f64,
     pub labor_demand: f64,
     pub household_budget: f64,
+    pub household_agents: usize,
+    pub household_deposits: f64,
+    pub household_debt: f64,
+    pub household_wealth: f64,
+    pub household_saving: f64,
+    pub saving_rate: f64,
+    pub permanent_income: f64,
+    pub gini: f64,
+    pub palma: f64,
+    pub poverty_rate: f64,
+    pub income_groups: Vec<IncomeGroup>,
     pub food_price: f64,
     pub energy_price: f64,
     pub food_shortage_ratio: f64,
     pub energy_shortage_ratio: f64,
+    pub capital_stock: f64,
+    pub investment: f64,
+    pub capital_output_multiplier: f64,
+    pub education_attainment: f64,
+    pub skill_index: f64,
+    pub required_skill: f64,
+    pub skill_mismatch: f64,
+    pub education_spending: f64,
+    pub intermediate_food: f64,
+    pub intermediate_energy: f64,
+    pub food_input_throttle: f64,
+    pub energy_input_throttle: f64,
+    pub markets: Vec<MarketOutcome>,
     pub bank_deposits: f64,
     pub loan_balance: f64,
     pub credit_stress: f64,
+    pub new_lending: f64,
+    pub loan_repayments: f64,
+    pub loan_interest: f64,
+    pub loan_defaults: f64,
+    pub loan_arrears: f64,
+    pub loan_count: usize,
+    pub loan_age_distribution: Vec<LoanAgeBucket>,
+    pub bank_capital: f64,
+    pub capital_ratio: f64,
+    pub liquidity_ratio: f64,
+    pub credit_rationing: f64,
+    pub bank_insolvencies: u32,
+    pub interbank_lending: f64,
+    pub interbank_borrowing: f64,
+    pub interbank_rate: f64,
+    pub currency: Option<String>,
+    pub exchange_rate: f64,
+    pub exports: f64,
+    pub imports: f64,
+    pub trade_balance: f64,
+    pub freight_cost: f64,
     pub power_capacity: f64,
     pub transport_capacity: f64,
     pub infrastructure_reliability: f64,
+    pub power_plants: Vec<PowerPlant>,
+    pub price_setting_plant: Option<String>,
+    pub plant_outages: usize,
+    pub emissions: f64,
+    pub infrastructure_outages: usize,
+    pub storage_capacity: f64,
+    pub storage_level: f64,
+    pub energy_stored: f64,
+    pub energy_released: f64,
+    pub wind_factor: f64,
+    pub solar_factor: f64,
+    pub temperature: f64,
+    pub precipitation: f64,
+    pub crop_yield: f64,
+    pub heating_demand: f64,
+    pub deposits: Vec<ResourceDeposit>,
+    pub extracted_share: f64,
+    pub infrastructure_assets: Vec<InfrastructureAsset>,
+    pub infrastructure_projects: Vec<InfrastructureProject>,
     pub tax_rate: f64,
     pub transfer_per_capita: f64,
     pub public_debt: f64,
+    pub debt_to_gdp: f64,
+    pub bond_yield: f64,
+    pub interest_payments: f64,
+    pub primary_balance: f64,
+    pub bond_issuance: f64,
+    pub bank_bond_holdings: f64,
+    pub household_bond_holdings: f64,
     pub policy_approval: f64,
     pub budget_balance: f64,
     pub unlocked_techs: Vec<String>,
@@ -170,8 +1245,16 @@ pub struct WorldSnapshot {
     pub scenario: String,
     pub tick: u64,
     pub days_elapsed: f64,
+    pub date: NaiveDate,
     pub total_population: u64,
     pub starving_regions: Vec<String>,
+    pub migration_flows: Vec<MigrationFlow>,
+    pub central_banks: Vec<CentralBank>,
+    pub interbank: InterbankMarket,
+    pub fx: FxMarket,
+    pub transport: TransportNetwork,
+    pub ledger: Ledger,
+    pub events: Vec<WorldEvent>,
     pub regions: Vec<RegionSnapshot>,
 }
 
@@ -180,14 +1263,36 @@ pub struct World {
     tick: u64,
     days_elapsed: f64,
     dt_days: f64,
+    start_date: NaiveDate,
     pub(crate) regions: HashMap<EntityId, RegionComponent>,
     pub(crate) populations: HashMap<EntityId, PopulationComponent>,
+    pub(crate) demographics: HashMap<EntityId, DemographyComponent>,
     pub(crate) economies: HashMap<EntityId, EconomyComponent>,
+    pub(crate) capital: HashMap<EntityId, CapitalComponent>,
+    pub(crate) education: HashMap<EntityId, EducationComponent>,
+    pub(crate) households: HashMap<EntityId, HouseholdComponent>,
+    pub(crate) household_agents: HashMap<EntityId, HouseholdAgentsComponent>,
+    pub(crate) distributions: HashMap<EntityId, DistributionComponent>,
     pub(crate) resources: HashMap<EntityId, ResourceStock>,
+    pub(crate) supply_chains: HashMap<EntityId, SupplyChainComponent>,
+    pub(crate) markets: HashMap<EntityId, MarketComponent>,
     pub(crate) finances: HashMap<EntityId, FinanceComponent>,
     pub(crate) infrastructure: HashMap<EntityId, InfrastructureComponent>,
     pub(crate) technology: HashMap<EntityId, TechnologyComponent>,
     pub(crate) policies: HashMap<EntityId, PolicyComponent>,
+    pub(crate) health: HashMap<EntityId, HealthComponent>,
+    pub(crate) weather: HashMap<EntityId, WeatherComponent>,
+    pub(crate) deposits: HashMap<EntityId, Vec<ResourceDeposit>>,
+    pub(crate) migration: MigrationState,
+    pub(crate) epidemiology: HealthState,
+    pub(crate) central_banks: Vec<CentralBank>,
+    pub(crate) interbank: InterbankMarket,
+    pub(crate) fx: FxMarket,
+    pub(crate) transport: TransportNetwork,
+    pub(crate) ledger: Ledger,
+    pub(crate) events: VecDeque<WorldEvent>,
+    events_logged: u64,
+    events_snapshotted: u64,
     pub(crate) bookkeeping: BookkeepingState,
 }
 
@@ -198,18 +1303,41 @@ impl World {
             tick: 0,
             days_elapsed: 0.0,
             dt_days,
+            start_date: NaiveDate::default(),
             regions: HashMap::new(),
             populations: HashMap::new(),
+            demographics: HashMap::new(),
             economies: HashMap::new(),
+            capital: HashMap::new(),
+            education: HashMap::new(),
+            households: HashMap::new(),
+            household_agents: HashMap::new(),
+            distributions: HashMap::new(),
             resources: HashMap::new(),
+            supply_chains: HashMap::new(),
+            markets: HashMap::new(),
             finances: HashMap::new(),
             infrastructure: HashMap::new(),
             technology: HashMap::new(),
             policies: HashMap::new(),
+            health: HashMap::new(),
+            weather: HashMap::new(),
+            deposits: HashMap::new(),
+            migration: MigrationState::default(),
+            epidemiology: HealthState::default(),
+            central_banks: Vec::new(),
+            interbank: InterbankMarket::default(),
+            fx: FxMarket::default(),
+            transport: TransportNetwork::default(),
+            ledger: Ledger::default(),
+            events: VecDeque::with_capacity(EVENT_CAPACITY),
+            events_logged: 0,
+            events_snapshotted: 0,
             bookkeeping: BookkeepingState::default(),
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn spawn_region(
         &mut self,
         region: RegionComponent,
@@ -250,6 +1378,22 @@ impl World {
         self.days_elapsed
     }
 
+    pub fn start_date(&self) -> NaiveDate {
+        self.start_date
+    }
+
+    pub fn set_start_date(&mut self, date: NaiveDate) {
+        self.start_date = date;
+    }
+
+    pub fn date(&self) -> NaiveDate {
+        self.start_date + Days::new(self.days_elapsed.max(0.0).floor() as u64)
+    }
+
+    pub fn day_of_year(&self) -> f64 {
+        self.date().ordinal0() as f64 + self.days_elapsed.max(0.0).fract()
+    }
+
     pub fn total_population(&self) -> u64 {
         self.populations.values().map(|p| p.citizens).sum()
     }
@@ -262,13 +1406,25 @@ impl World {
                 .get(id)
                 .expect("population component exists");
             let economy = self.economies.get(id).expect("economy component exists");
+            let demography = self.demographics.get(id);
+            let capital = self.capital.get(id);
+            let health = self.health.get(id);
+            let education = self.education.get(id);
+            let household = self.households.get(id);
+            let distribution = self.distributions.get(id);
+            let supply_chain = self.supply_chains.get(id);
+            let markets = self
+                .markets
+                .get(id)
+                .map(|m| vec![m.food.clone(), m.energy.clone()])
+                .unwrap_or_default();
             let stock = self.resources.get(id).expect("resource component exists");
             let finance = self.finances.get(id);
             let infra = self.infrastructure.get(id);
             let technology = self.technology.get(id);
             let policy = self.policies.get(id);
-            let unemployment_rate = if population.citizens > 0 {
-                1.0 - (population.employed as f64 / population.citizens as f64)
+            let unemployment_rate = if population.labour_force > 0 {
+                (1.0 - (population.employed as f64 / population.labour_force as f64)).max(0.0)
             } else {
                 0.0
             };
@@ -283,24 +1439,142 @@ impl World {
                 citizens: population.citizens,
                 employed: population.employed,
                 unemployment_rate,
+                labour_force: population.labour_force,
+                dependency_ratio: demography.map(|d| d.dependency_ratio).unwrap_or(0.0),
+                age_pyramid: demography.map(|d| d.cohorts.clone()).unwrap_or_default(),
+                immigrants: self.migration.immigrants(*id),
+                emigrants: self.migration.emigrants(*id),
+                net_migration: self.migration.immigrants(*id) - self.migration.emigrants(*id),
+                epidemics: health.map(|h| h.epidemics.clone()).unwrap_or_default(),
+                infectious: health.map(|h| h.infectious()).unwrap_or(0.0),
+                sick_workers: health.map(|h| h.sick_workers).unwrap_or(0.0),
+                disease_deaths: health.map(|h| h.deaths).unwrap_or(0.0),
+                hospital_beds: health.map(|h| h.hospital_beds()).unwrap_or(0.0),
+                hospital_load: health.map(|h| h.hospital_load).unwrap_or(0.0),
+                hospital_overload_days: health.map(|h| h.overload_days).unwrap_or(0.0),
+                npi_strength: health.map(|h| h.npi_strength).unwrap_or(0.0),
                 food: stock.food,
                 energy: stock.energy,
                 wage: economy.wage,
                 labor_demand: economy.labor_demand,
                 household_budget: economy.household_budget,
+                household_agents: self
+                    .household_agents
+                    .get(id)
+                    .map(|h| h.agents.len())
+                    .unwrap_or(0),
+                household_deposits: household.map(|h| h.deposits).unwrap_or(0.0),
+                household_debt: household.map(|h| h.debt).unwrap_or(0.0),
+                household_wealth: household.map(|h| h.net_wealth()).unwrap_or(0.0),
+                household_saving: household.map(|h| h.saving).unwrap_or(0.0),
+                saving_rate: household.map(|h| h.saving_rate()).unwrap_or(0.0),
+                permanent_income: household.map(|h| h.permanent_income).unwrap_or(0.0),
+                gini: distribution.map(|d| d.gini).unwrap_or(0.0),
+                palma: distribution.map(|d| d.palma).unwrap_or(0.0),
+                poverty_rate: distribution.map(|d| d.poverty_rate).unwrap_or(0.0),
+                income_groups: distribution.map(|d| d.groups.clone()).unwrap_or_default(),
                 food_price: economy.food_price,
                 energy_price: economy.energy_price,
                 food_shortage_ratio: economy.food_shortage_ratio,
                 energy_shortage_ratio: economy.energy_shortage_ratio,
+                capital_stock: capital.map(|c| c.stock).unwrap_or(0.0),
+                investment: capital.map(|c| c.investment).unwrap_or(0.0),
+                capital_output_multiplier: capital.map(|c| c.output_multiplier).unwrap_or(1.0),
+                education_attainment: education.map(|e| e.attainment).unwrap_or(0.0),
+                skill_index: education.map(|e| e.skill_index).unwrap_or(1.0),
+                required_skill: education.map(|e| e.required_skill).unwrap_or(1.0),
+                skill_mismatch: education.map(|e| e.skill_mismatch).unwrap_or(0.0),
+                education_spending: policy.map(|p| p.last_education_spending).unwrap_or(0.0),
+                intermediate_food: supply_chain.map(|c| c.intermediate_food).unwrap_or(0.0),
+                intermediate_energy: supply_chain.map(|c| c.intermediate_energy).unwrap_or(0.0),
+                food_input_throttle: supply_chain.map(|c| c.food_throttle).unwrap_or(0.0),
+                energy_input_throttle: supply_chain.map(|c| c.energy_throttle).unwrap_or(0.0),
+                markets,
                 bank_deposits: finance.map(|f| f.bank_deposits).unwrap_or(0.0),
                 loan_balance: finance.map(|f| f.loan_balance).unwrap_or(0.0),
                 credit_stress: finance.map(|f| f.credit_stress).unwrap_or(0.0),
+                new_lending: finance.map(|f| f.new_lending).unwrap_or(0.0),
+                loan_repayments: finance.map(|f| f.repayments).unwrap_or(0.0),
+                loan_interest: finance.map(|f| f.interest_received).unwrap_or(0.0),
+                loan_defaults: finance.map(|f| f.defaults).unwrap_or(0.0),
+                loan_arrears: finance.map(|f| f.arrears).unwrap_or(0.0),
+                loan_count: finance.map(|f| f.loans.len()).unwrap_or(0),
+                loan_age_distribution: finance
+                    .map(crate::lending::age_distribution)
+                    .unwrap_or_default(),
+                bank_capital: finance.map(|f| f.capital).unwrap_or(0.0),
+                capital_ratio: finance.map(|f| f.capital_ratio).unwrap_or(0.0),
+                liquidity_ratio: finance.map(|f| f.liquidity_ratio).unwrap_or(0.0),
+                credit_rationing: finance.map(|f| f.credit_rationing).unwrap_or(0.0),
+                bank_insolvencies: finance.map(|f| f.insolvencies).unwrap_or(0),
+                interbank_lending: finance.map(|f| f.interbank_lending).unwrap_or(0.0),
+                interbank_borrowing: finance.map(|f| f.interbank_borrowing).unwrap_or(0.0),
+                interbank_rate: finance.map(|f| f.interbank_rate).unwrap_or(0.0),
+                currency: crate::fx::currency(&self.fx, *id).map(|c| c.code.clone()),
+                exchange_rate: crate::fx::rate(&self.fx, *id),
+                exports: economy.exports,
+                imports: economy.imports,
+                trade_balance: economy.exports - economy.imports,
+                freight_cost: economy.freight_cost,
                 power_capacity: infra.map(|i| i.power_capacity).unwrap_or(0.0),
                 transport_capacity: infra.map(|i| i.transport_capacity).unwrap_or(0.0),
                 infrastructure_reliability: infra.map(|i| i.reliability).unwrap_or(0.0),
+                power_plants: infra.map(|i| i.plants.clone()).unwrap_or_default(),
+                price_setting_plant: infra.and_then(|i| {
+                    let id = i.price_setter?;
+                    i.plants.iter().find(|p| p.id == id).map(|p| p.name.clone())
+                }),
+                plant_outages: infra
+                    .map(|i| i.plants.iter().filter(|p| !p.online).count())
+                    .unwrap_or(0),
+                emissions: infra.map(|i| i.emissions).unwrap_or(0.0),
+                storage_capacity: infra
+                    .map(|i| i.storage.iter().map(|s| s.capacity).sum())
+                    .unwrap_or(0.0),
+                storage_level: infra
+                    .map(|i| i.storage.iter().map(|s| s.level).sum())
+                    .unwrap_or(0.0),
+                energy_stored: infra
+                    .map(|i| i.storage.iter().map(|s| s.charged).sum())
+                    .unwrap_or(0.0),
+                energy_released: infra
+                    .map(|i| i.storage.iter().map(|s| s.discharged).sum())
+                    .unwrap_or(0.0),
+                wind_factor: self.weather.get(id).map(|w| w.wind).unwrap_or(1.0),
+                solar_factor: self.weather.get(id).map(|w| w.solar).unwrap_or(1.0),
+                temperature: self.weather.get(id).map(|w| w.temperature).unwrap_or(0.0),
+                precipitation: self.weather.get(id).map(|w| w.precipitation).unwrap_or(0.0),
+                crop_yield: self.weather.get(id).map(|w| w.crop_yield).unwrap_or(1.0),
+                heating_demand: self
+                    .weather
+                    .get(id)
+                    .map(|w| w.heating_demand)
+                    .unwrap_or(1.0),
+                deposits: self.deposits.get(id).cloned().unwrap_or_default(),
+                extracted_share: self
+                    .deposits
+                    .get(id)
+                    .map(|d| crate::deposits::extracted_share(d))
+                    .unwrap_or(0.0),
+                infrastructure_outages: infra
+                    .map(|i| i.assets.iter().filter(|a| !a.in_service()).count())
+                    .unwrap_or(0),
+                infrastructure_assets: infra.map(|i| i.assets.clone()).unwrap_or_default(),
+                infrastructure_projects: infra.map(|i| i.projects.clone()).unwrap_or_default(),
                 tax_rate: policy.map(|p| p.tax_rate).unwrap_or(0.0),
                 transfer_per_capita: policy.map(|p| p.transfer_per_capita).unwrap_or(0.0),
                 public_debt: policy.map(|p| p.public_debt).unwrap_or(0.0),
+                debt_to_gdp: policy.map(|p| p.debt_to_gdp).unwrap_or(0.0),
+                bond_yield: policy.map(|p| p.bond_yield).unwrap_or(0.0),
+                interest_payments: policy.map(|p| p.last_interest).unwrap_or(0.0),
+                primary_balance: policy.map(|p| p.primary_balance).unwrap_or(0.0),
+                bond_issuance: policy.map(|p| p.last_bond_issuance).unwrap_or(0.0),
+                bank_bond_holdings: policy
+                    .map(|p| p.bonds.iter().map(|b| b.bank_holding).sum())
+                    .unwrap_or(0.0),
+                household_bond_holdings: policy
+                    .map(|p| p.bonds.iter().map(|b| b.household_holding).sum())
+                    .unwrap_or(0.0),
                 policy_approval: policy.map(|p| p.approval_rating).unwrap_or(0.0),
                 budget_balance: policy.map(|p| p.budget_balance).unwrap_or(0.0),
                 unlocked_techs: unlocked,
@@ -314,12 +1588,107 @@ impl World {
             scenario: scenario.to_string(),
             tick: self.tick,
             days_elapsed: self.days_elapsed,
+            date: self.date(),
             total_population: self.total_population(),
             starving_regions: self.bookkeeping.starving_regions.clone(),
+            migration_flows: self.migration.flows.clone(),
+            central_banks: self.central_banks.clone(),
+            interbank: self.interbank.clone(),
+            fx: self.fx.clone(),
+            transport: self.transport.clone(),
+            ledger: self.ledger.clone(),
+            events: self.events_since_snapshot().cloned().collect(),
             regions,
         }
     }
 
+    pub fn migration(&self) -> &MigrationState {
+        &self.migration
+    }
+
+    pub fn migration_mut(&mut self) -> &mut MigrationState {
+        &mut self.migration
+    }
+
+    pub fn central_banks(&self) -> &[CentralBank] {
+        &self.central_banks
+    }
+
+    pub fn central_banks_mut(&mut self) -> &mut Vec<CentralBank> {
+        &mut self.central_banks
+    }
+
+    pub fn interbank(&self) -> &InterbankMarket {
+        &self.interbank
+    }
+
+    pub fn interbank_mut(&mut self) -> &mut InterbankMarket {
+        &mut self.interbank
+    }
+
+    pub fn fx(&self) -> &FxMarket {
+        &self.fx
+    }
+
+    pub fn fx_mut(&mut self) -> &mut FxMarket {
+        &mut self.fx
+    }
+
+    pub fn transport(&self) -> &TransportNetwork {
+        &self.transport
+    }
+
+    pub fn transport_mut(&mut self) -> &mut TransportNetwork {
+        &mut self.transport
+    }
+
+    pub fn ledger(&self) -> &Ledger {
+        &self.ledger
+    }
+
+    pub fn events(&self) -> &VecDeque<WorldEvent> {
+        &self.events
+    }
+
+    /// Events logged since the last written snapshot, limited to what the buffer still holds.
+    pub fn events_since_snapshot(&self) -> impl Iterator<Item = &WorldEvent> {
+        let pending = (self.events_logged - self.events_snapshotted).min(self.events.len() as u64);
+        self.events
+            .iter()
+            .skip(self.events.len() - pending as usize)
+    }
+
+    pub fn mark_snapshot_written(&mut self) {
+        self.events_snapshotted = self.events_logged;
+    }
+
+    pub fn log_event(
+        &mut self,
+        tick: u64,
+        kind: EventKind,
+        region: Option<EntityId>,
+        message: impl Into<String>,
+    ) {
+        if self.events.len() == EVENT_CAPACITY {
+            self.events.pop_front();
+        }
+        self.events.push_back(WorldEvent {
+            tick,
+            kind,
+            region: region.map(|id| id.raw()),
+            message: message.into(),
+        });
+        self.events_logged += 1;
+    }
+
+    pub fn epidemiology(&self) -> &HealthState {
+        &self.epidemiology
+    }
+
+    pub fn epidemiology_mut(&mut self) -> &mut HealthState {
+        &mut self.epidemiology
+    }
+
     pub fn entity_ids(&self) -> Vec<EntityId> {
         let mut ids: Vec<_> = self.regions.keys().cloned().collect();
         ids.sort();
@@ -334,10 +1703,70 @@ impl World {
         self.economies.get_mut(&id)
     }
 
+    pub fn capital(&self, id: EntityId) -> Option<&CapitalComponent> {
+        self.capital.get(&id)
+    }
+
+    pub fn capital_mut(&mut self, id: EntityId) -> Option<&mut CapitalComponent> {
+        self.capital.get_mut(&id)
+    }
+
+    pub fn education(&self, id: EntityId) -> Option<&EducationComponent> {
+        self.education.get(&id)
+    }
+
+    pub fn education_mut(&mut self, id: EntityId) -> Option<&mut EducationComponent> {
+        self.education.get_mut(&id)
+    }
+
+    pub fn household(&self, id: EntityId) -> Option<&HouseholdComponent> {
+        self.households.get(&id)
+    }
+
+    pub fn household_mut(&mut self, id: EntityId) -> Option<&mut HouseholdComponent> {
+        self.households.get_mut(&id)
+    }
+
+    pub fn household_agents(&self, id: EntityId) -> Option<&HouseholdAgentsComponent> {
+        self.household_agents.get(&id)
+    }
+
+    pub fn household_agents_mut(&mut self, id: EntityId) -> Option<&mut HouseholdAgentsComponent> {
+        self.household_agents.get_mut(&id)
+    }
+
+    pub fn distribution(&self, id: EntityId) -> Option<&DistributionComponent> {
+        self.distributions.get(&id)
+    }
+
+    pub fn distribution_mut(&mut self, id: EntityId) -> Option<&mut DistributionComponent> {
+        self.distributions.get_mut(&id)
+    }
+
     pub fn region(&self, id: EntityId) -> Option<&RegionComponent> {
         self.regions.get(&id)
     }
 
+    pub fn market(&self, id: EntityId) -> Option<&MarketComponent> {
+        self.markets.get(&id)
+    }
+
+    pub fn market_mut(&mut self, id: EntityId) -> Option<&mut MarketComponent> {
+        self.markets.get_mut(&id)
+    }
+
+    pub fn supply_chain(&self, id: EntityId) -> Option<&SupplyChainComponent> {
+        self.supply_chains.get(&id)
+    }
+
+    pub fn supply_chain_mut(&mut self, id: EntityId) -> Option<&mut SupplyChainComponent> {
+        self.supply_chains.get_mut(&id)
+    }
+
+    pub fn resources(&self, id: EntityId) -> Option<&ResourceStock> {
+        self.resources.get(&id)
+    }
+
     pub fn resources_mut(&mut self, id: EntityId) -> Option<&mut ResourceStock> {
         self.resources.get_mut(&id)
     }
@@ -350,6 +1779,38 @@ impl World {
         self.populations.get_mut(&id)
     }
 
+    pub fn demography(&self, id: EntityId) -> Option<&DemographyComponent> {
+        self.demographics.get(&id)
+    }
+
+    pub fn demography_mut(&mut self, id: EntityId) -> Option<&mut DemographyComponent> {
+        self.demographics.get_mut(&id)
+    }
+
+    pub fn weather(&self, id: EntityId) -> Option<&WeatherComponent> {
+        self.weather.get(&id)
+    }
+
+    pub fn weather_mut(&mut self, id: EntityId) -> Option<&mut WeatherComponent> {
+        self.weather.get_mut(&id)
+    }
+
+    pub fn deposits(&self, id: EntityId) -> Option<&[ResourceDeposit]> {
+        self.deposits.get(&id).map(Vec::as_slice)
+    }
+
+    pub fn deposits_mut(&mut self, id: EntityId) -> Option<&mut Vec<ResourceDeposit>> {
+        self.deposits.get_mut(&id)
+    }
+
+    pub fn health(&self, id: EntityId) -> Option<&HealthComponent> {
+        self.health.get(&id)
+    }
+
+    pub fn health_mut(&mut self, id: EntityId) -> Option<&mut HealthComponent> {
+        self.health.get_mut(&id)
+    }
+
     pub fn finance(&self, id: EntityId) -> Option<&FinanceComponent> {
         self.finances.get(&id)
     }
@@ -0,0 +1,204 @@
+use panarchy::{
+    engine::{EngineBuilder, EngineSettings},
+    scenario::{Scenario, ScenarioLoader},
+    systems::{
+        BookkeepingSystem, EconomySystem, EnvironmentSystem, FinanceSystem, InfrastructureSystem,
+        PolicySystem, PopulationSystem, TechnologySystem,
+    },
+    world::{PopulationMode, World},
+};
+
+fn scenario_loader() -> ScenarioLoader {
+    ScenarioLoader::new(env!("CARGO_MANIFEST_DIR"))
+}
+
+fn scenario_path() -> std::path::PathBuf {
+    std::path::PathBuf::from("scenarios/tiny_island.yaml")
+}
+
+fn build_engine(seed: u64) -> EngineBuilder {
+    let settings = EngineSettings {
+        scenario_name: "tiny_island".into(),
+        seed,
+        snapshot_interval_ticks: 0,
+        snapshot_dir: std::path::PathBuf::from("snapshots_agents_tests"),
+    };
+    EngineBuilder::new(settings)
+        .with_system(EnvironmentSystem::new())
+        .with_system(InfrastructureSystem::new())
+        .with_system(PopulationSystem::new())
+        .with_system(EconomySystem::new())
+        .with_system(FinanceSystem::new())
+        .with_system(PolicySystem::new())
+        .with_system(TechnologySystem::new())
+        .with_system(BookkeepingSystem::new())
+}
+
+fn load(mode: PopulationMode) -> Scenario {
+    let mut scenario = scenario_loader().load(scenario_path()).unwrap();
+    scenario.population_mode = mode;
+    scenario
+}
+
+fn run(scenario: &Scenario, ticks: u64) -> World {
+    let mut world = scenario.build_world();
+    let mut engine = build_engine(scenario.seed).build();
+    engine.run(&mut world, ticks).unwrap();
+    world
+}
+
+#[test]
+fn region_aggregates_are_derived_from_weighted_agents() {
+    let scenario = load(PopulationMode::Agents);
+    let world = run(&scenario, 30);
+    for id in world.entity_ids() {
+        let households = world.household_agents(id).unwrap();
+        assert_eq!(
+            households.agents.len(),
+            scenario.agents.households_per_region
+        );
+        let population = world.population(id).unwrap();
+        let persons: f64 = households.agents.iter().map(|a| a.persons()).sum();
+        assert!((persons - population.citizens as f64).abs() < 1.0);
+        let employed: f64 = households
+            .agents
+            .iter()
+            .filter(|a| a.employed)
+            .map(|a| a.workers())
+            .sum();
+        assert_eq!(population.employed, employed.round() as u64);
+
+        let planned: f64 = households
+            .agents
+            .iter()
+            .map(|a| a.weight * a.planned_consumption)
+            .sum();
+        assert!((world.economy(id).unwrap().household_budget - planned).abs() < 1e-6);
+        let deposits: f64 = households
+            .agents
+            .iter()
+            .map(|a| a.weight * a.deposits)
+            .sum();
+        assert!((world.household(id).unwrap().deposits - deposits).abs() < 1e-3);
+    }
+    let snapshot = world.snapshot("tiny_island");
+    assert!(snapshot.regions.iter().all(|r| r.household_agents > 0));
+}
+
+#[test]
+fn agent_mode_tracks_the_aggregate_model() {
+    let aggregate = run(&load(PopulationMode::Aggregate), 60);
+    let agents = run(&load(PopulationMode::Agents), 60);
+    assert!(aggregate
+        .household_agents(aggregate.entity_ids()[0])
+        .is_none());
+    assert_eq!(aggregate.total_population(), agents.total_population());
+    for id in agents.entity_ids() {
+        let a = aggregate.population(id).unwrap().employed as f64;
+        let b = agents.population(id).unwrap().employed as f64;
+        assert!((a - b).abs() / a < 0.05, "employment {a} vs {b}");
+    }
+}
+
+#[test]
+fn layoffs_hit_the_least_productive_households_first() {
+    let mut scenario = load(PopulationMode::Agents);
+    for region in scenario.regions.iter_mut() {
+        region.economy.food_productivity_per_worker *= 1.2;
+        region.economy.energy_productivity_per_worker *= 1.2;
+    }
+    let world = run(&scenario, 60);
+    let id = world.entity_ids()[0];
+    let agents = &world.household_agents(id).unwrap().agents;
+    let employed_min = agents
+        .iter()
+        .filter(|a| a.employed)
+        .map(|a| a.productivity)
+        .fold(f64::INFINITY, f64::min);
+    let unemployed_max = agents
+        .iter()
+        .filter(|a| !a.employed)
+        .map(|a| a.productivity)
+        .fold(0.0, f64::max);
+    assert!(unemployed_max > 0.0, "the scenario should shed jobs");
+    assert!(employed_min >= unemployed_max);
+    let earning = |employed: bool| {
+        agents
+            .iter()
+            .filter(|a| a.employed == employed)
+            .map(|a| a.gross_income)
+            .sum::<f64>()
+            / agents.iter().filter(|a| a.employed == employed).count() as f64
+    };
+    assert!(earning(true) > earning(false));
+    assert!(world.distribution(id).unwrap().gini > 0.0);
+}
+
+#[test]
+fn households_differ_in_size_and_weight() {
+    let scenario = load(PopulationMode::Agents);
+    let world = scenario.build_world();
+    let id = world.entity_ids()[0];
+    let agents = &world.household_agents(id).unwrap().agents;
+    let (smallest, largest) = agents.iter().fold((f64::INFINITY, 0.0_f64), |(lo, hi), a| {
+        (lo.min(a.size), hi.max(a.size))
+    });
+    assert!(largest > smallest * 1.5, "sizes {smallest}..{largest}");
+    let first = agents.first().unwrap();
+    let last = agents.last().unwrap();
+    assert!(
+        first.size > last.size,
+        "poorer agents keep larger households"
+    );
+    assert!(first.weight < last.weight);
+    assert!((first.persons() - last.persons()).abs() < 1e-9);
+}
+
+#[test]
+fn citizens_and_labour_force_are_summed_from_agents() {
+    let scenario = load(PopulationMode::Agents);
+    let mut world = scenario.build_world();
+    let id = world.entity_ids()[0];
+    let before = world.population(id).unwrap().citizens as f64;
+    let added = {
+        let agent = &mut world.household_agents_mut(id).unwrap().agents[0];
+        let added = agent.persons();
+        agent.weight *= 2.0;
+        added
+    };
+    let mut engine = build_engine(scenario.seed).build();
+    engine.run(&mut world, 1).unwrap();
+    let population = world.population(id).unwrap();
+    let households = world.household_agents(id).unwrap();
+    let persons: f64 = households.agents.iter().map(|a| a.persons()).sum();
+    let workers: f64 = households.agents.iter().map(|a| a.workers()).sum();
+    assert_eq!(population.citizens, persons.round() as u64);
+    assert_eq!(population.labour_force, workers.round() as u64);
+    assert!(
+        (population.citizens as f64 - (before + added)).abs() < added * 0.1,
+        "the extra households should not be rescaled away"
+    );
+}
+
+#[test]
+fn income_groups_are_rebuilt_from_the_agents() {
+    let world = run(&load(PopulationMode::Agents), 30);
+    for id in world.entity_ids() {
+        let agents = &world.household_agents(id).unwrap().agents;
+        let groups = &world.distribution(id).unwrap().groups;
+        let persons: f64 = agents.iter().map(|a| a.persons()).sum();
+        let disposable: f64 = agents.iter().map(|a| a.weight * a.income).sum();
+        let members: f64 = groups.iter().map(|g| g.members).sum();
+        let grouped: f64 = groups.iter().map(|g| g.disposable_income).sum();
+        assert!((members - persons).abs() < 1e-6 * persons);
+        assert!((grouped - disposable).abs() < 1e-6 * disposable.abs().max(1.0));
+        let per_capita: Vec<f64> = groups
+            .iter()
+            .map(|g| g.disposable_income / g.members)
+            .collect();
+        assert!(
+            per_capita.windows(2).all(|w| w[0] <= w[1] + 1e-9),
+            "quintiles should be ordered by income: {per_capita:?}"
+        );
+    }
+}
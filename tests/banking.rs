@@ -0,0 +1,204 @@
+use panarchy::{
+    engine::{EngineBuilder, EngineSettings},
+    lending,
+    scenario::{Scenario, ScenarioLoader},
+    systems::{
+        BookkeepingSystem, EconomySystem, EnvironmentSystem, FinanceSystem, InfrastructureSystem,
+        PolicySystem, PopulationSystem, TechnologySystem,
+    },
+    world::{EventKind, Resolution, World},
+};
+
+fn scenario_loader() -> ScenarioLoader {
+    ScenarioLoader::new(env!("CARGO_MANIFEST_DIR"))
+}
+
+fn scenario_path() -> std::path::PathBuf {
+    std::path::PathBuf::from("scenarios/tiny_island.yaml")
+}
+
+fn build_engine(seed: u64) -> EngineBuilder {
+    let settings = EngineSettings {
+        scenario_name: "tiny_island".into(),
+        seed,
+        snapshot_interval_ticks: 0,
+        snapshot_dir: std::path::PathBuf::from("snapshots_banking_tests"),
+    };
+    EngineBuilder::new(settings)
+        .with_system(EnvironmentSystem::new())
+        .with_system(InfrastructureSystem::new())
+        .with_system(PopulationSystem::new())
+        .with_system(EconomySystem::new())
+        .with_system(FinanceSystem::new())
+        .with_system(PolicySystem::new())
+        .with_system(TechnologySystem::new())
+        .with_system(BookkeepingSystem::new())
+}
+
+fn with_resolution(resolution: Resolution) -> Scenario {
+    let mut scenario = scenario_loader().load(scenario_path()).unwrap();
+    for region in scenario.regions.iter_mut() {
+        region.finance.resolution = resolution;
+    }
+    scenario
+}
+
+fn fail_first_bank(scenario: &Scenario, peer_capital: f64) -> (World, World) {
+    let mut baseline = scenario.build_world();
+    let mut world = scenario.build_world();
+    for w in [&mut baseline, &mut world] {
+        for other in w.entity_ids().into_iter().skip(1) {
+            w.finance_mut(other).unwrap().capital += peer_capital;
+        }
+    }
+    let id = world.entity_ids()[0];
+    world.finance_mut(id).unwrap().capital = -1_000_000.0;
+    build_engine(scenario.seed)
+        .build()
+        .run(&mut baseline, 1)
+        .unwrap();
+    build_engine(scenario.seed)
+        .build()
+        .run(&mut world, 1)
+        .unwrap();
+    (baseline, world)
+}
+
+#[test]
+fn capital_ratio_breaches_ration_investment_credit() {
+    let scenario = scenario_loader().load(scenario_path()).unwrap();
+    let world = scenario.build_world();
+    let id = world.entity_ids()[0];
+    let finance = world.finance(id).unwrap();
+    let expected = scenario.regions[0].finance.initial_capital_ratio;
+    assert!((finance.capital_ratio - expected).abs() < 1e-9);
+    assert!((finance.capital - expected * lending::risk_weighted_assets(finance)).abs() < 1e-6);
+    assert_eq!(finance.credit_rationing, 0.0);
+
+    let mut strict = scenario.clone();
+    for region in strict.regions.iter_mut() {
+        region.finance.min_capital_ratio = 10.0;
+    }
+    let mut relaxed_world = scenario.build_world();
+    let mut strict_world = strict.build_world();
+    build_engine(scenario.seed)
+        .build()
+        .run(&mut relaxed_world, 20)
+        .unwrap();
+    build_engine(strict.seed)
+        .build()
+        .run(&mut strict_world, 20)
+        .unwrap();
+    let strict_finance = strict_world.finance(id).unwrap();
+    assert!(strict_finance.credit_rationing > 0.9);
+    assert!(
+        strict_world.capital(id).unwrap().stock < relaxed_world.capital(id).unwrap().stock,
+        "rationed banks should finance less investment"
+    );
+}
+
+#[test]
+fn insolvent_banks_are_bailed_out_with_public_debt() {
+    let scenario = with_resolution(Resolution::BailOut);
+    let (baseline, world) = fail_first_bank(&scenario, 0.0);
+    let id = world.entity_ids()[0];
+    let finance = world.finance(id).unwrap();
+    assert_eq!(finance.insolvencies, 1);
+    assert!(finance.capital > 0.0);
+    let extra_debt =
+        world.policy(id).unwrap().public_debt - baseline.policy(id).unwrap().public_debt;
+    assert!((extra_debt - finance.resolution_cost).abs() < 1e-3 * finance.resolution_cost);
+
+    let snapshot = world.snapshot("tiny_island");
+    assert_eq!(snapshot.regions[0].bank_insolvencies, 1);
+    assert!(snapshot
+        .events
+        .iter()
+        .any(|e| e.kind == EventKind::BankInsolvency && e.region == Some(id.raw())));
+}
+
+#[test]
+fn bail_in_and_merger_shift_losses_away_from_taxpayers() {
+    let scenario = with_resolution(Resolution::BailIn);
+    let (baseline, world) = fail_first_bank(&scenario, 0.0);
+    let id = world.entity_ids()[0];
+    let cost = world.finance(id).unwrap().resolution_cost;
+    assert!(cost > 0.0);
+    let deposits =
+        |w: &World| w.finance(id).unwrap().bank_deposits + w.household(id).unwrap().deposits;
+    let lost = deposits(&baseline) - deposits(&world);
+    assert!((lost - cost).abs() < 1e-3 * cost);
+    assert!(
+        (world.policy(id).unwrap().public_debt - baseline.policy(id).unwrap().public_debt).abs()
+            < 1e-6
+    );
+
+    let scenario = with_resolution(Resolution::Merger);
+    let (baseline, world) = fail_first_bank(&scenario, 5_000_000.0);
+    let cost = world.finance(id).unwrap().resolution_cost;
+    let injected: f64 = world
+        .entity_ids()
+        .into_iter()
+        .skip(1)
+        .map(|other| {
+            baseline.finance(other).unwrap().capital - world.finance(other).unwrap().capital
+        })
+        .sum();
+    assert!(cost > 0.0);
+    assert!((injected - cost).abs() < 1e-3 * cost);
+}
+
+#[test]
+fn a_merger_moves_the_failed_book_to_the_acquirer() {
+    let scenario = with_resolution(Resolution::Merger);
+    let (baseline, world) = fail_first_bank(&scenario, 5_000_000.0);
+    let ids = world.entity_ids();
+    let failed = world.finance(ids[0]).unwrap();
+    assert!(failed.loans.is_empty());
+    assert_eq!(failed.loan_balance, 0.0);
+    assert!(failed.capital >= 0.0);
+
+    let acquirer = ids[1..]
+        .iter()
+        .copied()
+        .max_by(|a, b| {
+            let drop =
+                |id| baseline.finance(id).unwrap().capital - world.finance(id).unwrap().capital;
+            drop(*a).total_cmp(&drop(*b))
+        })
+        .unwrap();
+    let gained = world.finance(acquirer).unwrap().loan_balance
+        - baseline.finance(acquirer).unwrap().loan_balance;
+    assert!(gained > 0.5 * baseline.finance(ids[0]).unwrap().loan_balance);
+    assert!(
+        (world.policy(ids[0]).unwrap().public_debt - baseline.policy(ids[0]).unwrap().public_debt)
+            .abs()
+            < 1e-6
+    );
+}
+
+#[test]
+fn a_merger_without_a_qualified_acquirer_is_logged_as_a_bail_out() {
+    let scenario = with_resolution(Resolution::Merger);
+    let mut world = scenario.build_world();
+    let id = world.entity_ids()[0];
+    let debt = world.policy(id).unwrap().public_debt;
+    world.finance_mut(id).unwrap().capital = -1e12;
+    build_engine(scenario.seed)
+        .build()
+        .run(&mut world, 1)
+        .unwrap();
+    let events: Vec<_> = world
+        .events()
+        .iter()
+        .filter(|e| e.kind == EventKind::BankInsolvency)
+        .collect();
+    assert_eq!(events.len(), 1);
+    assert!(
+        events[0].message.contains("no peer bank"),
+        "{}",
+        events[0].message
+    );
+    assert!(!world.finance(id).unwrap().loans.is_empty());
+    assert!(world.policy(id).unwrap().public_debt > debt);
+}
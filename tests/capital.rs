@@ -0,0 +1,131 @@
+use panarchy::{
+    engine::{EngineBuilder, EngineSettings},
+    production,
+    scenario::ScenarioLoader,
+    systems::{
+        BookkeepingSystem, EconomySystem, EnvironmentSystem, FinanceSystem, InfrastructureSystem,
+        PolicySystem, PopulationSystem, TechnologySystem,
+    },
+};
+
+fn scenario_loader() -> ScenarioLoader {
+    ScenarioLoader::new(env!("CARGO_MANIFEST_DIR"))
+}
+
+fn scenario_path() -> std::path::PathBuf {
+    std::path::PathBuf::from("scenarios/tiny_island.yaml")
+}
+
+fn build_engine(seed: u64) -> EngineBuilder {
+    let settings = EngineSettings {
+        scenario_name: "tiny_island".into(),
+        seed,
+        snapshot_interval_ticks: 0,
+        snapshot_dir: std::path::PathBuf::from("snapshots_capital_tests"),
+    };
+    EngineBuilder::new(settings)
+        .with_system(EnvironmentSystem::new())
+        .with_system(InfrastructureSystem::new())
+        .with_system(PopulationSystem::new())
+        .with_system(EconomySystem::new())
+        .with_system(FinanceSystem::new())
+        .with_system(PolicySystem::new())
+        .with_system(TechnologySystem::new())
+        .with_system(BookkeepingSystem::new())
+}
+
+#[test]
+fn loan_financed_investment_rebuilds_depleted_capital() {
+    let loader = scenario_loader();
+    let scenario = loader.load(scenario_path()).unwrap();
+    let mut world = scenario.build_world();
+    let id = world
+        .entity_ids()
+        .into_iter()
+        .next()
+        .expect("region exists");
+    if let Some(capital) = world.capital_mut(id) {
+        capital.stock *= 0.5;
+    }
+    let baseline_stock = world.capital(id).unwrap().stock;
+    let baseline_loans = world.finance(id).unwrap().loan_balance;
+    let mut engine = build_engine(scenario.seed).build();
+    engine.run(&mut world, 10).unwrap();
+    let capital = world.capital(id).expect("capital exists");
+    assert!(
+        capital.investment > capital.depreciation,
+        "a capital gap should trigger net investment"
+    );
+    assert!(capital.stock > baseline_stock);
+    assert!(
+        capital.output_multiplier < 1.0,
+        "a thin capital stock should depress output per worker"
+    );
+    assert!(
+        world.finance(id).unwrap().loan_balance > baseline_loans,
+        "investment should be financed by new loans"
+    );
+}
+
+#[test]
+fn higher_loan_rates_reduce_investment() {
+    let loader = scenario_loader();
+    let scenario = loader.load(scenario_path()).unwrap();
+    let run = |policy_rate: f64| {
+        let mut world = scenario.build_world();
+        let id = world.entity_ids()[0];
+        if let Some(finance) = world.finance_mut(id) {
+            finance.policy_rate = policy_rate;
+        }
+        let mut engine = build_engine(scenario.seed).build();
+        engine.run(&mut world, 10).unwrap();
+        world.capital(id).expect("capital exists").clone()
+    };
+    let cheap = run(0.01);
+    let dear = run(0.25);
+    assert!(cheap.desired_stock > dear.desired_stock);
+    assert!(
+        cheap.stock > dear.stock,
+        "dearer credit should slow capital accumulation ({} vs {})",
+        cheap.stock,
+        dear.stock
+    );
+}
+
+#[test]
+fn ces_production_nests_cobb_douglas() {
+    let loader = scenario_loader();
+    let scenario = loader.load(scenario_path()).unwrap();
+    let world = scenario.build_world();
+    let id = world.entity_ids()[0];
+    let mut capital = world.capital(id).expect("capital exists").clone();
+    let workers = world.population(id).expect("population exists").employed as f64;
+    capital.stock *= 2.0;
+    let cobb_douglas = production::output_multiplier(&capital, workers);
+    assert!((cobb_douglas - 2f64.powf(capital.capital_share)).abs() < 1e-9);
+    capital.substitution_elasticity = 0.5;
+    let complements = production::output_multiplier(&capital, workers);
+    assert!(complements > 1.0 && complements < cobb_douglas);
+}
+
+#[test]
+fn steady_state_capital_leaves_baseline_output_unchanged() {
+    let loader = scenario_loader();
+    let scenario = loader.load(scenario_path()).unwrap();
+    let mut world = scenario.build_world();
+    for id in world.entity_ids() {
+        let capital = world.capital(id).expect("capital exists");
+        let employed = world.population(id).expect("population exists").employed as f64;
+        assert!((production::output_multiplier(capital, employed) - 1.0).abs() < 1e-9);
+    }
+    let mut engine = build_engine(scenario.seed).build();
+    engine.run(&mut world, 1).unwrap();
+    for id in world.entity_ids() {
+        let capital = world.capital(id).expect("capital exists");
+        assert!(
+            (capital.output_multiplier - 1.0).abs() < 0.05,
+            "the calibrated stock should not shift tick-0 output ({})",
+            capital.output_multiplier
+        );
+    }
+}
@@ -0,0 +1,139 @@
+use panarchy::{
+    engine::{EngineBuilder, EngineSettings},
+    monetary,
+    scenario::{Scenario, ScenarioCentralBank, ScenarioForwardGuidance, ScenarioLoader},
+    systems::{
+        BookkeepingSystem, CentralBankSystem, EconomySystem, EnvironmentSystem, FinanceSystem,
+        InfrastructureSystem, PolicySystem, PopulationSystem, TechnologySystem,
+    },
+    world::{EventKind, GapMeasure, World},
+};
+
+fn scenario_loader() -> ScenarioLoader {
+    ScenarioLoader::new(env!("CARGO_MANIFEST_DIR"))
+}
+
+fn scenario_path() -> std::path::PathBuf {
+    std::path::PathBuf::from("scenarios/tiny_island.yaml")
+}
+
+fn build_engine(seed: u64) -> EngineBuilder {
+    let settings = EngineSettings {
+        scenario_name: "tiny_island".into(),
+        seed,
+        snapshot_interval_ticks: 0,
+        snapshot_dir: std::path::PathBuf::from("snapshots_central_bank_tests"),
+    };
+    EngineBuilder::new(settings)
+        .with_system(EnvironmentSystem::new())
+        .with_system(InfrastructureSystem::new())
+        .with_system(PopulationSystem::new())
+        .with_system(EconomySystem::new())
+        .with_system(CentralBankSystem::new())
+        .with_system(FinanceSystem::new())
+        .with_system(PolicySystem::new())
+        .with_system(TechnologySystem::new())
+        .with_system(BookkeepingSystem::new())
+}
+
+fn with_bank(configure: impl FnOnce(&mut ScenarioCentralBank)) -> Scenario {
+    let mut scenario = scenario_loader().load(scenario_path()).unwrap();
+    let mut bank = ScenarioCentralBank {
+        name: "Island Reserve".into(),
+        currency: None,
+        members: Vec::new(),
+        initial_rate: Some(0.03),
+        neutral_rate: 0.02,
+        inflation_target: 0.02,
+        inflation_weight: 0.5,
+        gap_weight: 0.5,
+        gap_measure: GapMeasure::Unemployment,
+        target_unemployment_rate: 0.07,
+        effective_lower_bound: 0.0,
+        smoothing: 0.0,
+        decision_interval_ticks: 30,
+        forward_guidance: None,
+    };
+    configure(&mut bank);
+    scenario.central_banks.push(bank);
+    scenario
+}
+
+fn decisions(world: &World) -> usize {
+    world
+        .events()
+        .iter()
+        .filter(|e| e.kind == EventKind::PolicyRateDecision)
+        .count()
+}
+
+#[test]
+fn decisions_are_logged_and_propagated_to_members() {
+    let scenario = with_bank(|_| {});
+    let mut world = scenario.build_world();
+    let mut engine = build_engine(scenario.seed).build();
+    engine.run(&mut world, 30).unwrap();
+    assert_eq!(decisions(&world), 0);
+    engine.run(&mut world, 1).unwrap();
+    assert_eq!(decisions(&world), 1);
+    let snapshot = world.snapshot("tiny_island");
+    assert_eq!(snapshot.events.len(), 1);
+    assert_eq!(snapshot.central_banks.len(), 1);
+
+    engine.run(&mut world, 40).unwrap();
+    assert_eq!(decisions(&world), 2);
+    let bank = &world.central_banks()[0];
+    assert_eq!(bank.members.len(), 3);
+    for id in &bank.members {
+        assert_eq!(world.finance(*id).unwrap().policy_rate, bank.policy_rate);
+    }
+    assert!(
+        bank.gap < 0.0,
+        "the fixture runs above its unemployment target"
+    );
+}
+
+#[test]
+fn taylor_rule_responds_to_inflation_and_respects_the_lower_bound() {
+    let world = with_bank(|_| {}).build_world();
+    let mut bank = world.central_banks()[0].clone();
+    bank.gap = 0.0;
+    bank.inflation = 0.06;
+    let hawkish = monetary::decide(&bank, 100);
+    assert!((hawkish - (0.02 + 0.06 + 0.5 * 0.04)).abs() < 1e-12);
+
+    bank.inflation = -0.03;
+    bank.gap = -0.08;
+    assert_eq!(monetary::decide(&bank, 100), 0.0);
+    bank.effective_lower_bound = -0.005;
+    assert_eq!(monetary::decide(&bank, 100), -0.005);
+
+    bank.smoothing = 0.5;
+    bank.policy_rate = 0.04;
+    bank.effective_lower_bound = -1.0;
+    let target = monetary::taylor_rate(&bank);
+    assert!((monetary::decide(&bank, 100) - (0.02 + 0.5 * target)).abs() < 1e-12);
+}
+
+#[test]
+fn forward_guidance_holds_the_rate_until_the_announced_tick() {
+    let scenario = with_bank(|bank| {
+        bank.decision_interval_ticks = 10;
+        bank.forward_guidance = Some(ScenarioForwardGuidance {
+            until_tick: 35,
+            rate: 0.045,
+        });
+    });
+    let mut world = scenario.build_world();
+    let mut engine = build_engine(scenario.seed).build();
+    engine.run(&mut world, 31).unwrap();
+    assert_eq!(decisions(&world), 3);
+    assert_eq!(world.central_banks()[0].policy_rate, 0.045);
+    engine.run(&mut world, 10).unwrap();
+    assert_ne!(world.central_banks()[0].policy_rate, 0.045);
+    let id = world.entity_ids()[0];
+    assert_eq!(
+        world.finance(id).unwrap().policy_rate,
+        world.central_banks()[0].policy_rate
+    );
+}
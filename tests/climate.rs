@@ -0,0 +1,142 @@
+use chrono::NaiveDate;
+use panarchy::{
+    engine::{EngineBuilder, EngineSettings},
+    scenario::{Scenario, ScenarioLoader},
+    systems::EnvironmentSystem,
+    weather,
+};
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+fn scenario_loader() -> ScenarioLoader {
+    ScenarioLoader::new(env!("CARGO_MANIFEST_DIR"))
+}
+
+fn scenario_path() -> std::path::PathBuf {
+    std::path::PathBuf::from("scenarios/tiny_island.yaml")
+}
+
+fn build_engine(seed: u64) -> EngineBuilder {
+    let settings = EngineSettings {
+        scenario_name: "tiny_island".into(),
+        seed,
+        snapshot_interval_ticks: 0,
+        snapshot_dir: std::path::PathBuf::from("snapshots_climate_tests"),
+    };
+    EngineBuilder::new(settings).with_system(EnvironmentSystem::new())
+}
+
+fn with_climate() -> Scenario {
+    scenario_loader().load(scenario_path()).unwrap()
+}
+
+fn starting(month: u32, day: u32) -> Scenario {
+    let mut scenario = with_climate();
+    scenario.start_date = NaiveDate::from_ymd_opt(2025, month, day).unwrap();
+    scenario
+}
+
+#[test]
+fn calendar_maps_ticks_to_dates() {
+    let scenario = starting(6, 30);
+    let mut world = scenario.build_world();
+    assert_eq!(world.day_of_year(), 180.0);
+    let mut engine = build_engine(scenario.seed).build();
+    engine.run(&mut world, 2).unwrap();
+    assert_eq!(world.date(), NaiveDate::from_ymd_opt(2025, 7, 2).unwrap());
+    assert_eq!(world.day_of_year(), 182.0);
+    assert_eq!(world.snapshot("tiny_island").date, world.date());
+
+    let scenario = starting(12, 31);
+    let mut world = scenario.build_world();
+    engine.run(&mut world, 1).unwrap();
+    assert_eq!(world.date(), NaiveDate::from_ymd_opt(2026, 1, 1).unwrap());
+    assert_eq!(world.day_of_year(), 0.0);
+}
+
+#[test]
+fn seasons_shape_yields_and_heating() {
+    let scenario = with_climate();
+    let world = scenario.build_world();
+    let base = world.weather(world.entity_ids()[0]).unwrap().clone();
+    let mut conditions = base.clone();
+    let mut rng = ChaCha8Rng::seed_from_u64(5);
+    let (mut temperature, mut crop_yield, mut heating) = (Vec::new(), Vec::new(), Vec::new());
+    for day in 0..365 {
+        weather::advance(&mut conditions, &mut rng, day as f64, 1.0);
+        assert!(conditions.precipitation >= 0.0);
+        assert!(conditions.crop_yield >= 0.0);
+        assert!(conditions.heating_demand > 0.0);
+        temperature.push(conditions.temperature);
+        crop_yield.push(conditions.crop_yield);
+        heating.push(conditions.heating_demand);
+    }
+    let mean = |days: &[f64]| days.iter().sum::<f64>() / days.len() as f64;
+    assert!(mean(&temperature[180..220]) > mean(&temperature[0..40]) + 10.0);
+    assert!(mean(&crop_yield[180..220]) > mean(&crop_yield[0..40]));
+    assert!(mean(&heating[0..40]) > 1.0, "winters need heating");
+    assert!(mean(&heating[180..220]) < mean(&heating[0..40]));
+
+    let mut wet = base.clone();
+    let mut dry = base;
+    dry.precipitation_mean = 0.2;
+    let mut wet_rng = ChaCha8Rng::seed_from_u64(9);
+    let mut dry_rng = ChaCha8Rng::seed_from_u64(9);
+    weather::advance(&mut wet, &mut wet_rng, 200.0, 1.0);
+    weather::advance(&mut dry, &mut dry_rng, 200.0, 1.0);
+    assert!(dry.crop_yield < wet.crop_yield, "drought cuts harvests");
+}
+
+#[test]
+fn default_climate_averages_to_one() {
+    let scenario = with_climate();
+    let world = scenario.build_world();
+    for id in world.entity_ids() {
+        let mut conditions = world.weather(id).unwrap().clone();
+        assert!(conditions.yield_sensitivity > 0.0 && conditions.heating_sensitivity > 0.0);
+        let mut rng = ChaCha8Rng::seed_from_u64(11);
+        let (mut crop_yield, mut heating) = (0.0, 0.0);
+        let days = 365 * 20;
+        for day in 0..days {
+            weather::advance(&mut conditions, &mut rng, (day % 365) as f64, 1.0);
+            crop_yield += conditions.crop_yield;
+            heating += conditions.heating_demand;
+        }
+        let crop_yield = crop_yield / days as f64;
+        let heating = heating / days as f64;
+        assert!(
+            (crop_yield - 1.0).abs() < 0.01,
+            "mean crop yield {crop_yield}"
+        );
+        assert!(
+            (heating - 1.0).abs() < 0.01,
+            "mean heating demand {heating}"
+        );
+    }
+}
+
+#[test]
+fn winter_slows_food_regeneration() {
+    let harvest = |scenario: Scenario| {
+        let mut world = scenario.build_world();
+        let id = world.entity_ids()[0];
+        let before = world.resources(id).unwrap().food;
+        build_engine(scenario.seed)
+            .build()
+            .run(&mut world, 1)
+            .unwrap();
+        let conditions = world.weather(id).unwrap().clone();
+        let snapshot = world.snapshot("tiny_island");
+        assert_eq!(snapshot.regions[0].crop_yield, conditions.crop_yield);
+        (world.resources(id).unwrap().food - before, conditions)
+    };
+    let (winter_food, winter) = harvest(starting(1, 15));
+    let (summer_food, summer) = harvest(starting(7, 20));
+    assert!(winter.temperature < summer.temperature);
+    assert!(winter.heating_demand > summer.heating_demand);
+    assert!(winter_food < summer_food);
+    assert!(
+        (winter_food / summer_food - winter.crop_yield / summer.crop_yield).abs() < 1e-9,
+        "regeneration scales with the crop yield"
+    );
+}
@@ -0,0 +1,275 @@
+use panarchy::{
+    engine::{EngineBuilder, EngineSettings},
+    fx, ledger,
+    scenario::{
+        Scenario, ScenarioCentralBank, ScenarioCurrencyArea, ScenarioForwardGuidance,
+        ScenarioLoader,
+    },
+    systems::{
+        BookkeepingSystem, CentralBankSystem, CurrencySystem, EconomySystem, EnvironmentSystem,
+        FinanceSystem, InfrastructureSystem, InterbankSystem, MigrationSystem, PolicySystem,
+        PopulationSystem, TechnologySystem, TradeSystem,
+    },
+    world::{EventKind, ExchangeRegime, FlowKind, GapMeasure, Sector, World},
+};
+
+fn scenario_loader() -> ScenarioLoader {
+    ScenarioLoader::new(env!("CARGO_MANIFEST_DIR"))
+}
+
+fn scenario_path() -> std::path::PathBuf {
+    std::path::PathBuf::from("scenarios/tiny_island.yaml")
+}
+
+fn build_engine(seed: u64) -> EngineBuilder {
+    let settings = EngineSettings {
+        scenario_name: "tiny_island".into(),
+        seed,
+        snapshot_interval_ticks: 0,
+        snapshot_dir: std::path::PathBuf::from("snapshots_currency_tests"),
+    };
+    EngineBuilder::new(settings)
+        .with_system(EnvironmentSystem::new())
+        .with_system(InfrastructureSystem::new())
+        .with_system(PopulationSystem::new())
+        .with_system(EconomySystem::new())
+        .with_system(CentralBankSystem::new())
+        .with_system(TradeSystem::new())
+        .with_system(CurrencySystem::new())
+        .with_system(FinanceSystem::new())
+        .with_system(InterbankSystem::new())
+        .with_system(PolicySystem::new())
+        .with_system(TechnologySystem::new())
+        .with_system(BookkeepingSystem::new())
+}
+
+fn area(code: &str, regions: &[&str]) -> ScenarioCurrencyArea {
+    ScenarioCurrencyArea {
+        code: code.into(),
+        regions: regions.iter().map(|r| r.to_string()).collect(),
+        regime: ExchangeRegime::Floating,
+        anchor: None,
+        peg: 1.0,
+        initial_rate: 1.0,
+    }
+}
+
+fn bank(name: &str, currency: &str, initial_rate: f64) -> ScenarioCentralBank {
+    ScenarioCentralBank {
+        name: name.into(),
+        currency: Some(currency.into()),
+        members: Vec::new(),
+        initial_rate: Some(initial_rate),
+        neutral_rate: 0.02,
+        inflation_target: 0.02,
+        inflation_weight: 0.5,
+        gap_weight: 0.5,
+        gap_measure: GapMeasure::Unemployment,
+        target_unemployment_rate: 0.07,
+        effective_lower_bound: 0.0,
+        smoothing: 0.0,
+        decision_interval_ticks: 30,
+        forward_guidance: None,
+    }
+}
+
+fn with_areas(areas: Vec<ScenarioCurrencyArea>) -> Scenario {
+    let mut scenario = scenario_loader().load(scenario_path()).unwrap();
+    scenario.currencies.areas = areas;
+    scenario
+}
+
+fn run(scenario: &Scenario, world: &mut World, ticks: u64) {
+    build_engine(scenario.seed)
+        .build()
+        .run(world, ticks)
+        .unwrap();
+}
+
+#[test]
+fn currency_areas_convert_trade_between_regions() {
+    let single = scenario_loader().load(scenario_path()).unwrap();
+    let mut world = single.build_world();
+    run(&single, &mut world, 5);
+    let snapshot = world.snapshot("tiny_island");
+    assert!(snapshot.fx.currencies.is_empty());
+    assert!(snapshot
+        .regions
+        .iter()
+        .all(|r| r.currency.is_none() && r.exchange_rate == 1.0 && r.exports == 0.0));
+
+    let mut cheap = area("ATL", &["Research Atoll"]);
+    cheap.initial_rate = 0.5;
+    let mut scenario = with_areas(vec![area("HBR", &["Harbor Town"]), cheap]);
+    scenario.currencies.trade_sensitivity = 0.0;
+    scenario.currencies.interest_sensitivity = 0.0;
+    let mut world = scenario.build_world();
+    let ids = world.entity_ids();
+    assert_eq!(
+        world.fx().currencies[0].members.len(),
+        2,
+        "unlisted regions join the first area"
+    );
+    run(&scenario, &mut world, 5);
+
+    let snapshot = world.snapshot("tiny_island");
+    let atoll = snapshot
+        .regions
+        .iter()
+        .find(|r| r.name == "Research Atoll")
+        .unwrap();
+    assert_eq!(atoll.currency.as_deref(), Some("ATL"));
+    assert!(
+        atoll.exports > 0.0,
+        "a cheap currency makes exports competitive"
+    );
+    let in_base = |f: &dyn Fn(&panarchy::world::RegionSnapshot) -> f64| {
+        snapshot
+            .regions
+            .iter()
+            .map(|r| f(r) * r.exchange_rate)
+            .sum::<f64>()
+    };
+    let exports = in_base(&|r| r.exports);
+    assert!((exports - in_base(&|r| r.imports)).abs() < 1e-6 * exports);
+    let posted: f64 = world
+        .ledger()
+        .entries
+        .iter()
+        .filter(|e| e.kind == FlowKind::Exports)
+        .map(|e| e.amount)
+        .sum();
+    assert!((posted - snapshot.regions.iter().map(|r| r.exports).sum::<f64>()).abs() < 1e-6);
+    assert!(
+        ledger::balance(world.ledger(), Sector::RestOfWorld).abs() < 1e-6 * exports,
+        "converted into the base currency, exports and imports cancel"
+    );
+    assert!(fx::convert(world.fx(), ids[0], ids[2], 1.0) > 1.9);
+}
+
+#[test]
+fn floating_rates_follow_interest_differentials_and_trade() {
+    let scenario = with_areas(vec![
+        area("HBR", &["Harbor Town", "Research Atoll"]),
+        area("HGL", &["Highlands"]),
+    ]);
+    let mut calm = scenario.build_world();
+    let mut tight = scenario.build_world();
+    let highlands = tight.entity_ids()[1];
+    tight.finance_mut(highlands).unwrap().policy_rate += 0.05;
+    run(&scenario, &mut calm, 30);
+    run(&scenario, &mut tight, 30);
+
+    let rate = |w: &World| w.fx().currencies[1].rate;
+    assert!(rate(&tight) > rate(&calm));
+    assert!(tight.fx().currencies[1].interest_differential > 0.04);
+    assert_eq!(
+        calm.fx().currencies[0].rate,
+        1.0,
+        "the first area is the numeraire"
+    );
+    let bound = scenario.currencies.max_daily_change * 30.0;
+    assert!((rate(&tight).ln()).abs() <= bound + 1e-12);
+
+    let currency = &calm.fx().currencies[1];
+    let drift = fx::drift(
+        calm.fx(),
+        currency,
+        calm.fx().currencies[0].policy_rate,
+        1.0,
+    );
+    let expected = scenario.currencies.trade_sensitivity * currency.trade_balance / currency.output
+        + scenario.currencies.interest_sensitivity * currency.interest_differential;
+    assert!((drift - expected / 365.0).abs() < 1e-12);
+}
+
+#[test]
+fn pegs_hold_and_their_central_bank_follows_the_anchor() {
+    let mut pegged = area("ATL", &["Research Atoll"]);
+    pegged.regime = ExchangeRegime::Pegged;
+    pegged.anchor = Some("HGL".into());
+    pegged.peg = 0.8;
+    let mut scenario = with_areas(vec![
+        area("HBR", &["Harbor Town"]),
+        area("HGL", &["Highlands"]),
+        pegged,
+    ]);
+    scenario.currencies.history_ticks = 10;
+    scenario.interbank.enabled = true;
+    let mut anchor = bank("Highland Bank", "HGL", 0.04);
+    anchor.forward_guidance = Some(ScenarioForwardGuidance {
+        until_tick: 100,
+        rate: 0.06,
+    });
+    scenario.central_banks = vec![bank("Atoll Board", "ATL", 0.01), anchor];
+    let mut world = scenario.build_world();
+    let ids = world.entity_ids();
+    assert_eq!(world.central_banks()[0].members, vec![ids[2]]);
+    assert_eq!(world.central_banks()[1].members, vec![ids[1]]);
+    run(&scenario, &mut world, 25);
+
+    let currencies = &world.fx().currencies;
+    assert!((currencies[2].rate - 0.8 * currencies[1].rate).abs() < 1e-12);
+    assert_ne!(currencies[1].rate, 1.0);
+    assert_eq!(world.central_banks()[1].policy_rate, 0.04);
+    assert_eq!(world.central_banks()[0].policy_rate, 0.04);
+    assert_eq!(world.finance(ids[2]).unwrap().policy_rate, 0.04);
+    assert_eq!(currencies[2].policy_rate, currencies[1].policy_rate);
+    run(&scenario, &mut world, 6);
+    assert_eq!(world.finance(ids[1]).unwrap().policy_rate, 0.06);
+    assert_eq!(
+        world.finance(ids[2]).unwrap().policy_rate,
+        0.06,
+        "the pegged area's central bank follows the anchor's decision"
+    );
+    assert_eq!(
+        world
+            .events()
+            .iter()
+            .filter(|e| e.kind == EventKind::PolicyRateDecision)
+            .count(),
+        3,
+        "the pegged bank logs its initial move to the anchor and each decision"
+    );
+    let currencies = &world.fx().currencies;
+    assert!(currencies[2].reserves != 0.0);
+
+    let snapshot = world.snapshot("tiny_island");
+    let history = &snapshot.fx.currencies[2].history;
+    assert_eq!(history.len(), 10);
+    assert_eq!(history.last().unwrap().tick, 30);
+    assert!(history
+        .iter()
+        .zip(&snapshot.fx.currencies[1].history)
+        .all(|(p, a)| (p.rate - 0.8 * a.rate).abs() < 1e-12));
+}
+
+#[test]
+fn migrants_compare_wages_in_a_common_currency() {
+    let inflow = |atoll_rate: f64| {
+        let mut cheap = area("ATL", &["Research Atoll"]);
+        cheap.initial_rate = atoll_rate;
+        let scenario = with_areas(vec![area("HBR", &["Harbor Town"]), cheap]);
+        let mut world = scenario.build_world();
+        let atoll = world.entity_ids()[2];
+        world.economy_mut(atoll).unwrap().wage *= 2.0;
+        build_engine(scenario.seed)
+            .with_system(MigrationSystem::new())
+            .build()
+            .run(&mut world, 1)
+            .unwrap();
+        world
+            .migration()
+            .flows
+            .iter()
+            .filter(|f| f.to == atoll.raw())
+            .map(|f| f.people)
+            .sum::<f64>()
+    };
+    assert!(inflow(1.0) > 0.0, "a higher wage attracts migrants");
+    assert_eq!(
+        inflow(0.25),
+        0.0,
+        "twice the wage in a currency worth a quarter is a pay cut"
+    );
+}
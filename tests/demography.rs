@@ -0,0 +1,157 @@
+use panarchy::{
+    engine::{EngineBuilder, EngineSettings},
+    scenario::ScenarioLoader,
+    systems::{
+        BookkeepingSystem, EconomySystem, EnvironmentSystem, FinanceSystem, InfrastructureSystem,
+        PolicySystem, PopulationSystem, TechnologySystem,
+    },
+    world::DemographicEvents,
+};
+
+fn scenario_loader() -> ScenarioLoader {
+    ScenarioLoader::new(env!("CARGO_MANIFEST_DIR"))
+}
+
+fn scenario_path() -> std::path::PathBuf {
+    std::path::PathBuf::from("scenarios/tiny_island.yaml")
+}
+
+fn build_engine(seed: u64) -> EngineBuilder {
+    let settings = EngineSettings {
+        scenario_name: "tiny_island".into(),
+        seed,
+        snapshot_interval_ticks: 0,
+        snapshot_dir: std::path::PathBuf::from("snapshots_demography_tests"),
+    };
+    EngineBuilder::new(settings)
+        .with_system(EnvironmentSystem::new())
+        .with_system(InfrastructureSystem::new())
+        .with_system(PopulationSystem::new())
+        .with_system(EconomySystem::new())
+        .with_system(FinanceSystem::new())
+        .with_system(PolicySystem::new())
+        .with_system(TechnologySystem::new())
+        .with_system(BookkeepingSystem::new())
+}
+
+#[test]
+fn age_pyramid_is_exported_and_sums_to_citizens() {
+    let loader = scenario_loader();
+    let scenario = loader.load(scenario_path()).unwrap();
+    let mut world = scenario.build_world();
+    let mut engine = build_engine(scenario.seed).build();
+    engine.run(&mut world, 5).unwrap();
+    let snapshot = world.snapshot("tiny_island");
+    for region in &snapshot.regions {
+        assert_eq!(region.age_pyramid.len(), 17);
+        let total: f64 = region.age_pyramid.iter().map(|c| c.population).sum();
+        assert!((total - region.citizens as f64).abs() <= 1.0);
+        assert!(region.labour_force < region.citizens);
+        assert!(region.employed <= region.labour_force);
+        assert!(region.dependency_ratio > 0.0);
+    }
+}
+
+#[test]
+fn births_come_from_fertile_cohorts_and_deaths_from_the_old() {
+    let loader = scenario_loader();
+    let scenario = loader.load(scenario_path()).unwrap();
+    let mut world = scenario.build_world();
+    let id = world.entity_ids()[0];
+    if let Some(demography) = world.demography_mut(id) {
+        for cohort in demography.cohorts.iter_mut() {
+            if cohort.min_age < 65 {
+                cohort.population = 0.0;
+            }
+        }
+    }
+    let mut engine = build_engine(scenario.seed).build();
+    engine.run(&mut world, 30).unwrap();
+    let demography = world.demography(id).expect("demography exists");
+    assert_eq!(demography.births, 0.0, "an elderly region has no births");
+    assert!(demography.deaths > 0.0);
+    assert_eq!(demography.labour_force, 0.0);
+    assert_eq!(world.population(id).unwrap().employed, 0);
+}
+
+#[test]
+fn cohorts_age_into_the_next_band() {
+    let loader = scenario_loader();
+    let mut scenario = loader.load(scenario_path()).unwrap();
+    for region in scenario.regions.iter_mut() {
+        region.age_structure.calibrate_to_crude_rates = false;
+        for cohort in region.age_structure.cohorts.iter_mut() {
+            cohort.fertility = 0.0;
+            cohort.mortality = 0.0;
+        }
+    }
+    let mut world = scenario.build_world();
+    let id = world.entity_ids()[0];
+    let before: Vec<f64> = world
+        .demography(id)
+        .unwrap()
+        .cohorts
+        .iter()
+        .map(|c| c.population)
+        .collect();
+    let mut engine = build_engine(scenario.seed).build();
+    engine.run(&mut world, 365).unwrap();
+    let after: Vec<f64> = world
+        .demography(id)
+        .unwrap()
+        .cohorts
+        .iter()
+        .map(|c| c.population)
+        .collect();
+    let total_before: f64 = before.iter().sum();
+    let total_after: f64 = after.iter().sum();
+    assert!((total_before - total_after).abs() < 1e-6 * total_before);
+    assert!(after[0] < before[0], "the youngest band should empty out");
+    assert!(
+        after[16] > before[16],
+        "the open-ended band should accumulate survivors"
+    );
+}
+
+#[test]
+fn small_regions_keep_fractional_births_in_deterministic_mode() {
+    let loader = scenario_loader();
+    let mut scenario = loader.load(scenario_path()).unwrap();
+    scenario.regions[0].citizens = 3000;
+    let mut world = scenario.build_world();
+    let id = world.entity_ids()[0];
+    let mut engine = build_engine(scenario.seed).build();
+    engine.run(&mut world, 1).unwrap();
+    let births = world.demography(id).unwrap().births;
+    assert!(
+        births > 0.0 && births < 1.0,
+        "expected births should not round away ({births})"
+    );
+}
+
+#[test]
+fn stochastic_mode_draws_whole_events_reproducibly() {
+    let loader = scenario_loader();
+    let mut scenario = loader.load(scenario_path()).unwrap();
+    scenario.demographic_events = DemographicEvents::Stochastic;
+    let run = |seed: u64| {
+        let mut world = scenario.build_world();
+        let mut engine = build_engine(seed).build();
+        let mut births = Vec::new();
+        for _ in 0..20 {
+            engine.run(&mut world, 1).unwrap();
+            births.extend(
+                world
+                    .entity_ids()
+                    .into_iter()
+                    .map(|id| world.demography(id).unwrap().births),
+            );
+        }
+        births
+    };
+    let first = run(scenario.seed);
+    assert!(first.iter().all(|b| b.fract() == 0.0));
+    assert!(first.iter().any(|b| *b > 0.0));
+    assert_eq!(first, run(scenario.seed));
+    assert_ne!(first, run(scenario.seed + 1));
+}
@@ -0,0 +1,230 @@
+use panarchy::{
+    deposits,
+    engine::{EngineBuilder, EngineSettings},
+    scenario::{Scenario, ScenarioDeposit, ScenarioLoader},
+    systems::{
+        BookkeepingSystem, EconomySystem, EnvironmentSystem, FinanceSystem, InfrastructureSystem,
+        PolicySystem, PopulationSystem, TechnologySystem,
+    },
+    world::{DepositKind, Good, ResourceDeposit},
+};
+
+fn scenario_loader() -> ScenarioLoader {
+    ScenarioLoader::new(env!("CARGO_MANIFEST_DIR"))
+}
+
+fn scenario_path() -> std::path::PathBuf {
+    std::path::PathBuf::from("scenarios/tiny_island.yaml")
+}
+
+fn build_engine(seed: u64) -> EngineBuilder {
+    let settings = EngineSettings {
+        scenario_name: "tiny_island".into(),
+        seed,
+        snapshot_interval_ticks: 0,
+        snapshot_dir: std::path::PathBuf::from("snapshots_deposits_tests"),
+    };
+    EngineBuilder::new(settings)
+        .with_system(EnvironmentSystem::new())
+        .with_system(InfrastructureSystem::new())
+        .with_system(PopulationSystem::new())
+        .with_system(EconomySystem::new())
+        .with_system(FinanceSystem::new())
+        .with_system(PolicySystem::new())
+        .with_system(TechnologySystem::new())
+        .with_system(BookkeepingSystem::new())
+}
+
+fn deposit(name: &str, kind: DepositKind, stock: f64, regen_rate: f64) -> ScenarioDeposit {
+    ScenarioDeposit {
+        name: name.into(),
+        kind,
+        stock,
+        carrying_capacity: None,
+        regen_rate: Some(regen_rate),
+        quality_elasticity: 0.5,
+    }
+}
+
+fn with_deposits(reserves: Vec<ScenarioDeposit>) -> Scenario {
+    let mut scenario = scenario_loader().load(scenario_path()).unwrap();
+    scenario.regions[0].deposits = reserves;
+    scenario
+}
+
+fn stock(kind: DepositKind, remaining: f64, regen_rate: f64) -> ResourceDeposit {
+    ResourceDeposit {
+        name: "Deposit".into(),
+        kind,
+        initial: 1_000.0,
+        remaining,
+        carrying_capacity: 1_000.0,
+        regen_rate,
+        quality_elasticity: 0.5,
+        quality: 1.0,
+        extracted: 0.0,
+        last_extraction: 0.0,
+    }
+}
+
+#[test]
+fn deposits_regrow_logistically_and_lose_quality() {
+    let mut reserves = vec![
+        stock(DepositKind::Fish, 100.0, 3.65),
+        stock(DepositKind::Fish, 500.0, 3.65),
+        stock(DepositKind::Fuel, 500.0, 0.0),
+    ];
+    deposits::regenerate(&mut reserves, 1.0);
+    assert!((reserves[0].remaining - 100.9).abs() < 1e-9);
+    assert!((reserves[1].remaining - 502.5).abs() < 1e-9);
+    assert_eq!(reserves[2].remaining, 500.0, "fuel never regrows");
+    assert!((reserves[2].quality - 0.5f64.sqrt()).abs() < 1e-9);
+    for _ in 0..5_000 {
+        deposits::regenerate(&mut reserves, 1.0);
+    }
+    assert!(reserves[0].remaining <= 1_000.0 && reserves[0].remaining > 999.0);
+
+    assert_eq!(deposits::extract(&mut reserves, Good::Energy, 200.0), 200.0);
+    assert_eq!(reserves[2].remaining, 300.0);
+    assert_eq!(deposits::extract(&mut reserves, Good::Energy, 900.0), 300.0);
+    assert_eq!(reserves[2].quality, 0.0);
+    assert_eq!(
+        deposits::yield_factor(&reserves, Good::Energy),
+        Some(0.0),
+        "an exhausted deposit yields nothing"
+    );
+    assert!((deposits::extracted_share(&reserves) - 500.0 / 3_000.0).abs() < 1e-9);
+
+    let fuel_only = vec![stock(DepositKind::Fuel, 10.0, 0.0)];
+    let mut unlimited = fuel_only.clone();
+    assert_eq!(deposits::extract(&mut unlimited, Good::Food, 75.0), 75.0);
+    assert_eq!(deposits::yield_factor(&fuel_only, Good::Food), None);
+}
+
+#[test]
+fn production_draws_down_deposits_instead_of_free_regen() {
+    let scenario = with_deposits(vec![
+        deposit("Valley soils", DepositKind::Soil, 5_000_000.0, 0.2),
+        deposit("Harbor fishery", DepositKind::Fish, 2_000_000.0, 0.5),
+    ]);
+    let mut world = scenario.build_world();
+    let id = world.entity_ids()[0];
+    let food_before = world.resources(id).unwrap().food;
+    let energy_before = world.resources(id).unwrap().energy;
+    EngineBuilder::new(EngineSettings {
+        scenario_name: "tiny_island".into(),
+        seed: scenario.seed,
+        snapshot_interval_ticks: 0,
+        snapshot_dir: std::path::PathBuf::from("snapshots_deposits_tests"),
+    })
+    .with_system(EnvironmentSystem::new())
+    .build()
+    .run(&mut world, 1)
+    .unwrap();
+    assert_eq!(
+        world.resources(id).unwrap().food,
+        food_before,
+        "deposit regions get no free food"
+    );
+    assert!(world.resources(id).unwrap().energy > energy_before);
+
+    let mut world = scenario.build_world();
+    build_engine(scenario.seed)
+        .build()
+        .run(&mut world, 30)
+        .unwrap();
+    let reserves = world.deposits(id).unwrap();
+    assert!(reserves.iter().all(|d| d.extracted > 0.0));
+    assert!(reserves.iter().all(|d| d.quality < 1.0));
+    let snapshot = world.snapshot("tiny_island");
+    let harbor = &snapshot.regions[0];
+    assert_eq!(harbor.deposits.len(), 2);
+    assert!(harbor.extracted_share > 0.0);
+    assert!(
+        (harbor.extracted_share - deposits::extracted_share(reserves)).abs() < 1e-12,
+        "snapshot reports extracted versus initial stock"
+    );
+    assert!(snapshot.regions[1].deposits.is_empty());
+    assert_eq!(snapshot.regions[1].extracted_share, 0.0);
+}
+
+#[test]
+fn exhausted_fuel_starves_energy_production() {
+    let mut scenario = with_deposits(vec![deposit(
+        "Harbor coalfield",
+        DepositKind::Fuel,
+        400_000.0,
+        0.0,
+    )]);
+    scenario.regions[0].infrastructure.power_plants.clear();
+    let mut world = scenario.build_world();
+    let id = world.entity_ids()[0];
+    let mut engine = build_engine(scenario.seed).build();
+    let mut remaining = f64::INFINITY;
+    let mut shortage = 0.0f64;
+    for _ in 0..120 {
+        engine.run(&mut world, 1).unwrap();
+        let fuel = &world.deposits(id).unwrap()[0];
+        assert!(fuel.remaining <= remaining, "fuel only ever runs down");
+        remaining = fuel.remaining;
+        shortage = shortage.max(world.economy(id).unwrap().energy_shortage_ratio);
+    }
+    let fuel = &world.deposits(id).unwrap()[0];
+    assert!(fuel.remaining < 1.0, "the coalfield is worked out");
+    assert!((fuel.extracted - 400_000.0).abs() < 1.0);
+    assert!(world.snapshot("tiny_island").regions[0].extracted_share > 0.99);
+    assert!(shortage > 0.0, "energy runs short once fuel is gone");
+}
+
+#[test]
+fn only_fuel_burning_plants_draw_on_fuel_reserves() {
+    let mut gasfield = deposit("Harbor gasfield", DepositKind::Fuel, 40_000.0, 0.0);
+    gasfield.quality_elasticity = 0.0;
+    let scenario = with_deposits(vec![gasfield]);
+    let mut world = scenario.build_world();
+    let id = world.entity_ids()[0];
+    let mut engine = build_engine(scenario.seed).build();
+    let mut hydro = 0.0;
+    for _ in 0..60 {
+        engine.run(&mut world, 1).unwrap();
+        let infra = world.infrastructure(id).unwrap();
+        let burned: f64 = infra.plants.iter().map(|p| p.fuel_used).sum();
+        let fuel = &world.deposits(id).unwrap()[0];
+        assert!((fuel.last_extraction - burned).abs() < 1e-6);
+        hydro += infra.plants[0].output;
+        assert_eq!(infra.plants[0].fuel_used, 0.0);
+    }
+    let fuel = &world.deposits(id).unwrap()[0];
+    assert!(fuel.remaining < 1.0, "the gas plants burn the field out");
+    assert!(world.infrastructure(id).unwrap().plants[1].output < 1e-6);
+    assert!(hydro > 40_000.0, "hydro output never touches the reserve");
+}
+
+#[test]
+fn output_is_capped_by_reserves_before_inputs_are_used() {
+    let mut scenario = with_deposits(vec![deposit(
+        "Valley soils",
+        DepositKind::Soil,
+        1_000.0,
+        0.0,
+    )]);
+    scenario.supply_chain.coefficients = vec![
+        vec![0.0, 0.0, 0.0, 0.08],
+        vec![0.0; 4],
+        vec![0.0; 4],
+        vec![0.0; 4],
+    ];
+    let mut world = scenario.build_world();
+    let id = world.entity_ids()[0];
+    build_engine(scenario.seed)
+        .build()
+        .run(&mut world, 1)
+        .unwrap();
+    let soil = &world.deposits(id).unwrap()[0];
+    assert!((soil.last_extraction - 1_000.0).abs() < 1e-6);
+    let chain = world.supply_chain(id).unwrap();
+    assert!(
+        (chain.intermediate_energy - 0.08 * soil.last_extraction).abs() < 1e-6,
+        "inputs are only used for output the soil can support"
+    );
+}
@@ -0,0 +1,121 @@
+use panarchy::{
+    distribution,
+    engine::{EngineBuilder, EngineSettings},
+    scenario::ScenarioLoader,
+    systems::{
+        BookkeepingSystem, EconomySystem, EnvironmentSystem, FinanceSystem, InfrastructureSystem,
+        PolicySystem, PopulationSystem, TechnologySystem,
+    },
+    world::TaxBracket,
+};
+
+fn scenario_loader() -> ScenarioLoader {
+    ScenarioLoader::new(env!("CARGO_MANIFEST_DIR"))
+}
+
+fn scenario_path() -> std::path::PathBuf {
+    std::path::PathBuf::from("scenarios/tiny_island.yaml")
+}
+
+fn build_engine(seed: u64) -> EngineBuilder {
+    let settings = EngineSettings {
+        scenario_name: "tiny_island".into(),
+        seed,
+        snapshot_interval_ticks: 0,
+        snapshot_dir: std::path::PathBuf::from("snapshots_distribution_tests"),
+    };
+    EngineBuilder::new(settings)
+        .with_system(EnvironmentSystem::new())
+        .with_system(InfrastructureSystem::new())
+        .with_system(PopulationSystem::new())
+        .with_system(EconomySystem::new())
+        .with_system(FinanceSystem::new())
+        .with_system(PolicySystem::new())
+        .with_system(TechnologySystem::new())
+        .with_system(BookkeepingSystem::new())
+}
+
+#[test]
+fn quintile_incomes_produce_inequality_metrics() {
+    let loader = scenario_loader();
+    let scenario = loader.load(scenario_path()).unwrap();
+    let mut world = scenario.build_world();
+    let mut engine = build_engine(scenario.seed).build();
+    engine.run(&mut world, 3).unwrap();
+    let snapshot = world.snapshot("tiny_island");
+    for region in &snapshot.regions {
+        assert_eq!(region.income_groups.len(), 5);
+        assert!(
+            region.gini > 0.1 && region.gini < 1.0,
+            "gini {} out of range",
+            region.gini
+        );
+        assert!(region.palma > 0.0, "palma ratio should be tracked");
+        assert!(region.poverty_rate > 0.0 && region.poverty_rate < 1.0);
+    }
+}
+
+#[test]
+fn progressive_brackets_collect_revenue_and_compress_incomes() {
+    let loader = scenario_loader();
+    let scenario = loader.load(scenario_path()).unwrap();
+    let mut baseline = scenario.build_world();
+    let mut taxed = scenario.build_world();
+    for id in taxed.entity_ids() {
+        if let Some(policy) = taxed.policy_mut(id) {
+            policy.tax_brackets = vec![
+                TaxBracket {
+                    threshold: 0.0,
+                    rate: 0.0,
+                },
+                TaxBracket {
+                    threshold: 60.0,
+                    rate: 0.3,
+                },
+                TaxBracket {
+                    threshold: 150.0,
+                    rate: 0.55,
+                },
+            ];
+        }
+    }
+    build_engine(scenario.seed)
+        .build()
+        .run(&mut baseline, 1)
+        .unwrap();
+    build_engine(scenario.seed)
+        .build()
+        .run(&mut taxed, 1)
+        .unwrap();
+
+    let id = taxed.entity_ids()[0];
+    let policy = taxed.policy(id).unwrap();
+    assert!(
+        policy.last_income_tax > 0.0,
+        "brackets should raise revenue"
+    );
+    let base_gini = baseline.distribution(id).unwrap().gini;
+    let taxed_gini = taxed.distribution(id).unwrap().gini;
+    assert!(
+        taxed_gini < base_gini,
+        "progressive taxes should lower gini ({} vs {})",
+        taxed_gini,
+        base_gini
+    );
+}
+
+#[test]
+fn bracket_tax_is_marginal() {
+    let brackets = [
+        TaxBracket {
+            threshold: 0.0,
+            rate: 0.1,
+        },
+        TaxBracket {
+            threshold: 100.0,
+            rate: 0.5,
+        },
+    ];
+    let tax = distribution::bracket_tax(&brackets, 150.0);
+    assert!((tax - 35.0).abs() < 1e-9);
+}
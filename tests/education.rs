@@ -0,0 +1,154 @@
+use panarchy::{
+    engine::{EngineBuilder, EngineSettings},
+    scenario::{Scenario, ScenarioLoader},
+    systems::{
+        BookkeepingSystem, EconomySystem, EducationSystem, EnvironmentSystem, FinanceSystem,
+        InfrastructureSystem, PolicySystem, PopulationSystem, TechnologySystem,
+    },
+    world::World,
+};
+
+fn scenario_loader() -> ScenarioLoader {
+    ScenarioLoader::new(env!("CARGO_MANIFEST_DIR"))
+}
+
+fn scenario_path() -> std::path::PathBuf {
+    std::path::PathBuf::from("scenarios/tiny_island.yaml")
+}
+
+fn build_engine(seed: u64) -> EngineBuilder {
+    let settings = EngineSettings {
+        scenario_name: "tiny_island".into(),
+        seed,
+        snapshot_interval_ticks: 0,
+        snapshot_dir: std::path::PathBuf::from("snapshots_education_tests"),
+    };
+    EngineBuilder::new(settings)
+        .with_system(EnvironmentSystem::new())
+        .with_system(InfrastructureSystem::new())
+        .with_system(EducationSystem::new())
+        .with_system(PopulationSystem::new())
+        .with_system(EconomySystem::new())
+        .with_system(FinanceSystem::new())
+        .with_system(PolicySystem::new())
+        .with_system(TechnologySystem::new())
+        .with_system(BookkeepingSystem::new())
+}
+
+fn load() -> Scenario {
+    let mut scenario = scenario_loader().load(scenario_path()).unwrap();
+    for region in scenario.regions.iter_mut() {
+        region.education.schooling_years = 0.25;
+        region.education.workforce_turnover_years = 0.5;
+    }
+    scenario
+}
+
+fn attainment(world: &World) -> f64 {
+    let id = world.entity_ids()[0];
+    world.education(id).unwrap().attainment
+}
+
+#[test]
+fn schooling_budget_raises_attainment_with_a_lag() {
+    let scenario = load();
+    let mut funded = scenario.clone();
+    funded.regions[0].policy.education_fraction = 0.4;
+    let mut unfunded = scenario.clone();
+    unfunded.regions[0].policy.education_fraction = 0.0;
+
+    let mut world = funded.build_world();
+    let initial = attainment(&world);
+    let mut engine = build_engine(funded.seed).build();
+    engine.run(&mut world, 5).unwrap();
+    let early = attainment(&world) - initial;
+    engine.run(&mut world, 295).unwrap();
+    let late = attainment(&world) - initial;
+    assert!(
+        early > 0.0 && early < late * 0.1,
+        "skills should respond slowly"
+    );
+
+    let mut starved = unfunded.build_world();
+    let mut engine = build_engine(unfunded.seed).build();
+    engine.run(&mut starved, 300).unwrap();
+    assert!(attainment(&starved) < initial);
+
+    let snapshot = world.snapshot("tiny_island");
+    assert!((snapshot.regions[0].education_attainment - attainment(&world)).abs() < 1e-9);
+    assert!(snapshot.regions[0].skill_index > 1.0);
+    assert!(snapshot.regions[0].education_spending > 0.0);
+}
+
+#[test]
+fn skill_mismatch_creates_structural_unemployment() {
+    let scenario = load();
+    let mut baseline = scenario.build_world();
+    let mut mismatched = scenario.build_world();
+    let id = mismatched.entity_ids()[0];
+    mismatched.education_mut(id).unwrap().required_skill = 1.4;
+
+    let mut engine = build_engine(scenario.seed).build();
+    engine.run(&mut baseline, 3).unwrap();
+    let mut engine = build_engine(scenario.seed).build();
+    engine.run(&mut mismatched, 3).unwrap();
+
+    let education = mismatched.education(id).unwrap();
+    assert!(education.skill_mismatch > 0.2);
+    assert!(education.matching_multiplier < 0.8);
+    let employed = |world: &World| world.population(id).unwrap().employed;
+    assert!(employed(&mismatched) < employed(&baseline));
+}
+
+#[test]
+fn skill_mismatch_slows_matching_while_workers_are_idle() {
+    let mut scenario = load();
+    for region in scenario.regions.iter_mut() {
+        region.economy.food_productivity_per_worker *= 2.0;
+        region.economy.energy_productivity_per_worker *= 2.0;
+        region.economy.target_inventory_days = 1.0;
+    }
+    let mut baseline = scenario.build_world();
+    let mut mismatched = scenario.build_world();
+    let id = mismatched.entity_ids()[0];
+    mismatched.education_mut(id).unwrap().required_skill = 1.4;
+
+    let mut engine = build_engine(scenario.seed).build();
+    engine.run(&mut baseline, 3).unwrap();
+    let mut engine = build_engine(scenario.seed).build();
+    engine.run(&mut mismatched, 3).unwrap();
+
+    let education = mismatched.education(id).unwrap();
+    let population = mismatched.population(id).unwrap();
+    assert!(
+        (population.employed as f64)
+            < population.labour_force as f64 * education.matching_multiplier,
+        "the employable cap is not binding"
+    );
+    let employed = |world: &World| world.population(id).unwrap().employed;
+    assert!(employed(&mismatched) < employed(&baseline));
+}
+
+#[test]
+fn skilled_workers_need_fewer_hands_for_the_same_output() {
+    let scenario = load();
+    let mut baseline = scenario.build_world();
+    let mut skilled = scenario.build_world();
+    let id = skilled.entity_ids()[0];
+    {
+        let education = skilled.education_mut(id).unwrap();
+        education.attainment = education.reference_attainment * 1.3;
+        education.graduate_attainment = education.attainment;
+        education.required_skill = 1.3;
+    }
+
+    let mut engine = build_engine(scenario.seed).build();
+    engine.run(&mut baseline, 1).unwrap();
+    let mut engine = build_engine(scenario.seed).build();
+    engine.run(&mut skilled, 1).unwrap();
+
+    let education = skilled.education(id).unwrap();
+    assert!(education.productivity_multiplier > 1.1);
+    let demand = |world: &World| world.economy(id).unwrap().labor_demand;
+    assert!(demand(&skilled) < demand(&baseline));
+}
@@ -5,6 +5,7 @@ use panarchy::{
         BookkeepingSystem, EconomySystem, EnvironmentSystem, FinanceSystem, InfrastructureSystem,
         PolicySystem, PopulationSystem, TechnologySystem,
     },
+    world::{EventKind, World, WorldSnapshot, EVENT_CAPACITY},
 };
 use tempfile::tempdir;
 
@@ -42,3 +43,49 @@ fn engine_runs_hook_each_tick() {
     assert_eq!(ticks.first().copied(), Some(1));
     assert_eq!(ticks.last().copied(), Some(6));
 }
+
+#[test]
+fn snapshots_carry_every_event_since_the_last_written_snapshot() {
+    let temp = tempdir().expect("tempdir");
+    let settings = EngineSettings {
+        scenario_name: "events".into(),
+        seed: 7,
+        snapshot_interval_ticks: 3,
+        snapshot_dir: temp.path().to_path_buf(),
+    };
+    let mut engine = EngineBuilder::new(settings).build();
+    let mut world = World::new(1.0);
+    let mut snapshots: Vec<WorldSnapshot> = Vec::new();
+    for _ in 0..6 {
+        let tick = world.tick();
+        world.log_event(
+            tick,
+            EventKind::PolicyRateDecision,
+            None,
+            format!("tick {tick}"),
+        );
+        engine
+            .run_with_hook(&mut world, 1, |snapshot| snapshots.push(snapshot))
+            .expect("run succeeds");
+    }
+    let counts: Vec<usize> = snapshots.iter().map(|s| s.events.len()).collect();
+    assert_eq!(counts, vec![1, 2, 3, 1, 2, 3]);
+
+    let written: WorldSnapshot = serde_json::from_slice(
+        &std::fs::read(temp.path().join("events").join("tick_000003.json")).unwrap(),
+    )
+    .unwrap();
+    let ticks: Vec<u64> = written.events.iter().map(|e| e.tick).collect();
+    assert_eq!(ticks, vec![0, 1, 2]);
+}
+
+#[test]
+fn the_event_log_is_bounded() {
+    let mut world = World::new(1.0);
+    for i in 0..EVENT_CAPACITY + 10 {
+        world.log_event(0, EventKind::PolicyRateDecision, None, format!("event {i}"));
+    }
+    assert_eq!(world.events().len(), EVENT_CAPACITY);
+    assert_eq!(world.events().front().unwrap().message, "event 10");
+    assert_eq!(world.snapshot("events").events.len(), EVENT_CAPACITY);
+}
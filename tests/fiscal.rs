@@ -0,0 +1,135 @@
+use panarchy::{
+    engine::{EngineBuilder, EngineSettings},
+    fiscal, lending,
+    scenario::ScenarioLoader,
+    systems::{
+        BookkeepingSystem, EconomySystem, EnvironmentSystem, FinanceSystem, InfrastructureSystem,
+        PolicySystem, PopulationSystem, TechnologySystem,
+    },
+};
+
+fn scenario_loader() -> ScenarioLoader {
+    ScenarioLoader::new(env!("CARGO_MANIFEST_DIR"))
+}
+
+fn scenario_path() -> std::path::PathBuf {
+    std::path::PathBuf::from("scenarios/tiny_island.yaml")
+}
+
+fn build_engine(seed: u64) -> EngineBuilder {
+    let settings = EngineSettings {
+        scenario_name: "tiny_island".into(),
+        seed,
+        snapshot_interval_ticks: 0,
+        snapshot_dir: std::path::PathBuf::from("snapshots_fiscal_tests"),
+    };
+    EngineBuilder::new(settings)
+        .with_system(EnvironmentSystem::new())
+        .with_system(InfrastructureSystem::new())
+        .with_system(PopulationSystem::new())
+        .with_system(EconomySystem::new())
+        .with_system(FinanceSystem::new())
+        .with_system(PolicySystem::new())
+        .with_system(TechnologySystem::new())
+        .with_system(BookkeepingSystem::new())
+}
+
+#[test]
+fn deficits_are_bond_financed_and_the_budget_identity_holds() {
+    let scenario = scenario_loader().load(scenario_path()).unwrap();
+    let mut world = scenario.build_world();
+    let mut engine = build_engine(scenario.seed).build();
+    let ids = world.entity_ids();
+    let mut paid_interest = false;
+    for _ in 0..90 {
+        engine.run(&mut world, 1).unwrap();
+        for id in &ids {
+            let policy = world.policy(*id).unwrap();
+            assert!(fiscal::budget_identity_residual(policy).abs() < 1e-6);
+            assert!(
+                (policy.budget_balance - (policy.primary_balance - policy.last_interest)).abs()
+                    < 1e-6
+            );
+            let bank: f64 = policy.bonds.iter().map(|b| b.bank_holding).sum();
+            let households: f64 = policy.bonds.iter().map(|b| b.household_holding).sum();
+            assert!((bank + households - policy.public_debt).abs() < 1e-6);
+            assert!((world.finance(*id).unwrap().government_bonds - bank).abs() < 1e-6);
+            paid_interest |= policy.last_interest > 0.0;
+        }
+    }
+    assert!(paid_interest);
+    let region = &world.snapshot("tiny_island").regions[0];
+    assert!(region.public_debt > 0.0);
+    assert!(region.bank_bond_holdings > 0.0);
+    assert!(region.household_bond_holdings > 0.0);
+    assert!(region.bond_yield > 0.0);
+}
+
+#[test]
+fn yields_respond_to_debt_to_gdp_and_approval() {
+    let scenario = scenario_loader().load(scenario_path()).unwrap();
+    let world = scenario.build_world();
+    let mut policy = world.policy(world.entity_ids()[0]).unwrap().clone();
+    policy.approval_rating = 0.8;
+    policy.debt_to_gdp = policy.debt_threshold * 0.5;
+    let low_debt = fiscal::sovereign_yield(&policy, 0.02);
+    assert!(
+        (low_debt - (0.02 + policy.term_premium + policy.approval_sensitivity * 0.2)).abs() < 1e-12
+    );
+    policy.debt_to_gdp = policy.debt_threshold + 1.0;
+    let high_debt = fiscal::sovereign_yield(&policy, 0.02);
+    assert!((high_debt - low_debt - policy.debt_sensitivity).abs() < 1e-12);
+    policy.approval_rating = 0.2;
+    assert!(fiscal::sovereign_yield(&policy, 0.02) > high_debt);
+}
+
+#[test]
+fn bonds_mature_and_surpluses_retire_debt() {
+    let scenario = scenario_loader().load(scenario_path()).unwrap();
+    let world = scenario.build_world();
+    let mut policy = world.policy(world.entity_ids()[0]).unwrap().clone();
+    policy.bond_maturity_days = 60.0;
+    policy.bond_yield = 0.04;
+    let sold = fiscal::issue(&mut policy, 1_000.0, 100.0);
+    assert!((sold.households - 100.0).abs() < 1e-9);
+    assert!((sold.bank - 900.0).abs() < 1e-9);
+    assert!((policy.public_debt - 1_000.0).abs() < 1e-9);
+    let coupons = fiscal::coupons(&policy, 365.0);
+    assert!((coupons.total() - 40.0).abs() < 1e-9);
+
+    let bought = fiscal::buy_back(&mut policy, 250.0);
+    assert!((bought.total() - 250.0).abs() < 1e-9);
+    assert!((policy.public_debt - 750.0).abs() < 1e-9);
+
+    let early = fiscal::mature(&mut policy, 30.0);
+    assert_eq!(early.total(), 0.0);
+    let redeemed = fiscal::mature(&mut policy, 30.0);
+    assert!((redeemed.total() - 750.0).abs() < 1e-9);
+    assert!(policy.bonds.is_empty());
+    assert_eq!(policy.public_debt, 0.0);
+}
+
+#[test]
+fn banks_pay_for_the_bonds_they_buy() {
+    let scenario = scenario_loader().load(scenario_path()).unwrap();
+    let mut world = scenario.build_world();
+    let mut engine = build_engine(scenario.seed).build();
+    engine.run(&mut world, 90).unwrap();
+    let id = world.entity_ids()[0];
+    let households = world.household(id).unwrap().deposits;
+    let finance = world.finance(id).unwrap().clone();
+    assert!(finance.government_bonds > 0.0);
+    let mut current = finance.clone();
+    lending::update_ratios(&mut current, households);
+    assert!((current.liquidity_ratio - finance.liquidity_ratio).abs() < 1e-9);
+    let mut redeemed = finance.clone();
+    redeemed.government_bonds = 0.0;
+    let liquidity = lending::liquid_assets(&finance, households);
+    assert!(
+        (lending::liquid_assets(&redeemed, households) - liquidity - finance.government_bonds)
+            .abs()
+            < 1e-6
+    );
+    lending::update_ratios(&mut redeemed, households);
+    assert!(redeemed.liquidity_ratio > finance.liquidity_ratio);
+}
@@ -0,0 +1,145 @@
+use panarchy::{
+    engine::{EngineBuilder, EngineSettings},
+    scenario::{
+        Scenario, ScenarioDisease, ScenarioIntervention, ScenarioLoader, ScenarioOutbreakSeed,
+    },
+    systems::{
+        BookkeepingSystem, EconomySystem, EnvironmentSystem, FinanceSystem, HealthSystem,
+        InfrastructureSystem, MigrationSystem, PolicySystem, PopulationSystem, TechnologySystem,
+    },
+    world::{InterventionKind, World},
+};
+
+fn scenario_loader() -> ScenarioLoader {
+    ScenarioLoader::new(env!("CARGO_MANIFEST_DIR"))
+}
+
+fn scenario_path() -> std::path::PathBuf {
+    std::path::PathBuf::from("scenarios/tiny_island.yaml")
+}
+
+fn build_engine(seed: u64) -> EngineBuilder {
+    let settings = EngineSettings {
+        scenario_name: "tiny_island".into(),
+        seed,
+        snapshot_interval_ticks: 0,
+        snapshot_dir: std::path::PathBuf::from("snapshots_health_tests"),
+    };
+    EngineBuilder::new(settings)
+        .with_system(EnvironmentSystem::new())
+        .with_system(InfrastructureSystem::new())
+        .with_system(PopulationSystem::new())
+        .with_system(MigrationSystem::new())
+        .with_system(HealthSystem::new())
+        .with_system(EconomySystem::new())
+        .with_system(FinanceSystem::new())
+        .with_system(PolicySystem::new())
+        .with_system(TechnologySystem::new())
+        .with_system(BookkeepingSystem::new())
+}
+
+fn outbreak_scenario() -> Scenario {
+    let mut scenario = scenario_loader().load(scenario_path()).unwrap();
+    scenario.health.diseases.push(ScenarioDisease {
+        name: "fever".into(),
+        transmission_rate: 0.5,
+        incubation_days: 3.0,
+        infectious_days: 6.0,
+        fatality_rate: 0.01,
+        hospitalisation_rate: 0.08,
+        seeds: vec![ScenarioOutbreakSeed {
+            region: "Harbor Town".into(),
+            infected: 50.0,
+        }],
+    });
+    scenario
+}
+
+fn run(scenario: &Scenario, ticks: u64) -> World {
+    let mut world = scenario.build_world();
+    let mut engine = build_engine(scenario.seed).build();
+    engine.run(&mut world, ticks).unwrap();
+    world
+}
+
+fn peak(world: &World, region: usize) -> f64 {
+    let id = world.entity_ids()[region];
+    world.health(id).unwrap().epidemics[0].peak_infectious
+}
+
+fn deaths(world: &World) -> f64 {
+    world
+        .entity_ids()
+        .into_iter()
+        .filter_map(|id| world.health(id))
+        .map(|h| h.epidemics[0].cumulative_deaths)
+        .sum()
+}
+
+#[test]
+fn outbreak_spreads_kills_and_keeps_workers_home() {
+    let scenario = outbreak_scenario();
+    let mut world = scenario.build_world();
+    let mut engine = build_engine(scenario.seed).build();
+    let harbor = world.entity_ids()[0];
+    let mut absent = 0.0_f64;
+    for _ in 0..40 {
+        engine.run(&mut world, 1).unwrap();
+        absent = absent.max(world.health(harbor).unwrap().sick_workers);
+    }
+    assert!(peak(&world, 0) > 1000.0, "the seeded outbreak should grow");
+    let imported: f64 = (1..3).map(|region| peak(&world, region)).sum();
+    assert!(imported > 0.0, "migrants should carry the disease onward");
+    assert!(absent > 0.0, "infectious workers should stay home");
+    assert!(deaths(&world) > 0.0);
+
+    let snapshot = world.snapshot("tiny_island");
+    let region = &snapshot.regions[0];
+    assert_eq!(region.epidemics.len(), 1);
+    assert!(region.hospital_beds > 0.0);
+    let pyramid_total: f64 = region.age_pyramid.iter().map(|c| c.population).sum();
+    assert!((pyramid_total - region.citizens as f64).abs() <= 1.0);
+}
+
+#[test]
+fn scheduled_npis_and_vaccination_flatten_the_curve() {
+    let baseline = run(&outbreak_scenario(), 60);
+
+    let mut scenario = outbreak_scenario();
+    scenario.health.interventions = vec![
+        ScenarioIntervention {
+            kind: InterventionKind::Npi,
+            region: None,
+            disease: None,
+            start_tick: 5,
+            duration_ticks: Some(50),
+            strength: 0.6,
+        },
+        ScenarioIntervention {
+            kind: InterventionKind::Vaccination,
+            region: Some("Harbor Town".into()),
+            disease: Some("fever".into()),
+            start_tick: 5,
+            duration_ticks: None,
+            strength: 0.02,
+        },
+    ];
+    let mitigated = run(&scenario, 60);
+    assert!(peak(&mitigated, 0) < peak(&baseline, 0) * 0.5);
+    assert!(deaths(&mitigated) < deaths(&baseline));
+}
+
+#[test]
+fn hospital_overload_raises_fatality() {
+    let ample = run(&outbreak_scenario(), 60);
+
+    let mut scenario = outbreak_scenario();
+    for region in scenario.regions.iter_mut() {
+        region.health.hospital_beds_per_1000 = 0.5;
+        region.policy.health_fraction = 0.0;
+    }
+    let scarce = run(&scenario, 60);
+    let harbor = scarce.entity_ids()[0];
+    assert!(scarce.health(harbor).unwrap().overload_days > 0.0);
+    assert!(deaths(&scarce) > deaths(&ample));
+}
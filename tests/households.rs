@@ -0,0 +1,90 @@
+use panarchy::{
+    engine::{EngineBuilder, EngineSettings},
+    scenario::ScenarioLoader,
+    systems::{
+        BookkeepingSystem, EconomySystem, EnvironmentSystem, FinanceSystem, InfrastructureSystem,
+        PolicySystem, PopulationSystem, TechnologySystem,
+    },
+};
+
+fn scenario_loader() -> ScenarioLoader {
+    ScenarioLoader::new(env!("CARGO_MANIFEST_DIR"))
+}
+
+fn scenario_path() -> std::path::PathBuf {
+    std::path::PathBuf::from("scenarios/tiny_island.yaml")
+}
+
+fn build_engine(seed: u64) -> EngineBuilder {
+    let settings = EngineSettings {
+        scenario_name: "tiny_island".into(),
+        seed,
+        snapshot_interval_ticks: 0,
+        snapshot_dir: std::path::PathBuf::from("snapshots_household_tests"),
+    };
+    EngineBuilder::new(settings)
+        .with_system(EnvironmentSystem::new())
+        .with_system(InfrastructureSystem::new())
+        .with_system(PopulationSystem::new())
+        .with_system(EconomySystem::new())
+        .with_system(FinanceSystem::new())
+        .with_system(PolicySystem::new())
+        .with_system(TechnologySystem::new())
+        .with_system(BookkeepingSystem::new())
+}
+
+#[test]
+fn unspent_income_accumulates_as_household_deposits() {
+    let loader = scenario_loader();
+    let scenario = loader.load(scenario_path()).unwrap();
+    let mut world = scenario.build_world();
+    let id = world
+        .entity_ids()
+        .into_iter()
+        .next()
+        .expect("region exists");
+    let baseline_deposits = world.household(id).expect("household exists").deposits;
+    let mut engine = build_engine(scenario.seed).build();
+    engine.run(&mut world, 5).unwrap();
+    let household = world.household(id).expect("household exists");
+    assert!(
+        household.saving > 0.0,
+        "households should save unspent income"
+    );
+    assert!(
+        household.deposits > baseline_deposits,
+        "savings should build deposits ({} -> {})",
+        baseline_deposits,
+        household.deposits
+    );
+    let snapshot = world.snapshot("tiny_island");
+    let region = snapshot.regions.iter().find(|r| r.id == id.raw()).unwrap();
+    assert!(region.household_wealth > 0.0);
+}
+
+#[test]
+fn consumption_is_smoothed_through_income_shock() {
+    let loader = scenario_loader();
+    let scenario = loader.load(scenario_path()).unwrap();
+    let mut world = scenario.build_world();
+    let id = world
+        .entity_ids()
+        .into_iter()
+        .next()
+        .expect("region exists");
+    let propensity = world.economy(id).unwrap().propensity_to_consume;
+    if let Some(econ) = world.economy_mut(id) {
+        econ.wage = 1.0;
+        econ.basic_income_per_capita = 0.0;
+    }
+    let mut engine = build_engine(scenario.seed).build();
+    engine.run(&mut world, 1).unwrap();
+    let household = world.household(id).expect("household exists");
+    let budget = world.economy(id).unwrap().household_budget;
+    assert!(
+        budget > household.disposable_income * propensity,
+        "budget {} should not collapse with income {}",
+        budget,
+        household.disposable_income
+    );
+}
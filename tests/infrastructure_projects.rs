@@ -0,0 +1,187 @@
+use panarchy::{
+    engine::{EngineBuilder, EngineSettings},
+    projects,
+    scenario::{Scenario, ScenarioAsset, ScenarioLoader},
+    systems::{
+        BookkeepingSystem, EconomySystem, EnvironmentSystem, FinanceSystem, InfrastructureSystem,
+        PolicySystem, PopulationSystem, TechnologySystem,
+    },
+    world::{AssetKind, EventKind, InfrastructureComponent, World},
+};
+
+fn scenario_loader() -> ScenarioLoader {
+    ScenarioLoader::new(env!("CARGO_MANIFEST_DIR"))
+}
+
+fn scenario_path() -> std::path::PathBuf {
+    std::path::PathBuf::from("scenarios/tiny_island.yaml")
+}
+
+fn build_engine(seed: u64) -> EngineBuilder {
+    let settings = EngineSettings {
+        scenario_name: "tiny_island".into(),
+        seed,
+        snapshot_interval_ticks: 0,
+        snapshot_dir: std::path::PathBuf::from("snapshots_infrastructure_projects_tests"),
+    };
+    EngineBuilder::new(settings)
+        .with_system(EnvironmentSystem::new())
+        .with_system(InfrastructureSystem::new())
+        .with_system(PopulationSystem::new())
+        .with_system(EconomySystem::new())
+        .with_system(FinanceSystem::new())
+        .with_system(PolicySystem::new())
+        .with_system(TechnologySystem::new())
+        .with_system(BookkeepingSystem::new())
+}
+
+fn asset(name: &str, kind: AssetKind, capacity: f64, age_years: f64) -> ScenarioAsset {
+    ScenarioAsset {
+        name: name.into(),
+        kind,
+        capacity,
+        age_years,
+        maintenance_cost: Some(1_000.0),
+    }
+}
+
+fn run(scenario: &Scenario, world: &mut World, ticks: u64) {
+    build_engine(scenario.seed)
+        .build()
+        .run(world, ticks)
+        .unwrap();
+}
+
+#[test]
+fn capacity_is_the_sum_of_ageing_assets() {
+    let mut scenario = scenario_loader().load(scenario_path()).unwrap();
+    let world = scenario.build_world();
+    let harbor = world.infrastructure(world.entity_ids()[0]).unwrap();
+    assert_eq!(harbor.assets.len(), harbor.plants.len() + 1);
+    assert_eq!(harbor.power_capacity, harbor.nameplate_capacity);
+    assert_eq!(
+        harbor.transport_capacity,
+        scenario.regions[0].infrastructure.transport_capacity
+    );
+    let upkeep: f64 = harbor.assets.iter().map(|a| a.maintenance_cost).sum();
+    assert!((upkeep - scenario.regions[0].infrastructure.maintenance_cost).abs() < 1e-6);
+
+    scenario.regions[1].infrastructure.assets = vec![
+        asset("New Wind", AssetKind::Plant, 30_000.0, 0.0),
+        asset("Old Coal", AssetKind::Plant, 30_000.0, 40.0),
+        asset("Ring Road", AssetKind::Road, 80_000.0, 10.0),
+    ];
+    scenario.regions[1].infrastructure.ageing = 0.1;
+    let mut world = scenario.build_world();
+    let highlands = world.entity_ids()[1];
+    let infra = world.infrastructure(highlands).unwrap();
+    assert_eq!(infra.power_capacity, 60_000.0);
+    assert_eq!(infra.assets[1].maintenance_cost, 5_000.0);
+    run(&scenario, &mut world, 30);
+
+    let infra = world.infrastructure(highlands).unwrap();
+    let (new, old) = (&infra.assets[0], &infra.assets[1]);
+    assert!(old.condition < new.condition, "old vintages wear faster");
+    let upkeep = |age_days: f64| 1_000.0 * (1.0 + 0.1 * (age_days - 1.0) / 365.0);
+    assert!((old.maintenance_cost - upkeep(old.age_days)).abs() < 1e-6);
+    assert!(old.maintenance_cost > 4.9 * new.maintenance_cost);
+    assert_eq!(old.age_days, 40.0 * 365.0 + 30.0);
+    let effective: f64 = infra
+        .assets
+        .iter()
+        .filter(|a| a.kind.is_power())
+        .map(|a| a.capacity * a.condition)
+        .sum();
+    assert!((infra.power_capacity - effective).abs() < 1e-6);
+}
+
+#[test]
+fn projects_take_time_to_build_and_announce_completion() {
+    let mut scenario = scenario_loader().load(scenario_path()).unwrap();
+    for template in scenario.infrastructure_projects.iter_mut() {
+        template.build_days = 10.0;
+    }
+    for region in scenario.regions.iter_mut() {
+        region.policy.public_investment_fraction = 0.0;
+    }
+    let mut world = scenario.build_world();
+    let harbor = world.entity_ids()[0];
+    let plants = world.infrastructure(harbor).unwrap().plants.len();
+    world.infrastructure_mut(harbor).unwrap().pending_investment = 60_000.0;
+    run(&scenario, &mut world, 9);
+
+    let infra = world.infrastructure(harbor).unwrap();
+    let project = &infra.projects[0];
+    assert_eq!(project.template.kind, AssetKind::Plant);
+    assert_eq!(project.funded, 60_000.0);
+    assert_eq!(project.elapsed_days, 9.0);
+    assert_eq!(
+        infra.plants.len(),
+        plants,
+        "nothing is added while building"
+    );
+
+    run(&scenario, &mut world, 1);
+    let infra = world.infrastructure(harbor).unwrap();
+    let built = infra.assets.last().unwrap();
+    assert_eq!(built.name, "Gas plant");
+    assert_eq!(built.built_tick, 9);
+    assert_eq!(built.cost, 60_000.0);
+    assert_eq!(infra.plants.len(), plants + 1);
+    assert_eq!(infra.nameplate_capacity, 80_000.0);
+    let plant = infra.plants.last().unwrap();
+    assert_eq!(plant.fuel_per_unit, 0.2);
+    assert_eq!(plant.emissions_per_unit, 0.4);
+    let snapshot = world.snapshot("tiny_island");
+    let event = snapshot
+        .events
+        .iter()
+        .find(|e| e.kind == EventKind::ProjectCompleted)
+        .unwrap();
+    assert_eq!(event.region, Some(harbor.raw()));
+    assert!(event.message.contains("Gas plant"));
+    assert!(snapshot.regions[0]
+        .infrastructure_assets
+        .iter()
+        .any(|a| a.built_tick == 9));
+}
+
+#[test]
+fn policy_priorities_and_shortages_pick_the_next_project() {
+    let scenario = scenario_loader().load(scenario_path()).unwrap();
+    let world = scenario.build_world();
+    let mut infra = world.infrastructure(world.entity_ids()[0]).unwrap().clone();
+
+    let kind = |infra: &InfrastructureComponent, priority, energy, transport| {
+        projects::choose(infra, priority, energy, transport)
+            .unwrap()
+            .kind
+    };
+    assert_eq!(kind(&infra, None, 0.0, 0.0), AssetKind::Plant);
+    assert_eq!(kind(&infra, None, 0.0, 0.2), AssetKind::Road);
+    assert_eq!(
+        kind(&infra, Some(AssetKind::Port), 0.3, 0.0),
+        AssetKind::Port
+    );
+
+    infra.pending_investment = 100_000.0;
+    projects::fund(&mut infra, 0, None, 0.0, 0.0);
+    assert_eq!(infra.projects.len(), 1);
+    assert_eq!(infra.pending_investment, 40_000.0);
+    assert_eq!(projects::power_share(&infra), 1.0);
+    assert_eq!(
+        kind(&infra, None, 0.0, 0.0),
+        AssetKind::Road,
+        "without shortages spending tracks the 60/40 power/transport split"
+    );
+    projects::fund(&mut infra, 1, None, 0.0, 0.0);
+    assert_eq!(infra.projects[1].template.kind, AssetKind::Road);
+    assert_eq!(infra.pending_investment, 10_000.0);
+
+    infra.plants.clear();
+    assert_eq!(
+        kind(&infra, Some(AssetKind::Plant), 0.3, 0.0),
+        AssetKind::Road,
+        "a region without a plant fleet has nowhere to dispatch a new plant"
+    );
+}
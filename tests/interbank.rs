@@ -0,0 +1,191 @@
+use panarchy::{
+    engine::{EngineBuilder, EngineSettings},
+    lending,
+    scenario::{Scenario, ScenarioLoader},
+    systems::{
+        BookkeepingSystem, EconomySystem, EnvironmentSystem, FinanceSystem, InfrastructureSystem,
+        InterbankSystem, PolicySystem, PopulationSystem, TechnologySystem,
+    },
+    world::{EventKind, FlowKind, InterbankLoan, Resolution, Sector, World},
+};
+
+fn scenario_loader() -> ScenarioLoader {
+    ScenarioLoader::new(env!("CARGO_MANIFEST_DIR"))
+}
+
+fn scenario_path() -> std::path::PathBuf {
+    std::path::PathBuf::from("scenarios/tiny_island.yaml")
+}
+
+fn build_engine(seed: u64) -> EngineBuilder {
+    let settings = EngineSettings {
+        scenario_name: "tiny_island".into(),
+        seed,
+        snapshot_interval_ticks: 0,
+        snapshot_dir: std::path::PathBuf::from("snapshots_interbank_tests"),
+    };
+    EngineBuilder::new(settings)
+        .with_system(EnvironmentSystem::new())
+        .with_system(InfrastructureSystem::new())
+        .with_system(PopulationSystem::new())
+        .with_system(EconomySystem::new())
+        .with_system(FinanceSystem::new())
+        .with_system(InterbankSystem::new())
+        .with_system(PolicySystem::new())
+        .with_system(TechnologySystem::new())
+        .with_system(BookkeepingSystem::new())
+}
+
+fn lopsided(enabled: bool) -> Scenario {
+    let mut scenario = scenario_loader().load(scenario_path()).unwrap();
+    scenario.interbank.enabled = enabled;
+    for (idx, region) in scenario.regions.iter_mut().enumerate() {
+        region.finance.initial_deposits = if idx == 0 { 80_000_000.0 } else { 0.0 };
+        region.finance.initial_capital_ratio = if idx == 0 { 1.0 } else { 0.12 };
+        region.households.deposits_per_capita = 0.0;
+    }
+    scenario
+}
+
+fn run(scenario: &Scenario, ticks: u64) -> World {
+    let mut world = scenario.build_world();
+    build_engine(scenario.seed)
+        .build()
+        .run(&mut world, ticks)
+        .unwrap();
+    world
+}
+
+#[test]
+fn surplus_banks_lend_to_deficit_banks_near_the_policy_rate() {
+    let closed = run(&lopsided(false), 1);
+    assert!(closed.interbank().loans.is_empty());
+
+    let world = run(&lopsided(true), 1);
+    let ids = world.entity_ids();
+    let market = world.interbank();
+    assert!(market.volume > 0.0);
+    assert!(market.loans.iter().all(|l| l.lender == ids[0].raw()));
+    let lender = world.finance(ids[0]).unwrap();
+    assert!((lender.interbank_lending - market.volume).abs() < 1e-6);
+    for id in ids.iter().skip(1) {
+        let borrower = world.finance(*id).unwrap();
+        assert!(borrower.interbank_borrowing > 0.0);
+        assert!(borrower.interbank_rate >= lender.policy_rate + market.spread - 1e-12);
+        assert!(
+            borrower.interbank_rate
+                <= lender.policy_rate + market.spread + market.stress_premium * 2.0
+        );
+        let surplus = |w: &World| {
+            lending::liquidity_surplus(w.finance(*id).unwrap(), w.household(*id).unwrap().deposits)
+        };
+        assert!(surplus(&world) > surplus(&closed));
+        assert!(borrower.liquidity_ratio >= closed.finance(*id).unwrap().liquidity_ratio);
+    }
+    let snapshot = world.snapshot("tiny_island");
+    assert!((snapshot.interbank.volume - market.volume).abs() < 1e-6);
+    assert!(snapshot.regions[1].interbank_borrowing > 0.0);
+}
+
+#[test]
+fn counterparty_limits_tighten_with_credit_stress() {
+    let scenario = lopsided(true);
+    let mut calm = scenario.build_world();
+    let mut stressed = scenario.build_world();
+    let ids = stressed.entity_ids();
+    stressed.finance_mut(ids[1]).unwrap().credit_stress = 5.0;
+    build_engine(scenario.seed)
+        .build()
+        .run(&mut calm, 1)
+        .unwrap();
+    build_engine(scenario.seed)
+        .build()
+        .run(&mut stressed, 1)
+        .unwrap();
+    assert!(calm.finance(ids[1]).unwrap().interbank_borrowing > 0.0);
+    assert_eq!(stressed.finance(ids[1]).unwrap().interbank_borrowing, 0.0);
+    assert!(stressed.finance(ids[2]).unwrap().interbank_borrowing > 0.0);
+}
+
+#[test]
+fn bank_failures_spread_through_interbank_exposures() {
+    let mut scenario = lopsided(true);
+    for region in scenario.regions.iter_mut() {
+        region.finance.resolution = Resolution::BailIn;
+    }
+    let mut world = scenario.build_world();
+    let ids = world.entity_ids();
+    world.finance_mut(ids[1]).unwrap().capital = -1_000_000.0;
+    let lender_capital = world.finance(ids[0]).unwrap().capital;
+    world.interbank_mut().loans.push(InterbankLoan {
+        lender: ids[0].raw(),
+        borrower: ids[1].raw(),
+        amount: lender_capital * 3.0,
+        rate: 0.03,
+    });
+    build_engine(scenario.seed)
+        .build()
+        .run(&mut world, 1)
+        .unwrap();
+
+    let market = world.interbank();
+    assert_eq!(market.contagion_failures, 1);
+    assert!((market.losses - lender_capital * 3.0 * (1.0 - market.recovery_rate)).abs() < 1e-3);
+    let lender = world.finance(ids[0]).unwrap();
+    assert_eq!(lender.insolvencies, 1);
+    assert!(lender.interbank_losses > lender_capital);
+    let failures = world
+        .events()
+        .iter()
+        .filter(|e| e.kind == EventKind::BankInsolvency)
+        .count();
+    assert_eq!(failures, 2);
+}
+
+#[test]
+fn interbank_funding_covers_deficits_before_new_loans() {
+    let deficit = |enabled: bool| {
+        let mut scenario = lopsided(enabled);
+        scenario.regions[1].economy.wage_per_worker *= 4.0;
+        let mut world = scenario.build_world();
+        let id = world.entity_ids()[1];
+        let before = world.finance(id).unwrap().loan_balance;
+        build_engine(scenario.seed)
+            .build()
+            .run(&mut world, 5)
+            .unwrap();
+        world.finance(id).unwrap().loan_balance - before
+    };
+    let closed = deficit(false);
+    let open = deficit(true);
+    assert!(closed > 0.0, "the high-wage region runs a deficit");
+    assert!(open < closed, "{open} vs {closed}");
+}
+
+#[test]
+fn maintenance_during_an_overdraft_keeps_the_overdraft_and_flows_to_households() {
+    let mut scenario = lopsided(true);
+    scenario.regions[1].economy.wage_per_worker *= 4.0;
+    let mut world = scenario.build_world();
+    let id = world.entity_ids()[1];
+    build_engine(scenario.seed)
+        .build()
+        .run(&mut world, 5)
+        .unwrap();
+    let finance = world.finance(id).unwrap();
+    assert!(
+        finance.bank_deposits < 0.0,
+        "interbank funding leaves firms overdrawn"
+    );
+    let maintenance: Vec<_> = world
+        .ledger()
+        .entries
+        .iter()
+        .filter(|e| e.region == Some(id.raw()) && e.kind == FlowKind::Maintenance)
+        .collect();
+    assert!(!maintenance.is_empty());
+    assert!(maintenance
+        .iter()
+        .all(|e| e.from == Sector::Firms && e.to == Sector::Households));
+    assert!(world.infrastructure(id).unwrap().maintenance_funding > 0.0);
+}
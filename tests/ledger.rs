@@ -0,0 +1,217 @@
+use panarchy::{
+    engine::{EngineBuilder, EngineSettings},
+    ledger,
+    scenario::{Scenario, ScenarioLoader},
+    systems::{
+        BookkeepingSystem, EconomySystem, EnvironmentSystem, FinanceSystem, InfrastructureSystem,
+        InterbankSystem, PolicySystem, PopulationSystem, TechnologySystem,
+    },
+    world::{FlowKind, Resolution, Sector, World},
+};
+
+fn scenario_loader() -> ScenarioLoader {
+    ScenarioLoader::new(env!("CARGO_MANIFEST_DIR"))
+}
+
+fn scenario_path() -> std::path::PathBuf {
+    std::path::PathBuf::from("scenarios/tiny_island.yaml")
+}
+
+fn build_engine(seed: u64) -> EngineBuilder {
+    let settings = EngineSettings {
+        scenario_name: "tiny_island".into(),
+        seed,
+        snapshot_interval_ticks: 0,
+        snapshot_dir: std::path::PathBuf::from("snapshots_ledger_tests"),
+    };
+    EngineBuilder::new(settings)
+        .with_system(EnvironmentSystem::new())
+        .with_system(InfrastructureSystem::new())
+        .with_system(PopulationSystem::new())
+        .with_system(EconomySystem::new())
+        .with_system(FinanceSystem::new())
+        .with_system(InterbankSystem::new())
+        .with_system(PolicySystem::new())
+        .with_system(TechnologySystem::new())
+        .with_system(BookkeepingSystem::new())
+}
+
+fn run(scenario: &Scenario, world: &mut World, ticks: u64) {
+    build_engine(scenario.seed)
+        .build()
+        .run(world, ticks)
+        .unwrap();
+}
+
+fn posted(world: &World, kind: FlowKind, from: Sector, to: Sector) -> f64 {
+    world
+        .ledger()
+        .entries
+        .iter()
+        .filter(|e| e.kind == kind && e.from == from && e.to == to)
+        .map(|e| e.amount)
+        .sum()
+}
+
+#[test]
+fn sector_balances_sum_to_zero_every_tick() {
+    let mut scenario = scenario_loader().load(scenario_path()).unwrap();
+    scenario.interbank.enabled = true;
+    let mut world = scenario.build_world();
+    let mut engine = build_engine(scenario.seed).build();
+    for tick in 0..40 {
+        engine.run(&mut world, 1).unwrap();
+        let ledger = world.ledger();
+        assert_eq!(ledger.tick, tick);
+        let scale: f64 = ledger.balances.iter().map(|b| b.abs()).sum();
+        assert!(scale > 0.0);
+        assert!(ledger::net_flows(ledger).iter().sum::<f64>().abs() <= 1e-6 * scale);
+        assert!(ledger.entries.iter().all(|e| e.from != e.to));
+    }
+    let snapshot = world.snapshot("tiny_island");
+    assert_eq!(snapshot.ledger.entries.len(), world.ledger().entries.len());
+    assert_eq!(
+        ledger::balance(world.ledger(), Sector::RestOfWorld),
+        0.0,
+        "regions only trade once currency areas are configured"
+    );
+}
+
+#[test]
+fn flow_of_funds_matrix_matches_system_flows() {
+    let scenario = scenario_loader().load(scenario_path()).unwrap();
+    let mut world = scenario.build_world();
+    run(&scenario, &mut world, 5);
+    let ids = world.entity_ids();
+    let sum = |f: &dyn Fn(&World, usize) -> f64| (0..ids.len()).map(|i| f(&world, i)).sum::<f64>();
+
+    let wages = sum(&|w, i| w.economy(ids[i]).unwrap().wage_bill);
+    assert!(wages > 0.0);
+    assert!(
+        (posted(&world, FlowKind::Wages, Sector::Firms, Sector::Households) - wages).abs()
+            < 1e-6 * wages
+    );
+
+    let taxes = sum(&|w, i| w.policy(ids[i]).unwrap().last_income_tax);
+    assert_eq!(taxes, sum(&|w, i| w.economy(ids[i]).unwrap().income_tax));
+    assert!(
+        (posted(
+            &world,
+            FlowKind::IncomeTax,
+            Sector::Households,
+            Sector::Government
+        ) - taxes)
+            .abs()
+            < 1e-6 * (taxes + 1.0)
+    );
+    let transfers = sum(&|w, i| w.policy(ids[i]).unwrap().last_transfers);
+    assert!(
+        (posted(
+            &world,
+            FlowKind::Transfers,
+            Sector::Government,
+            Sector::Households
+        ) - transfers)
+            .abs()
+            < 1e-6 * (transfers + 1.0)
+    );
+    let interest = sum(&|w, i| w.finance(ids[i]).unwrap().interest_received);
+    assert!(interest > 0.0);
+    assert!(posted(&world, FlowKind::LoanInterest, Sector::Firms, Sector::Banks) >= interest);
+    let coupons = sum(&|w, i| w.policy(ids[i]).unwrap().last_interest);
+    let paid = posted(
+        &world,
+        FlowKind::BondCoupon,
+        Sector::Government,
+        Sector::Banks,
+    ) + posted(
+        &world,
+        FlowKind::BondCoupon,
+        Sector::Government,
+        Sector::Households,
+    );
+    assert!((paid - coupons).abs() < 1e-6 * (coupons + 1.0));
+
+    let matrix = world.ledger().matrix;
+    for sector in Sector::ALL {
+        assert_eq!(matrix[Sector::RestOfWorld.index()][sector.index()], 0.0);
+        assert_eq!(matrix[sector.index()][Sector::RestOfWorld.index()], 0.0);
+    }
+}
+
+#[test]
+fn sector_balances_move_only_through_postings() {
+    let scenario = scenario_loader().load(scenario_path()).unwrap();
+    let mut world = scenario.build_world();
+    let mut engine = build_engine(scenario.seed).build();
+    for _ in 0..20 {
+        let before = ledger::positions(&world);
+        engine.run(&mut world, 1).unwrap();
+        let after = ledger::positions(&world);
+        let net = ledger::net_flows(world.ledger());
+        for sector in [
+            Sector::Households,
+            Sector::Firms,
+            Sector::Banks,
+            Sector::Government,
+        ] {
+            let i = sector.index();
+            let change = after[i] - before[i];
+            assert!(
+                (change - net[i]).abs() <= 1e-9 * (after[i].abs() + before[i].abs() + 1.0),
+                "{sector:?}: {change} vs {}",
+                net[i]
+            );
+        }
+    }
+    let sales_tax = posted(
+        &world,
+        FlowKind::SalesTax,
+        Sector::Firms,
+        Sector::Government,
+    );
+    assert!(sales_tax > 0.0, "firms pay sales tax out of their deposits");
+
+    let id = world.entity_ids()[0];
+    ledger::open(&mut world, 20);
+    world.finance_mut(id).unwrap().bank_deposits += 1_000.0;
+    let error = ledger::verify(&world).unwrap_err().to_string();
+    assert!(error.contains("Firms"), "{error}");
+}
+
+#[test]
+fn bank_resolutions_are_posted_to_the_paying_sector() {
+    for (resolution, payer) in [
+        (Resolution::BailOut, Sector::Government),
+        (Resolution::BailIn, Sector::Households),
+    ] {
+        let mut scenario = scenario_loader().load(scenario_path()).unwrap();
+        for region in scenario.regions.iter_mut() {
+            region.finance.resolution = resolution;
+            region.finance.initial_deposits = 0.0;
+        }
+        let mut world = scenario.build_world();
+        let id = world.entity_ids()[0];
+        world.finance_mut(id).unwrap().capital = -1_000_000.0;
+        run(&scenario, &mut world, 1);
+
+        let cost = world.finance(id).unwrap().resolution_cost;
+        assert!(cost > 0.0);
+        let kind = match resolution {
+            Resolution::BailIn => FlowKind::BailIn,
+            _ => FlowKind::BankSupport,
+        };
+        let paid: f64 = world
+            .ledger()
+            .entries
+            .iter()
+            .filter(|e| e.kind == kind && e.region == Some(id.raw()) && e.to == Sector::Banks)
+            .map(|e| e.amount)
+            .sum();
+        assert!((paid - cost).abs() < 1e-6 * cost, "{resolution:?}");
+        assert!(
+            posted(&world, kind, payer, Sector::Banks) > 0.0,
+            "{resolution:?}"
+        );
+    }
+}
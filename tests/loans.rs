@@ -0,0 +1,169 @@
+use panarchy::{
+    engine::{EngineBuilder, EngineSettings},
+    lending,
+    scenario::ScenarioLoader,
+    systems::{
+        BookkeepingSystem, EconomySystem, EnvironmentSystem, FinanceSystem, InfrastructureSystem,
+        PolicySystem, PopulationSystem, TechnologySystem,
+    },
+    world::{Amortisation, BorrowerType, World},
+};
+
+fn scenario_loader() -> ScenarioLoader {
+    ScenarioLoader::new(env!("CARGO_MANIFEST_DIR"))
+}
+
+fn scenario_path() -> std::path::PathBuf {
+    std::path::PathBuf::from("scenarios/tiny_island.yaml")
+}
+
+fn build_engine(seed: u64) -> EngineBuilder {
+    let settings = EngineSettings {
+        scenario_name: "tiny_island".into(),
+        seed,
+        snapshot_interval_ticks: 0,
+        snapshot_dir: std::path::PathBuf::from("snapshots_loans_tests"),
+    };
+    EngineBuilder::new(settings)
+        .with_system(EnvironmentSystem::new())
+        .with_system(InfrastructureSystem::new())
+        .with_system(PopulationSystem::new())
+        .with_system(EconomySystem::new())
+        .with_system(FinanceSystem::new())
+        .with_system(PolicySystem::new())
+        .with_system(TechnologySystem::new())
+        .with_system(BookkeepingSystem::new())
+}
+
+#[test]
+fn initial_loans_are_seeded_as_staggered_cohorts() {
+    let scenario = scenario_loader().load(scenario_path()).unwrap();
+    let initial = scenario.regions[0].finance.initial_loans;
+    let world = scenario.build_world();
+    let id = world.entity_ids()[0];
+    let finance = world.finance(id).unwrap();
+    assert!(finance.loans.len() > 1);
+    assert!((finance.loan_balance - initial).abs() < 1e-6);
+    let ages: Vec<f64> = finance.loans.iter().map(|l| l.age_days).collect();
+    assert!(ages.windows(2).all(|w| w[1] > w[0]));
+
+    let buckets = lending::age_distribution(finance);
+    let bucketed: f64 = buckets.iter().map(|b| b.outstanding).sum();
+    assert!((bucketed - initial).abs() < 1e-6);
+    assert_eq!(
+        buckets.iter().map(|b| b.loans).sum::<usize>(),
+        finance.loans.len()
+    );
+}
+
+#[test]
+fn scheduled_repayments_amortise_loans_by_maturity() {
+    let scenario = scenario_loader().load(scenario_path()).unwrap();
+    let world = scenario.build_world();
+    let id = world.entity_ids()[0];
+    for amortisation in [
+        Amortisation::Annuity,
+        Amortisation::Linear,
+        Amortisation::Bullet,
+    ] {
+        let mut finance = world.finance(id).unwrap().clone();
+        finance.loans.clear();
+        finance.firm_loan_terms.maturity_days = 365.0;
+        finance.firm_loan_terms.amortisation = amortisation;
+        finance.default_rate = 0.0;
+        lending::refresh(&mut finance);
+        lending::originate(&mut finance, BorrowerType::Firm, 1_000.0, 0.0);
+        assert_eq!(finance.loans.len(), 1);
+
+        let mut repaid = 0.0;
+        let mut halfway = 0.0;
+        for day in 0..365 {
+            lending::reset_flows(&mut finance, day);
+            lending::service(&mut finance, 1e9, 1.0);
+            lending::refresh(&mut finance);
+            repaid += finance.repayments;
+            if day == 181 {
+                halfway = finance.loan_balance;
+            }
+        }
+        assert!(finance.loans.is_empty(), "{amortisation:?} loan matured");
+        assert!((repaid - 1_000.0).abs() < 1e-6);
+        match amortisation {
+            Amortisation::Bullet => assert!((halfway - 1_000.0).abs() < 1e-6),
+            _ => assert!(halfway > 400.0 && halfway < 600.0, "{halfway}"),
+        }
+    }
+}
+
+#[test]
+fn missed_payments_build_arrears_and_raise_defaults() {
+    let scenario = scenario_loader().load(scenario_path()).unwrap();
+    let world = scenario.build_world();
+    let id = world.entity_ids()[0];
+    let base = world.finance(id).unwrap().clone();
+
+    let run = |cash: f64| {
+        let mut finance = base.clone();
+        for tick in 0..90 {
+            lending::reset_flows(&mut finance, tick);
+            lending::service(&mut finance, cash, 1.0);
+            lending::write_off(&mut finance, |_| 0.0, 1.0);
+            lending::refresh(&mut finance);
+        }
+        finance
+    };
+    let paying = run(1e9);
+    let stressed = run(0.0);
+    assert!(paying.arrears < 1e-6);
+    assert!(stressed.arrears > 0.0);
+    assert!(stressed.cumulative_defaults > paying.cumulative_defaults);
+
+    let mut world = scenario.build_world();
+    let mut engine = build_engine(scenario.seed).build();
+    engine.run(&mut world, 1).unwrap();
+    let region = &world.snapshot("tiny_island").regions[0];
+    assert!(region.loan_repayments > 0.0);
+    assert!(region.loan_interest > 0.0);
+
+    engine.run(&mut world, 59).unwrap();
+    let snapshot = world.snapshot("tiny_island");
+    let region = &snapshot.regions[0];
+    assert!(region.new_lending > 0.0);
+    assert!(region.loan_count > 0);
+    let bucketed: f64 = region
+        .loan_age_distribution
+        .iter()
+        .map(|b| b.outstanding)
+        .sum();
+    assert!((bucketed - region.loan_balance).abs() < 1e-3 * region.loan_balance.max(1.0));
+}
+
+#[test]
+fn infrastructure_drawdowns_count_as_new_lending() {
+    let scenario = scenario_loader().load(scenario_path()).unwrap();
+    let mut world = scenario.build_world();
+    let id = world.entity_ids()[0];
+    if let Some(finance) = world.finance_mut(id) {
+        finance.bank_deposits = 0.0;
+        finance.credit_rationing = 0.0;
+    }
+    let principal = |world: &World, borrower: BorrowerType| -> f64 {
+        world
+            .finance(id)
+            .unwrap()
+            .loans
+            .iter()
+            .filter(|l| l.borrower == borrower)
+            .map(|l| l.principal)
+            .sum()
+    };
+    let infrastructure_before = principal(&world, BorrowerType::Infrastructure);
+    let firm_before = principal(&world, BorrowerType::Firm);
+    let mut engine = build_engine(scenario.seed).build();
+    engine.run(&mut world, 1).unwrap();
+    let infrastructure = principal(&world, BorrowerType::Infrastructure) - infrastructure_before;
+    let firm = principal(&world, BorrowerType::Firm) - firm_before;
+    assert!(infrastructure > 0.0, "maintenance should be loan-funded");
+    let new_lending = world.finance(id).unwrap().new_lending;
+    assert!((new_lending - infrastructure - firm).abs() < 1e-6 * new_lending);
+}
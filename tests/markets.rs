@@ -0,0 +1,85 @@
+use panarchy::{
+    engine::{EngineBuilder, EngineSettings},
+    scenario::ScenarioLoader,
+    systems::{
+        BookkeepingSystem, EconomySystem, EnvironmentSystem, FinanceSystem, InfrastructureSystem,
+        PolicySystem, PopulationSystem, TechnologySystem,
+    },
+    world::{Good, MarketModel},
+};
+
+fn scenario_loader() -> ScenarioLoader {
+    ScenarioLoader::new(env!("CARGO_MANIFEST_DIR"))
+}
+
+fn scenario_path() -> std::path::PathBuf {
+    std::path::PathBuf::from("scenarios/tiny_island.yaml")
+}
+
+fn build_engine(seed: u64) -> EngineBuilder {
+    let settings = EngineSettings {
+        scenario_name: "tiny_island".into(),
+        seed,
+        snapshot_interval_ticks: 0,
+        snapshot_dir: std::path::PathBuf::from("snapshots_market_tests"),
+    };
+    EngineBuilder::new(settings)
+        .with_system(EnvironmentSystem::new())
+        .with_system(InfrastructureSystem::new())
+        .with_system(PopulationSystem::new())
+        .with_system(EconomySystem::new())
+        .with_system(FinanceSystem::new())
+        .with_system(PolicySystem::new())
+        .with_system(TechnologySystem::new())
+        .with_system(BookkeepingSystem::new())
+}
+
+#[test]
+fn posted_price_is_the_default_market() {
+    let loader = scenario_loader();
+    let scenario = loader.load(scenario_path()).unwrap();
+    let mut world = scenario.build_world();
+    let id = world.entity_ids()[0];
+    let price = world.economy(id).unwrap().food_price;
+    let mut engine = build_engine(scenario.seed).build();
+    engine.run(&mut world, 1).unwrap();
+    let market = world.market(id).expect("market component exists");
+    assert_eq!(market.food.model, MarketModel::PostedPrice);
+    assert_eq!(market.food.clearing_price, price);
+    assert!(market.food.volume > 0.0);
+}
+
+#[test]
+fn order_book_discovers_price_from_scarcity() {
+    let loader = scenario_loader();
+    let mut scenario = loader.load(scenario_path()).unwrap();
+    scenario.markets.food = Some(MarketModel::OrderBook);
+    let mut world = scenario.build_world();
+    let id = world.entity_ids()[0];
+    if let Some(stock) = world.resources_mut(id) {
+        stock.food = 5.0;
+    }
+    let baseline_price = world.economy(id).unwrap().food_price;
+    let mut engine = build_engine(scenario.seed).build();
+    engine.run(&mut world, 1).unwrap();
+
+    let market = world.market(id).unwrap();
+    assert_eq!(market.food.model, MarketModel::OrderBook);
+    assert_eq!(market.energy.model, MarketModel::PostedPrice);
+    assert!(
+        market.food.clearing_price > baseline_price,
+        "scarce supply should clear above the reference price ({} vs {})",
+        market.food.clearing_price,
+        baseline_price
+    );
+    assert!(
+        market.food.unfilled_demand > 0.0,
+        "unmatched bids should be recorded"
+    );
+    let snapshot = world.snapshot("tiny_island");
+    let region = snapshot.regions.iter().find(|r| r.id == id.raw()).unwrap();
+    assert!(region
+        .markets
+        .iter()
+        .any(|m| m.good == Good::Food && m.model == MarketModel::OrderBook));
+}
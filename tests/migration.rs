@@ -0,0 +1,107 @@
+use panarchy::{
+    engine::{EngineBuilder, EngineSettings},
+    scenario::ScenarioLoader,
+    systems::{
+        BookkeepingSystem, EconomySystem, EnvironmentSystem, FinanceSystem, InfrastructureSystem,
+        MigrationSystem, PolicySystem, PopulationSystem, TechnologySystem,
+    },
+    world::World,
+};
+
+fn scenario_loader() -> ScenarioLoader {
+    ScenarioLoader::new(env!("CARGO_MANIFEST_DIR"))
+}
+
+fn scenario_path() -> std::path::PathBuf {
+    std::path::PathBuf::from("scenarios/tiny_island.yaml")
+}
+
+fn build_engine(seed: u64) -> EngineBuilder {
+    let settings = EngineSettings {
+        scenario_name: "tiny_island".into(),
+        seed,
+        snapshot_interval_ticks: 0,
+        snapshot_dir: std::path::PathBuf::from("snapshots_migration_tests"),
+    };
+    EngineBuilder::new(settings)
+        .with_system(EnvironmentSystem::new())
+        .with_system(InfrastructureSystem::new())
+        .with_system(PopulationSystem::new())
+        .with_system(MigrationSystem::new())
+        .with_system(EconomySystem::new())
+        .with_system(FinanceSystem::new())
+        .with_system(PolicySystem::new())
+        .with_system(TechnologySystem::new())
+        .with_system(BookkeepingSystem::new())
+}
+
+fn inflow(world: &World, to: u64) -> f64 {
+    world
+        .migration()
+        .flows
+        .iter()
+        .filter(|f| f.to == to)
+        .map(|f| f.people)
+        .sum()
+}
+
+fn boost_wages(world: &mut World, factor: f64) -> u64 {
+    let ids = world.entity_ids();
+    let target = ids[1];
+    if let Some(econ) = world.economy_mut(target) {
+        econ.wage *= factor;
+    }
+    target.raw()
+}
+
+#[test]
+fn people_move_towards_higher_wages() {
+    let loader = scenario_loader();
+    let scenario = loader.load(scenario_path()).unwrap();
+    let mut world = scenario.build_world();
+    let target = boost_wages(&mut world, 4.0);
+    let mut engine = build_engine(scenario.seed).build();
+    engine.run(&mut world, 3).unwrap();
+    assert!(
+        inflow(&world, target) > 0.0,
+        "a wage premium should attract migrants"
+    );
+    let snapshot = world.snapshot("tiny_island");
+    let region = snapshot.regions.iter().find(|r| r.id == target).unwrap();
+    assert!(region.net_migration > 0.0);
+    assert!(!snapshot.migration_flows.is_empty());
+    let pyramid_total: f64 = region.age_pyramid.iter().map(|c| c.population).sum();
+    assert!((pyramid_total - region.citizens as f64).abs() <= 1.0);
+}
+
+#[test]
+fn immigration_caps_block_inflows() {
+    let loader = scenario_loader();
+    let scenario = loader.load(scenario_path()).unwrap();
+    let mut world = scenario.build_world();
+    let target = boost_wages(&mut world, 4.0);
+    let id = world.entity_ids()[1];
+    if let Some(policy) = world.policy_mut(id) {
+        policy.immigration_cap = Some(0.0);
+    }
+    let mut engine = build_engine(scenario.seed).build();
+    engine.run(&mut world, 3).unwrap();
+    assert_eq!(inflow(&world, target), 0.0);
+}
+
+#[test]
+fn distance_dampens_flows() {
+    let loader = scenario_loader();
+    let scenario = loader.load(scenario_path()).unwrap();
+    let run = |distance: f64| {
+        let mut world = scenario.build_world();
+        world.migration_mut().default_distance = distance;
+        let target = boost_wages(&mut world, 4.0);
+        let mut engine = build_engine(scenario.seed).build();
+        engine.run(&mut world, 1).unwrap();
+        inflow(&world, target)
+    };
+    let near = run(10.0);
+    let far = run(300.0);
+    assert!(near > far, "distant regions should exchange fewer migrants");
+}
@@ -0,0 +1,147 @@
+use panarchy::{
+    engine::{EngineBuilder, EngineSettings},
+    power, projects,
+    scenario::{Scenario, ScenarioLoader},
+    systems::{
+        BookkeepingSystem, EconomySystem, EnvironmentSystem, FinanceSystem, InfrastructureSystem,
+        PolicySystem, PopulationSystem, TechnologySystem,
+    },
+    world::{AssetKind, EventKind, InfrastructureComponent},
+};
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+fn scenario_loader() -> ScenarioLoader {
+    ScenarioLoader::new(env!("CARGO_MANIFEST_DIR"))
+}
+
+fn scenario_path() -> std::path::PathBuf {
+    std::path::PathBuf::from("scenarios/tiny_island.yaml")
+}
+
+fn build_engine(seed: u64) -> EngineBuilder {
+    let settings = EngineSettings {
+        scenario_name: "tiny_island".into(),
+        seed,
+        snapshot_interval_ticks: 0,
+        snapshot_dir: std::path::PathBuf::from("snapshots_outages_tests"),
+    };
+    EngineBuilder::new(settings)
+        .with_system(EnvironmentSystem::new())
+        .with_system(InfrastructureSystem::new())
+        .with_system(PopulationSystem::new())
+        .with_system(EconomySystem::new())
+        .with_system(FinanceSystem::new())
+        .with_system(PolicySystem::new())
+        .with_system(TechnologySystem::new())
+        .with_system(BookkeepingSystem::new())
+}
+
+fn harbor(scenario: &Scenario) -> InfrastructureComponent {
+    let world = scenario.build_world();
+    world.infrastructure(world.entity_ids()[0]).unwrap().clone()
+}
+
+#[test]
+fn reliability_sets_the_outage_hazard() {
+    let scenario = scenario_loader().load(scenario_path()).unwrap();
+    let mut infra = harbor(&scenario);
+    let mut rng = ChaCha8Rng::seed_from_u64(7);
+    infra.reliability = 1.0;
+    for _ in 0..365 {
+        assert!(projects::outages(&mut infra, &mut rng, 1.0).is_empty());
+    }
+
+    infra.reliability = 0.5;
+    infra.outage_rate = 2.0;
+    let tripped = projects::outages(&mut infra, &mut rng, 1.0);
+    assert!(!tripped.is_empty());
+    projects::refresh_capacity(&mut infra);
+    let plant = tripped.iter().find_map(|a| a.plant).unwrap();
+    let unit = infra.plants.iter().find(|p| p.id == plant).unwrap();
+    assert!(!unit.online, "tripped assets take their plant offline");
+    let out: f64 = tripped
+        .iter()
+        .filter(|a| a.plant.is_some())
+        .map(|a| a.capacity)
+        .sum();
+    assert_eq!(infra.forced_out_capacity, out);
+    assert!(infra.power_capacity < infra.nameplate_capacity - out + 1e-6);
+    assert_eq!(
+        power::condition(&infra),
+        1.0,
+        "healthy units are not derated"
+    );
+    assert_eq!(infra.outage_count, tripped.len() as u64);
+
+    infra.reliability = 1.0;
+    for _ in 0..3 {
+        projects::outages(&mut infra, &mut rng, 1.0);
+    }
+    projects::refresh_capacity(&mut infra);
+    assert!(infra.assets.iter().all(|a| a.in_service()));
+    assert!(infra.plants.iter().all(|p| p.online));
+    assert_eq!(infra.power_capacity, infra.nameplate_capacity);
+}
+
+#[test]
+fn underfunded_maintenance_prolongs_outages() {
+    let scenario = scenario_loader().load(scenario_path()).unwrap();
+    let duration = |funding: f64| {
+        let mut infra = harbor(&scenario);
+        infra.reliability = 0.0;
+        infra.outage_rate = 100.0;
+        infra.maintenance_funding = funding;
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+        projects::outages(&mut infra, &mut rng, 1.0)[0].outage_days
+    };
+    assert_eq!(duration(1.0), 3.0);
+    assert_eq!(duration(0.5), 6.0);
+    assert_eq!(duration(0.0), 15.0);
+
+    let mut world = scenario.build_world();
+    let id = world.entity_ids()[0];
+    let finance = world.finance_mut(id).unwrap();
+    finance.bank_deposits = 0.0;
+    finance.credit_rationing = 1.0;
+    build_engine(scenario.seed)
+        .build()
+        .run(&mut world, 1)
+        .unwrap();
+    assert_eq!(
+        world.infrastructure(id).unwrap().maintenance_funding,
+        0.0,
+        "rationed banks stop lending for upkeep"
+    );
+}
+
+#[test]
+fn blackouts_cut_capacity_and_are_reported() {
+    let mut scenario = scenario_loader().load(scenario_path()).unwrap();
+    scenario.regions[0].infrastructure.outage_rate = 20.0;
+    let mut world = scenario.build_world();
+    let id = world.entity_ids()[0];
+    let mut engine = build_engine(scenario.seed).build();
+    let mut blackout = false;
+    for _ in 0..200 {
+        engine.run(&mut world, 1).unwrap();
+        let infra = world.infrastructure(id).unwrap();
+        let road = infra.assets.iter().find(|a| a.kind == AssetKind::Road);
+        if road.is_some_and(|r| !r.in_service()) {
+            assert_eq!(infra.transport_capacity, 0.0);
+            assert!(world.economy(id).unwrap().transport_shortfall > 0.99);
+            blackout = true;
+            break;
+        }
+    }
+    assert!(blackout, "a road outage should occur");
+
+    let snapshot = world.snapshot("tiny_island");
+    assert!(snapshot.regions[0].infrastructure_outages > 0);
+    assert!(world
+        .events()
+        .iter()
+        .any(|e| e.kind == EventKind::InfrastructureOutage
+            && e.region == Some(id.raw())
+            && e.message.contains("Road network")));
+}
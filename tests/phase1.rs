@@ -38,21 +38,19 @@ fn employment_rises_when_productivity_drops() {
     let loader = scenario_loader();
     let scenario = loader.load(scenario_path()).unwrap();
 
-    let mut world_high_demand = scenario.build_world();
-    for id in world_high_demand.entity_ids() {
-        if let Some(econ) = world_high_demand.economy_mut(id) {
-            econ.food_productivity_per_worker *= 0.5;
-            econ.energy_productivity_per_worker *= 0.5;
-        }
+    let mut high_demand = scenario.clone();
+    for region in high_demand.regions.iter_mut() {
+        region.economy.food_productivity_per_worker *= 0.5;
+        region.economy.energy_productivity_per_worker *= 0.5;
     }
+    let mut world_high_demand = high_demand.build_world();
 
-    let mut world_low_demand = scenario.build_world();
-    for id in world_low_demand.entity_ids() {
-        if let Some(econ) = world_low_demand.economy_mut(id) {
-            econ.food_productivity_per_worker *= 2.0;
-            econ.energy_productivity_per_worker *= 2.0;
-        }
+    let mut low_demand = scenario.clone();
+    for region in low_demand.regions.iter_mut() {
+        region.economy.food_productivity_per_worker *= 2.0;
+        region.economy.energy_productivity_per_worker *= 2.0;
     }
+    let mut world_low_demand = low_demand.build_world();
 
     let mut engine_high = build_engine(scenario.seed).build();
     engine_high.run(&mut world_high_demand, 30).unwrap();
@@ -70,7 +70,9 @@ fn transport_capacity_can_create_shortfall() {
         .next()
         .expect("region exists");
     if let Some(infra) = world.infrastructure_mut(id) {
-        infra.transport_capacity = 100.0;
+        for road in infra.assets.iter_mut().filter(|a| !a.kind.is_power()) {
+            road.capacity = 100.0;
+        }
     }
     let mut engine = build_engine(scenario.seed).build();
     engine.run(&mut world, 1).unwrap();
@@ -111,7 +113,7 @@ fn infrastructure_investments_raise_capacity() {
         .expect("infra component exists")
         .power_capacity;
     let mut engine = build_engine(scenario.seed).build();
-    engine.run(&mut world, 6).unwrap();
+    engine.run(&mut world, 75).unwrap();
     let updated_capacity = world
         .infrastructure(id)
         .expect("infra component exists")
@@ -0,0 +1,190 @@
+use panarchy::{
+    engine::{EngineBuilder, EngineSettings},
+    power,
+    scenario::{Scenario, ScenarioLoader, ScenarioPowerPlant},
+    systems::{
+        BookkeepingSystem, EconomySystem, EnvironmentSystem, FinanceSystem, InfrastructureSystem,
+        PolicySystem, PopulationSystem, TechnologySystem,
+    },
+    world::PlantTechnology,
+};
+
+fn scenario_loader() -> ScenarioLoader {
+    ScenarioLoader::new(env!("CARGO_MANIFEST_DIR"))
+}
+
+fn scenario_path() -> std::path::PathBuf {
+    std::path::PathBuf::from("scenarios/tiny_island.yaml")
+}
+
+fn build_engine(seed: u64) -> EngineBuilder {
+    let settings = EngineSettings {
+        scenario_name: "tiny_island".into(),
+        seed,
+        snapshot_interval_ticks: 0,
+        snapshot_dir: std::path::PathBuf::from("snapshots_power_tests"),
+    };
+    EngineBuilder::new(settings)
+        .with_system(EnvironmentSystem::new())
+        .with_system(InfrastructureSystem::new())
+        .with_system(PopulationSystem::new())
+        .with_system(EconomySystem::new())
+        .with_system(FinanceSystem::new())
+        .with_system(PolicySystem::new())
+        .with_system(TechnologySystem::new())
+        .with_system(BookkeepingSystem::new())
+}
+
+fn plant(
+    name: &str,
+    technology: PlantTechnology,
+    capacity: f64,
+    marginal_cost: f64,
+) -> ScenarioPowerPlant {
+    ScenarioPowerPlant {
+        name: name.into(),
+        technology,
+        capacity,
+        marginal_cost,
+        fuel_per_unit: 0.0,
+        emissions_per_unit: 0.0,
+        online: true,
+    }
+}
+
+fn with_fleet() -> Scenario {
+    let mut scenario = scenario_loader().load(scenario_path()).unwrap();
+    let mut gas = plant("Harbor Gas", PlantTechnology::Gas, 30_000.0, 1.1);
+    gas.fuel_per_unit = 0.2;
+    gas.emissions_per_unit = 0.4;
+    scenario.regions[0].infrastructure.power_plants = vec![
+        plant("Old Oil", PlantTechnology::Oil, 25_000.0, 1.8),
+        gas,
+        plant("Falls", PlantTechnology::Hydro, 20_000.0, 0.4),
+    ];
+    scenario
+}
+
+#[test]
+fn plants_are_dispatched_cheapest_first() {
+    let world = with_fleet().build_world();
+    let id = world.entity_ids()[0];
+    let mut infra = world.infrastructure(id).unwrap().clone();
+    assert_eq!(infra.power_capacity, 75_000.0);
+    let names: Vec<&str> = power::merit_order(&infra)
+        .into_iter()
+        .map(|idx| infra.plants[idx].name.as_str())
+        .collect();
+    assert_eq!(names, ["Falls", "Harbor Gas", "Old Oil"]);
+
+    let dispatch = power::dispatch(&mut infra, 30_000.0, 30_000.0, None, 1.0);
+    assert_eq!(dispatch.dispatched, 30_000.0);
+    assert_eq!(dispatch.curtailed, 0.0);
+    let output = |name: &str| infra.plants.iter().find(|p| p.name == name).unwrap().output;
+    assert_eq!(output("Falls"), 20_000.0);
+    assert_eq!(output("Harbor Gas"), 10_000.0);
+    assert_eq!(output("Old Oil"), 0.0);
+    assert_eq!(
+        dispatch.price,
+        Some(1.1),
+        "the marginal plant sets the price"
+    );
+    assert_eq!(infra.price_setter, Some(infra.plants[1].id));
+    assert!((infra.emissions - 4_000.0).abs() < 1e-9);
+    assert!((infra.plants[1].fuel_used - 2_000.0).abs() < 1e-9);
+
+    let dispatch = power::dispatch(&mut infra, 60_000.0, 30_000.0, None, 1.0);
+    assert_eq!(dispatch.dispatched, 30_000.0, "dispatch stops at demand");
+    assert_eq!(dispatch.curtailed, 30_000.0);
+    assert_eq!(infra.plants[0].output, 0.0);
+    assert_eq!(dispatch.price, Some(1.1));
+}
+
+#[test]
+fn outages_and_scarcity_lift_the_clearing_price() {
+    let mut scenario = with_fleet();
+    scenario.regions[0].infrastructure.power_plants[2].online = false;
+    let world = scenario.build_world();
+    let id = world.entity_ids()[0];
+    let mut infra = world.infrastructure(id).unwrap().clone();
+
+    let dispatch = power::dispatch(&mut infra, 40_000.0, 40_000.0, None, 1.0);
+    let falls = infra.plants.iter().find(|p| p.name == "Falls").unwrap();
+    assert_eq!(falls.output, 0.0);
+    assert_eq!(falls.available, 0.0);
+    assert_eq!(dispatch.price, Some(1.8));
+
+    let dispatch = power::dispatch(&mut infra, 60_000.0, 66_000.0, None, 1.0);
+    assert_eq!(dispatch.dispatched, 55_000.0);
+    assert_eq!(dispatch.curtailed, 5_000.0);
+    assert_eq!(dispatch.unmet, 11_000.0);
+    let premium = infra.scarcity_premium * 11_000.0 / 66_000.0;
+    assert!((dispatch.price.unwrap() - 1.8 * (1.0 + premium)).abs() < 1e-12);
+
+    infra.power_capacity = 0.5 * infra.nameplate_capacity;
+    let dispatch = power::dispatch(&mut infra, 10_000.0, 10_000.0, None, 1.0);
+    assert_eq!(
+        infra.plants[1].available, 15_000.0,
+        "degraded grids derate plants"
+    );
+    assert_eq!(dispatch.price, Some(1.1));
+}
+
+#[test]
+fn snapshots_report_plant_output_and_the_price_setter() {
+    let mut scenario = with_fleet();
+    scenario.regions[0].infrastructure.power_plants[0].online = false;
+    let mut world = scenario.build_world();
+    build_engine(scenario.seed)
+        .build()
+        .run(&mut world, 60)
+        .unwrap();
+    let id = world.entity_ids()[0];
+    let snapshot = world.snapshot("tiny_island");
+    let region = snapshot.regions.iter().find(|r| r.id == id.raw()).unwrap();
+    assert_eq!(region.power_plants.len(), 3);
+    assert_eq!(region.plant_outages, 1);
+    let produced: f64 = region.power_plants.iter().map(|p| p.output).sum();
+    let economy = world.economy(id).unwrap();
+    assert!((produced - economy.energy_dispatched).abs() < 1e-6);
+    let setter = region.price_setting_plant.as_deref().unwrap();
+    let marginal = region
+        .power_plants
+        .iter()
+        .find(|p| p.name == setter)
+        .unwrap();
+    assert!(marginal.output > 0.0 || marginal.available > 0.0);
+    assert!(region.energy_price >= marginal.marginal_cost);
+    assert!(
+        region.energy_price >= world.infrastructure(id).unwrap().marginal_price,
+        "the marginal plant's cost floors the energy market"
+    );
+    assert!(snapshot
+        .regions
+        .iter()
+        .all(|r| !r.power_plants.is_empty() && r.price_setting_plant.is_some()));
+}
+
+#[test]
+fn spending_stays_within_budget_when_plants_floor_the_price() {
+    let mut scenario = with_fleet();
+    scenario.regions[0].economy.energy_price = 0.1;
+    scenario.regions[0].economy.wage_per_worker *= 0.05;
+    scenario.regions[0].policy.transfer_per_capita = Some(0.0);
+    scenario.regions[0].households.deposits_per_capita = 0.0;
+    scenario.regions[0].households.borrowing_limit_days = 0.0;
+    let mut world = scenario.build_world();
+    build_engine(scenario.seed)
+        .build()
+        .run(&mut world, 1)
+        .unwrap();
+    let id = world.entity_ids()[0];
+    let economy = world.economy(id).unwrap();
+    assert!(economy.energy_price >= 0.4);
+    assert!(
+        economy.sales_revenue <= economy.household_budget + 1e-6,
+        "{} spent from a budget of {}",
+        economy.sales_revenue,
+        economy.household_budget
+    );
+}
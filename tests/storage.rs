@@ -0,0 +1,170 @@
+use panarchy::{
+    engine::{EngineBuilder, EngineSettings},
+    scenario::{ScenarioLoader, ScenarioStorage},
+    storage,
+    systems::{
+        BookkeepingSystem, EconomySystem, EnvironmentSystem, FinanceSystem, InfrastructureSystem,
+        PolicySystem, PopulationSystem, TechnologySystem,
+    },
+    weather,
+    world::{PlantTechnology, StorageUnit},
+};
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+fn scenario_loader() -> ScenarioLoader {
+    ScenarioLoader::new(env!("CARGO_MANIFEST_DIR"))
+}
+
+fn scenario_path() -> std::path::PathBuf {
+    std::path::PathBuf::from("scenarios/tiny_island.yaml")
+}
+
+fn build_engine(seed: u64) -> EngineBuilder {
+    let settings = EngineSettings {
+        scenario_name: "tiny_island".into(),
+        seed,
+        snapshot_interval_ticks: 0,
+        snapshot_dir: std::path::PathBuf::from("snapshots_storage_tests"),
+    };
+    EngineBuilder::new(settings)
+        .with_system(EnvironmentSystem::new())
+        .with_system(InfrastructureSystem::new())
+        .with_system(PopulationSystem::new())
+        .with_system(EconomySystem::new())
+        .with_system(FinanceSystem::new())
+        .with_system(PolicySystem::new())
+        .with_system(TechnologySystem::new())
+        .with_system(BookkeepingSystem::new())
+}
+
+fn battery(capacity: f64, rate: f64, efficiency: f64) -> StorageUnit {
+    StorageUnit {
+        id: 1,
+        name: "Battery".into(),
+        capacity,
+        charge_rate: rate,
+        discharge_rate: rate,
+        efficiency,
+        level: 0.0,
+        charged: 0.0,
+        discharged: 0.0,
+    }
+}
+
+#[test]
+fn storage_respects_rates_capacity_and_losses() {
+    let mut units = vec![battery(1_000.0, 400.0, 0.8)];
+    let absorbed = storage::charge(&mut units, 1_000.0, 1.0, 0.0);
+    assert_eq!(absorbed, 400.0, "charging is limited by the charge rate");
+    assert!((units[0].level - 320.0).abs() < 1e-9);
+
+    for _ in 0..5 {
+        storage::charge(&mut units, 1_000.0, 1.0, 0.0);
+    }
+    assert!((units[0].level - 1_000.0).abs() < 1e-9);
+    assert!((units[0].charged - 1_250.0).abs() < 1e-9);
+
+    storage::reset(&mut units);
+    assert_eq!(storage::discharge(&mut units, 250.0, 1.0), 250.0);
+    assert_eq!(storage::discharge(&mut units, 900.0, 1.0), 400.0);
+    assert!((units[0].level - 350.0).abs() < 1e-9);
+    assert_eq!(units[0].discharged, 650.0);
+
+    let mut plain = vec![battery(1_000.0, 400.0, 0.8)];
+    let mut improved = vec![battery(1_000.0, 400.0, 0.8)];
+    storage::charge(&mut plain, 100.0, 1.0, 0.0);
+    storage::charge(&mut improved, 100.0, 1.0, 0.1);
+    assert!(improved[0].level > plain[0].level);
+    assert!((improved[0].level - 90.0).abs() < 1e-9);
+}
+
+#[test]
+fn weather_drives_renewable_capacity_factors() {
+    let scenario = scenario_loader().load(scenario_path()).unwrap();
+    let world = scenario.build_world();
+    let mut conditions = world.weather(world.entity_ids()[1]).unwrap().clone();
+    let mut rng = ChaCha8Rng::seed_from_u64(3);
+    let mut solar = Vec::new();
+    let mut wind = Vec::new();
+    for day in 0..365 {
+        weather::advance(&mut conditions, &mut rng, day as f64, 1.0);
+        assert!((0.0..=1.0).contains(&conditions.solar));
+        assert!((0.0..=1.0).contains(&conditions.wind));
+        solar.push(conditions.solar);
+        wind.push(conditions.wind);
+        assert_eq!(
+            weather::capacity_factor(PlantTechnology::Wind, &conditions),
+            conditions.wind
+        );
+        assert_eq!(
+            weather::capacity_factor(PlantTechnology::Coal, &conditions),
+            1.0
+        );
+    }
+    let mean = |days: &[f64]| days.iter().sum::<f64>() / days.len() as f64;
+    assert!(
+        mean(&solar[150..195]) > 2.0 * mean(&solar[0..30]),
+        "summer sun outshines winter"
+    );
+    let spread = wind.iter().cloned().fold(f64::MIN, f64::max)
+        - wind.iter().cloned().fold(f64::MAX, f64::min);
+    assert!(spread > 0.2, "wind output varies day to day");
+
+    let mut world = scenario.build_world();
+    let id = world.entity_ids()[1];
+    build_engine(scenario.seed)
+        .build()
+        .run(&mut world, 10)
+        .unwrap();
+    let factor = world.weather(id).unwrap().wind;
+    let infra = world.infrastructure(id).unwrap();
+    let turbines = infra
+        .plants
+        .iter()
+        .find(|p| p.technology == PlantTechnology::Wind)
+        .unwrap();
+    assert_eq!(turbines.capacity_factor, factor);
+    assert!(turbines.available <= turbines.capacity * factor + 1e-9);
+    let snapshot = world.snapshot("tiny_island");
+    assert_eq!(snapshot.regions[1].wind_factor, factor);
+}
+
+#[test]
+fn storage_shifts_curtailed_energy_into_shortfalls() {
+    let mut scenario = scenario_loader().load(scenario_path()).unwrap();
+    for plant in scenario.regions[1].infrastructure.power_plants.iter_mut() {
+        plant.capacity *= 0.4;
+    }
+    let run = |storage: Vec<ScenarioStorage>| {
+        let mut scenario = scenario.clone();
+        scenario.regions[1].infrastructure.storage = storage;
+        let mut world = scenario.build_world();
+        let id = world.entity_ids()[1];
+        let mut engine = build_engine(scenario.seed).build();
+        let (mut stored, mut released, mut curtailed) = (0.0, 0.0, 0.0);
+        for _ in 0..60 {
+            engine.run(&mut world, 1).unwrap();
+            let region = &world.snapshot("tiny_island").regions[1];
+            assert!(region.storage_level <= region.storage_capacity + 1e-9);
+            stored += region.energy_stored;
+            released += region.energy_released;
+            curtailed += world.economy(id).unwrap().energy_curtailed;
+        }
+        (stored, released, curtailed)
+    };
+    let (_, _, baseline) = run(Vec::new());
+    assert!(baseline > 0.0, "a thin fleet curtails generation");
+    let (stored, released, curtailed) = run(vec![ScenarioStorage {
+        name: "Pumped hydro".into(),
+        capacity: 200_000.0,
+        charge_rate: 20_000.0,
+        discharge_rate: 20_000.0,
+        efficiency: 0.85,
+        initial_level: 0.0,
+    }]);
+    assert!(stored > 0.0);
+    assert!(released > 0.0);
+    assert!(released <= stored * 0.85 + 1e-6);
+    assert!(curtailed < baseline);
+}
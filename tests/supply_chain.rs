@@ -0,0 +1,184 @@
+use panarchy::{
+    engine::{EngineBuilder, EngineSettings},
+    scenario::{Scenario, ScenarioLoader},
+    systems::{
+        BookkeepingSystem, EconomySystem, EnvironmentSystem, FinanceSystem, InfrastructureSystem,
+        PolicySystem, PopulationSystem, TechnologySystem,
+    },
+    world::Good,
+};
+
+fn scenario_loader() -> ScenarioLoader {
+    ScenarioLoader::new(env!("CARGO_MANIFEST_DIR"))
+}
+
+fn scenario_path() -> std::path::PathBuf {
+    std::path::PathBuf::from("scenarios/tiny_island.yaml")
+}
+
+fn build_engine(seed: u64) -> EngineBuilder {
+    let settings = EngineSettings {
+        scenario_name: "tiny_island".into(),
+        seed,
+        snapshot_interval_ticks: 0,
+        snapshot_dir: std::path::PathBuf::from("snapshots_supply_chain_tests"),
+    };
+    EngineBuilder::new(settings)
+        .with_system(EnvironmentSystem::new())
+        .with_system(InfrastructureSystem::new())
+        .with_system(PopulationSystem::new())
+        .with_system(EconomySystem::new())
+        .with_system(FinanceSystem::new())
+        .with_system(PolicySystem::new())
+        .with_system(TechnologySystem::new())
+        .with_system(BookkeepingSystem::new())
+}
+
+fn load_with_supply_chain(latency_ticks: u32) -> Scenario {
+    let mut scenario = scenario_loader().load(scenario_path()).unwrap();
+    scenario.supply_chain.latency_ticks = latency_ticks;
+    // agriculture, manufacturing, services, energy
+    scenario.supply_chain.coefficients = vec![
+        vec![0.0, 0.0, 0.0, 0.08],
+        vec![0.0; 4],
+        vec![0.0; 4],
+        vec![0.01, 0.0, 0.0, 0.0],
+    ];
+    scenario
+}
+
+fn load_from_yaml(supply_chain: &str) -> anyhow::Result<Scenario> {
+    let base = std::fs::read_to_string(
+        std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join(scenario_path()),
+    )?;
+    let dir = tempfile::tempdir()?;
+    let path = dir.path().join("scenario.yaml");
+    std::fs::write(&path, format!("{base}\nsupply_chain:\n{supply_chain}"))?;
+    ScenarioLoader::new(dir.path()).load(&path)
+}
+
+#[test]
+fn default_sectors_size_the_matrix() {
+    let scenario = load_with_supply_chain(0);
+    let names: Vec<&str> = scenario
+        .supply_chain
+        .sectors
+        .iter()
+        .map(|s| s.name.as_str())
+        .collect();
+    assert_eq!(
+        names,
+        ["agriculture", "manufacturing", "services", "energy"]
+    );
+    assert!(scenario.supply_chain.validate().is_ok());
+    let world = scenario.build_world();
+    let chain = world.supply_chain(world.entity_ids()[0]).unwrap();
+    assert_eq!(chain.matrix.len(), 4);
+    assert_eq!(chain.sector_output.len(), 4);
+}
+
+#[test]
+fn non_square_matrix_is_rejected_at_load() {
+    let err = load_from_yaml(
+        "  coefficients:\n    - [0.0, 0.0, 0.0, 0.08]\n    - [0.0, 0.0, 0.0, 0.0]\n",
+    )
+    .unwrap_err();
+    assert!(format!("{err:#}").contains("4x4"), "{err:#}");
+}
+
+#[test]
+fn unproductive_matrix_is_rejected_at_load() {
+    let mut scenario = load_with_supply_chain(0);
+    scenario.supply_chain.coefficients[0][3] = 1.5;
+    scenario.supply_chain.coefficients[3][0] = 1.0;
+    let err = scenario.supply_chain.validate().unwrap_err();
+    assert!(err.to_string().contains("not productive"), "{err}");
+}
+
+#[test]
+fn intermediate_sectors_pass_their_inputs_through() {
+    let mut scenario = load_with_supply_chain(0);
+    scenario.supply_chain.coefficients = vec![
+        vec![0.0, 0.1, 0.0, 0.0],
+        vec![0.0, 0.0, 0.2, 0.5],
+        vec![0.0; 4],
+        vec![0.0; 4],
+    ];
+    assert!(scenario.supply_chain.validate().is_ok());
+    let mut world = scenario.build_world();
+    let id = world.entity_ids()[0];
+    let chain = world.supply_chain(id).unwrap();
+    assert!(
+        (chain.coefficient(Good::Food, Good::Energy) - 0.05).abs() < 1e-9,
+        "manufacturing's energy should be embodied in food"
+    );
+    build_engine(scenario.seed)
+        .build()
+        .run(&mut world, 1)
+        .unwrap();
+    let chain = world.supply_chain(id).unwrap();
+    let agriculture = chain.sector_output[0];
+    assert!(agriculture > 0.0);
+    assert!((chain.sector_output[1] - 0.1 * agriculture).abs() < 1e-6);
+    assert!((chain.sector_output[2] - 0.02 * agriculture).abs() < 1e-6);
+    assert!((chain.intermediate_energy - 0.05 * agriculture).abs() < 1e-6);
+}
+
+#[test]
+fn energy_shortage_throttles_food_output() {
+    let scenario = load_with_supply_chain(0);
+    let mut world = scenario.build_world();
+    let id = world
+        .entity_ids()
+        .into_iter()
+        .next()
+        .expect("region exists");
+    if let Some(stock) = world.resources_mut(id) {
+        stock.energy = 0.0;
+    }
+    if let Some(infra) = world.infrastructure_mut(id) {
+        infra.assets.retain(|a| !a.kind.is_power());
+    }
+    let mut engine = build_engine(scenario.seed).build();
+    engine.run(&mut world, 1).unwrap();
+    let chain = world.supply_chain(id).expect("supply chain configured");
+    assert!(
+        chain.food_throttle > 0.0,
+        "missing energy should throttle food production"
+    );
+}
+
+#[test]
+fn intermediate_inputs_are_drawn_from_stock() {
+    let scenario = load_with_supply_chain(0);
+    let mut world = scenario.build_world();
+    let mut engine = build_engine(scenario.seed).build();
+    engine.run(&mut world, 2).unwrap();
+    let snapshot = world.snapshot("tiny_island");
+    for region in &snapshot.regions {
+        assert!(region.intermediate_energy > 0.0);
+        assert!(region.intermediate_food > 0.0);
+        assert_eq!(region.food_input_throttle, 0.0);
+    }
+}
+
+#[test]
+fn supply_chain_latency_delays_inputs() {
+    let scenario = load_with_supply_chain(2);
+    let mut world = scenario.build_world();
+    let id = world.entity_ids()[0];
+    let mut engine = build_engine(scenario.seed).build();
+    engine.run(&mut world, 1).unwrap();
+    let chain = world.supply_chain(id).unwrap();
+    assert!(
+        chain.food_throttle > 0.99,
+        "no inputs should have arrived on the first tick"
+    );
+    assert!(!chain.in_transit.is_empty(), "orders should be in transit");
+    engine.run(&mut world, 3).unwrap();
+    let chain = world.supply_chain(id).unwrap();
+    assert!(
+        chain.food_throttle < 0.5,
+        "inputs should arrive after the latency window"
+    );
+}
@@ -0,0 +1,253 @@
+use std::collections::HashMap;
+
+use panarchy::{
+    engine::{EngineBuilder, EngineSettings},
+    scenario::{Scenario, ScenarioLink, ScenarioLoader},
+    systems::{
+        BookkeepingSystem, CurrencySystem, EconomySystem, EnvironmentSystem, FinanceSystem,
+        InfrastructureSystem, PolicySystem, PopulationSystem, TechnologySystem, TradeSystem,
+        TransportSystem,
+    },
+    transport,
+    world::{Consignment, FlowKind, TransportLink, TransportNetwork, World},
+};
+
+fn scenario_loader() -> ScenarioLoader {
+    ScenarioLoader::new(env!("CARGO_MANIFEST_DIR"))
+}
+
+fn scenario_path() -> std::path::PathBuf {
+    std::path::PathBuf::from("scenarios/tiny_island.yaml")
+}
+
+fn build_engine(seed: u64) -> EngineBuilder {
+    let settings = EngineSettings {
+        scenario_name: "tiny_island".into(),
+        seed,
+        snapshot_interval_ticks: 0,
+        snapshot_dir: std::path::PathBuf::from("snapshots_transport_tests"),
+    };
+    EngineBuilder::new(settings)
+        .with_system(EnvironmentSystem::new())
+        .with_system(InfrastructureSystem::new())
+        .with_system(PopulationSystem::new())
+        .with_system(EconomySystem::new())
+        .with_system(TradeSystem::new())
+        .with_system(CurrencySystem::new())
+        .with_system(TransportSystem::new())
+        .with_system(FinanceSystem::new())
+        .with_system(PolicySystem::new())
+        .with_system(TechnologySystem::new())
+        .with_system(BookkeepingSystem::new())
+}
+
+fn link(id: u64, from: &str, to: &str, capacity: f64, free_flow_time: f64) -> TransportLink {
+    TransportLink {
+        id,
+        from: from.into(),
+        to: to.into(),
+        capacity,
+        length: free_flow_time,
+        cost: 0.0,
+        free_flow_time,
+        flow: 0.0,
+        utilisation: 0.0,
+        travel_time: 0.0,
+        generalised_cost: 0.0,
+    }
+}
+
+fn network(links: Vec<TransportLink>) -> TransportNetwork {
+    let mut network = TransportNetwork {
+        bpr_alpha: 0.15,
+        bpr_beta: 4.0,
+        time_value: 1.0,
+        assignment_steps: 8,
+        passenger_weight: 1.0,
+        links,
+        ..TransportNetwork::default()
+    };
+    transport::refresh(&mut network);
+    network
+}
+
+fn consignment(from: u64, to: u64, volume: f64) -> Consignment {
+    Consignment {
+        from,
+        to,
+        good: None,
+        volume,
+        route: Vec::new(),
+        unit_cost: 0.0,
+    }
+}
+
+fn scenario_link(from: &str, to: &str, capacity: f64, cost: f64) -> ScenarioLink {
+    ScenarioLink {
+        from: from.into(),
+        to: to.into(),
+        capacity,
+        length: 100.0,
+        cost,
+        speed: None,
+    }
+}
+
+fn run(scenario: &Scenario, world: &mut World, ticks: u64) {
+    build_engine(scenario.seed)
+        .build()
+        .run(world, ticks)
+        .unwrap();
+}
+
+#[test]
+fn shortest_paths_prefer_cheap_chains_over_costly_direct_links() {
+    let mut direct = link(3, "A", "C", 1_000.0, 5.0);
+    direct.cost = 1.0;
+    let mut network = network(vec![
+        link(1, "A", "B", 1_000.0, 2.0),
+        link(2, "C", "B", 1_000.0, 2.0),
+        direct,
+        link(4, "D", "E", 1_000.0, 1.0),
+    ]);
+    transport::refresh(&mut network);
+
+    let (path, cost) = transport::shortest_path(&network, "A", "C").unwrap();
+    assert_eq!(path, vec![0, 1], "links are traversed in either direction");
+    assert!((cost - 4.0).abs() < 1e-12);
+    let (_, back) = transport::shortest_path(&network, "C", "A").unwrap();
+    assert_eq!(cost, back);
+    assert!(transport::shortest_path(&network, "A", "E").is_none());
+
+    let names = ["A", "B", "C", "D", "E", "F"].map(String::from);
+    transport::refresh_components(&mut network);
+    assert_eq!(
+        transport::components(&network, &names),
+        vec![0, 0, 0, 3, 3, 5]
+    );
+
+    network.links[1].capacity = 0.0;
+    transport::refresh(&mut network);
+    let (path, cost) = transport::shortest_path(&network, "A", "C").unwrap();
+    assert_eq!(path, vec![2], "closed links are skipped");
+    assert!((cost - 6.0).abs() < 1e-12);
+    let costs = transport::route_costs(&network, "A");
+    assert_eq!(costs["C"], cost);
+    assert!(!costs.contains_key("D"));
+
+    network.links[2].capacity = 0.0;
+    transport::refresh(&mut network);
+    transport::refresh_components(&mut network);
+    assert_eq!(
+        transport::components(&network, &names),
+        vec![0, 0, 2, 3, 3, 5],
+        "closing the last route splits the component"
+    );
+}
+
+#[test]
+fn congestion_raises_travel_times_and_spreads_flow() {
+    let mut network = network(vec![
+        link(1, "A", "B", 100.0, 1.0),
+        link(2, "A", "C", 1_000.0, 1.5),
+        link(3, "C", "B", 1_000.0, 1.5),
+    ]);
+    let names: HashMap<u64, String> = [(1, "A"), (2, "B"), (3, "Z")]
+        .into_iter()
+        .map(|(id, name)| (id, name.to_string()))
+        .collect();
+    let mut light = vec![consignment(1, 2, 10.0)];
+    transport::assign(&mut network, &mut light, &names);
+    assert_eq!(light[0].route, vec![1]);
+    assert!((network.links[0].utilisation - 0.1).abs() < 1e-12);
+    assert!(network.links[0].travel_time < 1.001);
+    assert_eq!(network.links[1].flow, 0.0);
+
+    let mut heavy = vec![consignment(1, 2, 400.0), consignment(1, 3, 50.0)];
+    transport::assign(&mut network, &mut heavy, &names);
+    let direct = &network.links[0];
+    assert!(direct.utilisation > 1.0);
+    let expected = direct.free_flow_time * (1.0 + 0.15 * direct.utilisation.powf(4.0));
+    assert!((direct.travel_time - expected).abs() < 1e-12);
+    assert!(
+        network.links[1].flow > 0.0,
+        "congestion pushes increments onto the detour"
+    );
+    assert!((direct.flow + network.links[1].flow - 400.0).abs() < 1e-9);
+    assert_eq!(heavy[0].route, vec![2, 3]);
+    assert_eq!(network.unrouted, 50.0);
+    assert!(heavy[1].route.is_empty());
+}
+
+#[test]
+fn trade_is_routed_over_the_network_and_pays_freight() {
+    let mut scenario = scenario_loader().load(scenario_path()).unwrap();
+    scenario.transport.time_value = 0.01;
+    scenario.transport.links = vec![
+        scenario_link("Harbor Town", "Highlands", 50_000.0, 0.05),
+        scenario_link("Highlands", "Research Atoll", 50_000.0, 0.05),
+    ];
+    let mut world = scenario.build_world();
+    let ids = world.entity_ids();
+    assert_eq!(world.transport().links[1].id, 2);
+    assert!((world.transport().links[0].free_flow_time - 2.0).abs() < 1e-12);
+    run(&scenario, &mut world, 10);
+
+    let snapshot = world.snapshot("tiny_island");
+    let shipments = &snapshot.transport.shipments;
+    assert!(!shipments.is_empty());
+    assert!(shipments.iter().all(|s| s.good.is_some()));
+    for shipment in shipments {
+        let direct = [shipment.from, shipment.to].contains(&ids[1].raw());
+        assert_eq!(shipment.route.len(), if direct { 1 } else { 2 });
+    }
+    assert!(snapshot.transport.links.iter().any(|l| l.flow > 0.0));
+    let freight: f64 = snapshot.regions.iter().map(|r| r.freight_cost).sum();
+    assert!(freight > 0.0);
+    assert!((freight - snapshot.transport.freight_cost).abs() < 1e-9);
+    let posted: f64 = world
+        .ledger()
+        .entries
+        .iter()
+        .filter(|e| e.kind == FlowKind::Freight)
+        .map(|e| e.amount)
+        .sum();
+    assert!((posted - freight).abs() < 1e-9);
+
+    scenario.transport.links.truncate(1);
+    let mut world = scenario.build_world();
+    run(&scenario, &mut world, 10);
+    let snapshot = world.snapshot("tiny_island");
+    let atoll = &snapshot.regions[2];
+    assert_eq!(
+        atoll.exports + atoll.imports,
+        0.0,
+        "unconnected regions cannot trade"
+    );
+}
+
+#[test]
+fn freight_costs_are_priced_into_trade() {
+    let exports = |cost: f64| {
+        let mut scenario = scenario_loader().load(scenario_path()).unwrap();
+        scenario.transport.time_value = 0.01;
+        scenario.transport.links = vec![
+            scenario_link("Harbor Town", "Highlands", 50_000.0, cost),
+            scenario_link("Highlands", "Research Atoll", 50_000.0, cost),
+        ];
+        let mut world = scenario.build_world();
+        run(&scenario, &mut world, 10);
+        world
+            .snapshot("tiny_island")
+            .regions
+            .iter()
+            .map(|r| r.exports)
+            .sum::<f64>()
+    };
+    let cheap = exports(0.05);
+    assert!(cheap > 0.0);
+    assert!(
+        exports(5.0) < cheap,
+        "costly routes should make fewer trades worthwhile"
+    );
+}